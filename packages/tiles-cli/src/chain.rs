@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use cosmwasm_std::{Binary, Coin, Event};
+
+use crate::error::CliError;
+
+/// Result of a broadcast transaction. Dry runs carry no hash and no events.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TxOutcome {
+    pub txhash: Option<String>,
+    pub events: Vec<Event>,
+}
+
+impl TxOutcome {
+    pub fn dry_run() -> Self {
+        Self::default()
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.txhash.is_none()
+    }
+
+    fn txhash_or_default(&self) -> String {
+        self.txhash.clone().unwrap_or_default()
+    }
+
+    /// First value of `key` on events of type `ty`
+    pub fn attribute(&self, ty: &str, key: &str) -> Option<&str> {
+        self.attributes(ty, key).into_iter().next()
+    }
+
+    /// Every value of `key` on events of type `ty`, in emission order
+    pub fn attributes(&self, ty: &str, key: &str) -> Vec<&str> {
+        self.events
+            .iter()
+            .filter(|event| event.ty == ty)
+            .flat_map(|event| event.attributes.iter())
+            .filter(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+            .collect()
+    }
+
+    pub fn code_id(&self) -> Result<u64, CliError> {
+        self.attribute("store_code", "code_id")
+            .and_then(|code_id| code_id.parse().ok())
+            .ok_or_else(|| self.missing("store_code event"))
+    }
+
+    /// Addresses of every contract instantiated by the transaction, in order
+    pub fn contract_addresses(&self) -> Vec<String> {
+        self.attributes("instantiate", "_contract_address")
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    pub fn missing(&self, what: &str) -> CliError {
+        CliError::MissingEvent {
+            txhash: self.txhash_or_default(),
+            what: what.to_string(),
+        }
+    }
+}
+
+/// Everything the CLI needs from a chain. Implemented by `starsd` for real
+/// networks and by mocks in tests; wrap with `DryRun` to print instead of broadcast.
+pub trait Chain {
+    /// Address signing every transaction
+    fn sender(&self) -> &str;
+
+    fn store_code(&mut self, wasm: &Path) -> Result<TxOutcome, CliError>;
+
+    fn instantiate(
+        &mut self,
+        code_id: u64,
+        msg: &Binary,
+        funds: &[Coin],
+        label: &str,
+    ) -> Result<TxOutcome, CliError>;
+
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<TxOutcome, CliError>;
+
+    /// Smart query returning the raw JSON response
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, CliError>;
+}
+
+/// Lets the typed tiles client run on top of any chain
+pub struct ChainTransport<'a, C: Chain>(pub &'a mut C);
+
+impl<C: Chain> tiles_client::Transport for ChainTransport<'_, C> {
+    type Error = CliError;
+    type Response = TxOutcome;
+
+    fn sender(&self) -> &str {
+        self.0.sender()
+    }
+
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, Self::Error> {
+        self.0.query(contract, msg)
+    }
+
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<Self::Response, Self::Error> {
+        self.0.execute(contract, msg, funds)
+    }
+}
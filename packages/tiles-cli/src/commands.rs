@@ -0,0 +1,403 @@
+use std::{fs, path::PathBuf};
+
+use clap::{Subcommand, ValueEnum};
+use cosmwasm_std::{to_json_binary, Binary, Timestamp, Uint128};
+use serde_json::{json, Value};
+use tiles::core::{
+    pricing::PriceScaling,
+    tile::metadata::{PixelUpdate, TileMetadata},
+};
+use tiles_client::TilesClient;
+
+use crate::{
+    chain::{Chain, ChainTransport, TxOutcome},
+    error::CliError,
+    generate, messages,
+    profile::Profile,
+    state::DeployState,
+};
+
+/// Seconds between creating the minter and the start of minting
+pub const DEFAULT_START_DELAY: u64 = 60;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ContractKind {
+    Tiles,
+    Minter,
+    Factory,
+}
+
+#[derive(Clone, Debug, PartialEq, Subcommand)]
+pub enum Command {
+    /// Upload a contract wasm and remember its code id
+    StoreCode {
+        #[arg(value_enum)]
+        contract: ContractKind,
+        wasm: PathBuf,
+    },
+    /// Instantiate the vending factory allowing the stored tiles code id
+    InstantiateFactory,
+    /// Create the vending minter and the tiles collection through the factory
+    CreateMinter {
+        /// Mint start in unix seconds, defaults to shortly after now
+        #[arg(long)]
+        start_time: Option<u64>,
+    },
+    /// Mint a tile paying the configured mint price
+    Mint,
+    /// Paint pixels of a tile at the contract's current pricing
+    Paint {
+        token_id: String,
+        /// Pixel updates as `id:color:duration`, e.g. `4:#FF0000:3600`
+        #[arg(required = true, value_parser = parse_pixel_update)]
+        pixels: Vec<PixelUpdate>,
+        /// JSON file with the tile's current metadata, a blank tile is assumed otherwise
+        #[arg(long)]
+        metadata: Option<PathBuf>,
+        /// Pay in this denom instead of the collection's
+        #[arg(long)]
+        denom: Option<String>,
+    },
+    /// Replace the tiles price scaling
+    SetPriceScaling {
+        hour_1_price: u128,
+        hour_12_price: u128,
+        hour_24_price: u128,
+        quadratic_base: u128,
+        /// Price per byte of pixel annotations, free when omitted
+        #[arg(long)]
+        annotation_byte_price: Option<u128>,
+    },
+    /// Withdraw escrowed payouts, everything pending unless an amount is given
+    Withdraw {
+        /// Defaults to the profile denom
+        #[arg(long)]
+        denom: Option<String>,
+        #[arg(long)]
+        amount: Option<u128>,
+    },
+    /// Query the deployed contracts
+    #[command(subcommand)]
+    Query(Query),
+    /// Render the message templates from the profile, validating the result
+    GenerateMessages {
+        #[arg(long, default_value = generate::TEMPLATES_DIR)]
+        templates: PathBuf,
+        #[arg(long, default_value = "scripts/messages/generated")]
+        out_dir: PathBuf,
+        /// Defaults to the stored tiles code id
+        #[arg(long)]
+        tiles_code_id: Option<u64>,
+        /// Mint start in unix seconds, defaults to shortly after now
+        #[arg(long)]
+        start_time: Option<u64>,
+    },
+    /// Print the selected profile
+    Profile,
+}
+
+#[derive(Clone, Debug, PartialEq, Subcommand)]
+pub enum Query {
+    Config,
+    PriceScaling,
+    NumTokens,
+    NftInfo {
+        token_id: String,
+    },
+    OwnerOf {
+        token_id: String,
+    },
+    Tokens {
+        owner: String,
+    },
+    TileSvg {
+        token_id: String,
+        #[arg(long)]
+        scale: Option<u32>,
+        #[arg(long)]
+        grid_lines: Option<bool>,
+    },
+    PendingBalance {
+        address: String,
+    },
+    MinterConfig,
+    FactoryParams,
+}
+
+pub fn parse_pixel_update(value: &str) -> Result<PixelUpdate, String> {
+    let mut parts = value.splitn(3, ':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(id), Some(color), Some(duration)) => Ok(PixelUpdate::new(
+            id.parse()
+                .map_err(|_| format!("invalid pixel id: {}", id))?,
+            color,
+            duration
+                .parse()
+                .map_err(|_| format!("invalid duration: {}", duration))?,
+        )),
+        _ => Err(format!("expected id:color:duration, got {}", value)),
+    }
+}
+
+/// Runs a command against the chain, updating the deployment state with
+/// whatever it produced. Dry runs leave the state untouched.
+pub fn run<C: Chain>(
+    command: &Command,
+    chain: &mut C,
+    state: &mut DeployState,
+    profile: &Profile,
+    now: Timestamp,
+) -> Result<Value, CliError> {
+    let settings = &profile.deploy;
+    match command {
+        Command::StoreCode { contract, wasm } => {
+            let outcome = chain.store_code(wasm)?;
+            if outcome.is_dry_run() {
+                return Ok(summary(&outcome));
+            }
+            let code_id = outcome.code_id()?;
+            let (code_ids, step) = match contract {
+                ContractKind::Tiles => (&mut state.code_ids.tiles, "store_tiles"),
+                ContractKind::Minter => (&mut state.code_ids.minter, "store_minter"),
+                ContractKind::Factory => (&mut state.code_ids.factory, "store_factory"),
+            };
+            *code_ids = Some(code_id);
+            record(state, step, &outcome);
+            Ok(json!({ "txhash": outcome.txhash, "code_id": code_id }))
+        }
+        Command::InstantiateFactory => {
+            let msg = messages::instantiate_factory(
+                settings,
+                state.minter_code_id()?,
+                state.tiles_code_id()?,
+            );
+            let outcome = chain.instantiate(
+                state.factory_code_id()?,
+                &to_json_binary(&msg)?,
+                &[],
+                "Tiles Factory",
+            )?;
+            if outcome.is_dry_run() {
+                return Ok(summary(&outcome));
+            }
+            let factory = outcome
+                .contract_addresses()
+                .into_iter()
+                .next()
+                .ok_or_else(|| outcome.missing("instantiate event"))?;
+            state.contracts.factory = Some(factory.clone());
+            record(state, "instantiate_factory", &outcome);
+            Ok(json!({ "txhash": outcome.txhash, "factory": factory }))
+        }
+        Command::CreateMinter { start_time } => {
+            let start_time = mint_start(*start_time, now);
+            let msg = messages::create_minter(settings, state.tiles_code_id()?, start_time);
+            let factory = state.factory_contract()?.to_string();
+            let outcome = chain.execute(
+                &factory,
+                &to_json_binary(&msg)?,
+                &[messages::coin(settings, settings.creation_fee)],
+            )?;
+            if outcome.is_dry_run() {
+                return Ok(summary(&outcome));
+            }
+            // The minter is instantiated first and instantiates the collection itself
+            let (minter, tiles) = match outcome.contract_addresses().as_slice() {
+                [minter, tiles, ..] => (minter.clone(), tiles.clone()),
+                _ => return Err(outcome.missing("minter and collection instantiate events")),
+            };
+            state.contracts.minter = Some(minter.clone());
+            state.contracts.tiles = Some(tiles.clone());
+            record(state, "create_minter", &outcome);
+
+            // The factory instantiates the collection with the default config and
+            // pricing, the deployer applies the profile's as its royalty payment address
+            let mut client = tiles_client(chain, state)?;
+            let configured = client.update_config(profile.tiles.clone()).send()?;
+            record(state, "update_config", &configured);
+            let priced = match &profile.price_scaling {
+                Some(price_scaling) => {
+                    let priced = client.update_price_scaling(price_scaling.clone()).send()?;
+                    record(state, "set_price_scaling", &priced);
+                    priced.txhash
+                }
+                None => None,
+            };
+            Ok(json!({
+                "txhash": outcome.txhash,
+                "minter": minter,
+                "tiles": tiles,
+                "config_txhash": configured.txhash,
+                "price_scaling_txhash": priced,
+            }))
+        }
+        Command::Mint => {
+            let minter = state.minter_contract()?.to_string();
+            let outcome = chain.execute(
+                &minter,
+                &to_json_binary(&messages::mint())?,
+                &[messages::coin(settings, settings.mint_price)],
+            )?;
+            if outcome.is_dry_run() {
+                return Ok(summary(&outcome));
+            }
+            let token_id = outcome
+                .attribute("wasm", "token_id")
+                .ok_or_else(|| outcome.missing("token_id attribute"))?
+                .to_string();
+            state.minted_tokens.push(token_id.clone());
+            record(state, "mint", &outcome);
+            Ok(json!({ "txhash": outcome.txhash, "token_id": token_id }))
+        }
+        Command::Paint {
+            token_id,
+            pixels,
+            metadata,
+            denom,
+        } => {
+            let mut client = tiles_client(chain, state)?;
+            let current_metadata = match metadata {
+                Some(path) => serde_json::from_str(&fs::read_to_string(path)?)?,
+                None => TileMetadata::new(&client.config()?),
+            };
+            let mut paint = client
+                .set_pixel_color(token_id.clone(), current_metadata)
+                .updates(pixels.iter().cloned());
+            if let Some(denom) = denom {
+                paint = paint.denom(denom.clone());
+            }
+            let outcome = paint.send()?;
+            record(state, "paint", &outcome);
+            Ok(summary(&outcome))
+        }
+        Command::SetPriceScaling {
+            hour_1_price,
+            hour_12_price,
+            hour_24_price,
+            quadratic_base,
+            annotation_byte_price,
+        } => {
+            let price_scaling = PriceScaling {
+                hour_1_price: Uint128::new(*hour_1_price),
+                hour_12_price: Uint128::new(*hour_12_price),
+                hour_24_price: Uint128::new(*hour_24_price),
+                quadratic_base: Uint128::new(*quadratic_base),
+                annotation_byte_price: Uint128::new(annotation_byte_price.unwrap_or_default()),
+            };
+            let mut client = tiles_client(chain, state)?;
+            let outcome = client.update_price_scaling(price_scaling).send()?;
+            record(state, "set_price_scaling", &outcome);
+            Ok(summary(&outcome))
+        }
+        Command::Withdraw { denom, amount } => {
+            let mut client = tiles_client(chain, state)?;
+            let denom = denom.clone().unwrap_or_else(|| settings.denom.clone());
+            let mut withdraw = client.withdraw(denom);
+            if let Some(amount) = amount {
+                withdraw = withdraw.amount(*amount);
+            }
+            let outcome = withdraw.send()?;
+            record(state, "withdraw", &outcome);
+            Ok(summary(&outcome))
+        }
+        Command::Query(query) => run_query(query, chain, state),
+        Command::GenerateMessages {
+            templates,
+            out_dir,
+            tiles_code_id,
+            start_time,
+        } => {
+            let tiles_code_id = match tiles_code_id {
+                Some(code_id) => *code_id,
+                None => state.tiles_code_id()?,
+            };
+            let written = generate::generate(
+                templates,
+                out_dir,
+                profile,
+                tiles_code_id,
+                mint_start(*start_time, now),
+            )?;
+            Ok(json!({ "written": written }))
+        }
+        Command::Profile => Ok(serde_json::to_value(profile)?),
+    }
+}
+
+fn mint_start(start_time: Option<u64>, now: Timestamp) -> Timestamp {
+    start_time
+        .map(Timestamp::from_seconds)
+        .unwrap_or_else(|| now.plus_seconds(DEFAULT_START_DELAY))
+}
+
+fn run_query<C: Chain>(
+    query: &Query,
+    chain: &mut C,
+    state: &DeployState,
+) -> Result<Value, CliError> {
+    let value = match query {
+        Query::Config => serde_json::to_value(tiles_client(chain, state)?.config()?)?,
+        Query::PriceScaling => serde_json::to_value(tiles_client(chain, state)?.price_scaling()?)?,
+        Query::NumTokens => json!({ "count": tiles_client(chain, state)?.num_tokens()? }),
+        Query::NftInfo { token_id } => {
+            serde_json::to_value(tiles_client(chain, state)?.nft_info(token_id)?)?
+        }
+        Query::OwnerOf { token_id } => {
+            serde_json::to_value(tiles_client(chain, state)?.owner_of(token_id).query()?)?
+        }
+        Query::Tokens { owner } => {
+            serde_json::to_value(tiles_client(chain, state)?.tokens(owner).query()?)?
+        }
+        Query::TileSvg {
+            token_id,
+            scale,
+            grid_lines,
+        } => {
+            let client = tiles_client(chain, state)?;
+            let mut tile_svg = client.tile_svg(token_id);
+            if let Some(scale) = scale {
+                tile_svg = tile_svg.scale(*scale);
+            }
+            if let Some(grid_lines) = grid_lines {
+                tile_svg = tile_svg.grid_lines(*grid_lines);
+            }
+            serde_json::to_value(tile_svg.query()?)?
+        }
+        Query::PendingBalance { address } => {
+            serde_json::to_value(tiles_client(chain, state)?.pending_balance(address)?)?
+        }
+        Query::MinterConfig => {
+            let msg = to_json_binary(&vending_minter::msg::QueryMsg::Config {})?;
+            raw_query(chain, state.minter_contract()?, &msg)?
+        }
+        Query::FactoryParams => {
+            let msg = to_json_binary(&sg2::query::Sg2QueryMsg::Params {})?;
+            raw_query(chain, state.factory_contract()?, &msg)?
+        }
+    };
+    Ok(value)
+}
+
+fn tiles_client<'a, C: Chain>(
+    chain: &'a mut C,
+    state: &DeployState,
+) -> Result<TilesClient<ChainTransport<'a, C>>, CliError> {
+    Ok(TilesClient::new(
+        ChainTransport(chain),
+        state.tiles_contract()?,
+    ))
+}
+
+fn raw_query<C: Chain>(chain: &C, contract: &str, msg: &Binary) -> Result<Value, CliError> {
+    Ok(serde_json::from_slice(&chain.query(contract, msg)?)?)
+}
+
+fn record(state: &mut DeployState, step: &str, outcome: &TxOutcome) {
+    if let Some(txhash) = &outcome.txhash {
+        state.record(step, txhash);
+    }
+}
+
+fn summary(outcome: &TxOutcome) -> Value {
+    json!({ "txhash": outcome.txhash, "dry_run": outcome.is_dry_run() })
+}
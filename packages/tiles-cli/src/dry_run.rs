@@ -0,0 +1,89 @@
+use std::{fs, path::Path};
+
+use cosmwasm_std::{from_json, Binary, Coin};
+use serde_json::{json, Value};
+
+use crate::{
+    chain::{Chain, TxOutcome},
+    error::CliError,
+};
+
+/// Collects the messages a command would broadcast instead of sending them.
+/// Queries still go to the wrapped chain so prices and state stay accurate.
+pub struct DryRun<C: Chain> {
+    inner: C,
+    messages: Vec<Value>,
+}
+
+impl<C: Chain> DryRun<C> {
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            messages: vec![],
+        }
+    }
+
+    /// Messages in cosmos SDK JSON form, ready to be signed
+    pub fn messages(&self) -> &[Value] {
+        &self.messages
+    }
+
+    pub fn into_messages(self) -> Vec<Value> {
+        self.messages
+    }
+}
+
+impl<C: Chain> Chain for DryRun<C> {
+    fn sender(&self) -> &str {
+        self.inner.sender()
+    }
+
+    fn store_code(&mut self, wasm: &Path) -> Result<TxOutcome, CliError> {
+        let bytes = fs::read(wasm)?;
+        self.messages.push(json!({
+            "@type": "/cosmwasm.wasm.v1.MsgStoreCode",
+            "sender": self.sender(),
+            "wasm_byte_code": Binary::from(bytes),
+        }));
+        Ok(TxOutcome::dry_run())
+    }
+
+    fn instantiate(
+        &mut self,
+        code_id: u64,
+        msg: &Binary,
+        funds: &[Coin],
+        label: &str,
+    ) -> Result<TxOutcome, CliError> {
+        self.messages.push(json!({
+            "@type": "/cosmwasm.wasm.v1.MsgInstantiateContract",
+            "sender": self.sender(),
+            "admin": self.sender(),
+            "code_id": code_id.to_string(),
+            "label": label,
+            "msg": from_json::<Value>(msg)?,
+            "funds": funds,
+        }));
+        Ok(TxOutcome::dry_run())
+    }
+
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<TxOutcome, CliError> {
+        self.messages.push(json!({
+            "@type": "/cosmwasm.wasm.v1.MsgExecuteContract",
+            "sender": self.sender(),
+            "contract": contract,
+            "msg": from_json::<Value>(msg)?,
+            "funds": funds,
+        }));
+        Ok(TxOutcome::dry_run())
+    }
+
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, CliError> {
+        self.inner.query(contract, msg)
+    }
+}
@@ -0,0 +1,42 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CliError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Chain error: {0}")]
+    Chain(String),
+
+    #[error("Missing deployment state: {0} (run the earlier deploy steps first)")]
+    MissingState(String),
+
+    #[error("Transaction {txhash} has no {what}")]
+    MissingEvent { txhash: String, what: String },
+
+    #[error("Invalid profile: {0}")]
+    InvalidProfile(String),
+
+    #[error("Invalid template: {0}")]
+    InvalidTemplate(String),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+impl From<tiles_client::ClientError<CliError>> for CliError {
+    fn from(err: tiles_client::ClientError<CliError>) -> Self {
+        match err {
+            tiles_client::ClientError::Transport(err) => err,
+            tiles_client::ClientError::Std(err) => CliError::Std(err),
+            err => CliError::InvalidArgument(err.to_string()),
+        }
+    }
+}
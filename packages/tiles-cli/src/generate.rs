@@ -0,0 +1,272 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use sg2::msg::CreateMinterMsg;
+use tiles::contract::msg::InstantiateMsg;
+use vending_factory::msg::VendingMinterInitMsgExtension;
+
+use crate::{error::CliError, profile::Profile};
+
+pub const TEMPLATES_DIR: &str = "scripts/messages/templates";
+pub const INSTANTIATE_TILE: &str = "instantiate_tile.json";
+pub const CREATE_MINTER: &str = "create_minter.json";
+
+pub type TemplateVars = BTreeMap<String, Value>;
+
+/// Values available to templates as `$NAME` placeholders
+pub fn template_vars(profile: &Profile, tiles_code_id: u64, start_time: Timestamp) -> TemplateVars {
+    let deploy = &profile.deploy;
+    let tiles = &profile.tiles;
+    BTreeMap::from([
+        ("DEPLOYER_ADDRESS".to_string(), json!(deploy.deployer)),
+        ("TOKEN_DENOM".to_string(), json!(deploy.denom)),
+        ("COLLECTION_NAME".to_string(), json!(deploy.collection_name)),
+        (
+            "COLLECTION_SYMBOL".to_string(),
+            json!(deploy.collection_symbol),
+        ),
+        (
+            "COLLECTION_DESCRIPTION".to_string(),
+            json!(deploy.collection_description),
+        ),
+        ("COLLECTION_URI".to_string(), json!(deploy.collection_image)),
+        ("BASE_TOKEN_URI".to_string(), json!(deploy.base_token_uri)),
+        (
+            "ROYALTY_SHARE".to_string(),
+            json!(Decimal::percent(deploy.royalty_share_percent)),
+        ),
+        (
+            "MINT_PRICE".to_string(),
+            json!(Uint128::from(deploy.mint_price)),
+        ),
+        ("NUM_TOKENS".to_string(), json!(deploy.num_tokens)),
+        (
+            "PER_ADDRESS_LIMIT".to_string(),
+            json!(deploy.per_address_limit),
+        ),
+        ("START_TIME".to_string(), json!(start_time)),
+        ("TILE_CODE_ID".to_string(), json!(tiles_code_id)),
+        ("PAINT_DENOM".to_string(), json!(tiles.denom)),
+        (
+            "PIXEL_MIN_EXPIRATION".to_string(),
+            json!(tiles.pixel_min_expiration),
+        ),
+        (
+            "PIXEL_MAX_EXPIRATION".to_string(),
+            json!(tiles.pixel_max_expiration),
+        ),
+        ("TILE_WIDTH".to_string(), json!(tiles.tile_width)),
+        ("TILE_HEIGHT".to_string(), json!(tiles.tile_height)),
+        ("DEFAULT_COLOR".to_string(), json!(tiles.default_color)),
+        (
+            "PIXEL_HISTORY_DEPTH".to_string(),
+            json!(tiles.pixel_history_depth),
+        ),
+        ("PAYOUT_MODE".to_string(), json!(tiles.payout_mode)),
+        ("REVENUE_SPLITS".to_string(), json!(tiles.revenue_splits)),
+        (
+            "MIN_PRICE_MULTIPLIER".to_string(),
+            json!(tiles.min_price_multiplier),
+        ),
+        (
+            "MAX_PRICE_MULTIPLIER".to_string(),
+            json!(tiles.max_price_multiplier),
+        ),
+        ("OUTBID".to_string(), json!(tiles.outbid)),
+        (
+            "REVEAL_WINDOW_BLOCKS".to_string(),
+            json!(tiles.reveal_window_blocks),
+        ),
+        (
+            "UNREVEALED_COMMITS".to_string(),
+            json!(tiles.unrevealed_commits),
+        ),
+        (
+            "KEYFRAME_SURCHARGE_BPS".to_string(),
+            json!(tiles.keyframe_surcharge_bps),
+        ),
+        ("MODERATORS".to_string(), json!(tiles.moderators)),
+        (
+            "FREE_OWNER_PAINTING".to_string(),
+            json!(tiles.free_owner_painting),
+        ),
+        ("PRICE_SCALING".to_string(), json!(profile.price_scaling)),
+    ])
+}
+
+/// Substitutes placeholders in every string of the template. A string that is
+/// exactly one placeholder takes the variable's JSON value, so numbers, lists
+/// and `null` keep their type; placeholders inside longer strings are
+/// interpolated as text.
+pub fn render(template: &Value, vars: &TemplateVars) -> Result<Value, CliError> {
+    Ok(match template {
+        Value::String(text) => render_string(text, vars)?,
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| render(item, vars))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), render(value, vars)?)))
+                .collect::<Result<_, CliError>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+fn render_string(text: &str, vars: &TemplateVars) -> Result<Value, CliError> {
+    if let Some(name) = text.strip_prefix('$') {
+        if is_var_name(name) {
+            return lookup(name, vars).cloned();
+        }
+    }
+
+    let mut rendered = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('$') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let len = after
+            .find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'))
+            .unwrap_or(after.len());
+        if len == 0 {
+            rendered.push('$');
+        } else {
+            match lookup(&after[..len], vars)? {
+                Value::String(value) => rendered.push_str(value),
+                value => rendered.push_str(&value.to_string()),
+            }
+        }
+        rest = &after[len..];
+    }
+    rendered.push_str(rest);
+    Ok(Value::String(rendered))
+}
+
+fn is_var_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn lookup<'a>(name: &str, vars: &'a TemplateVars) -> Result<&'a Value, CliError> {
+    vars.get(name)
+        .ok_or_else(|| CliError::InvalidTemplate(format!("unknown placeholder ${}", name)))
+}
+
+fn render_typed<T: DeserializeOwned>(template: &str, vars: &TemplateVars) -> Result<T, CliError> {
+    let rendered = render(&serde_json::from_str(template)?, vars)?;
+    serde_json::from_value(rendered).map_err(|err| CliError::InvalidTemplate(err.to_string()))
+}
+
+/// Renders the tiles instantiate message and checks it would be accepted
+pub fn instantiate_tile(template: &str, vars: &TemplateVars) -> Result<InstantiateMsg, CliError> {
+    let msg: InstantiateMsg = render_typed(template, vars)?;
+    msg.config()
+        .validate()
+        .map_err(|err| CliError::InvalidTemplate(err.to_string()))?;
+    msg.price_scaling()
+        .validate()
+        .map_err(|err| CliError::InvalidTemplate(err.to_string()))?;
+    check_royalty_share(msg.collection_info.royalty_info.as_ref().map(|r| r.share))?;
+    Ok(msg)
+}
+
+/// Renders the factory create minter message and checks its vending parameters
+pub fn create_minter(
+    template: &str,
+    vars: &TemplateVars,
+    profile: &Profile,
+) -> Result<CreateMinterMsg<VendingMinterInitMsgExtension>, CliError> {
+    let msg: CreateMinterMsg<VendingMinterInitMsgExtension> = render_typed(template, vars)?;
+    let deploy = &profile.deploy;
+    if msg.init_msg.mint_price.denom != deploy.denom {
+        return Err(CliError::InvalidTemplate(format!(
+            "mint price must be paid in {}",
+            deploy.denom
+        )));
+    }
+    if msg.init_msg.mint_price.amount < Uint128::from(deploy.min_mint_price) {
+        return Err(CliError::InvalidTemplate(
+            "mint price is below the factory minimum".to_string(),
+        ));
+    }
+    if msg.init_msg.num_tokens == 0 || msg.init_msg.num_tokens > deploy.max_token_limit {
+        return Err(CliError::InvalidTemplate(format!(
+            "num_tokens must be between 1 and {}",
+            deploy.max_token_limit
+        )));
+    }
+    if msg.init_msg.per_address_limit == 0
+        || msg.init_msg.per_address_limit > deploy.max_per_address_limit
+    {
+        return Err(CliError::InvalidTemplate(format!(
+            "per_address_limit must be between 1 and {}",
+            deploy.max_per_address_limit
+        )));
+    }
+    check_royalty_share(
+        msg.collection_params
+            .info
+            .royalty_info
+            .as_ref()
+            .map(|r| r.share),
+    )?;
+    Ok(msg)
+}
+
+fn check_royalty_share(share: Option<Decimal>) -> Result<(), CliError> {
+    match share {
+        Some(share) if share > Decimal::one() => Err(CliError::InvalidTemplate(
+            "royalty share cannot exceed 100%".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Renders both templates into `out_dir`, returning the written files
+pub fn generate(
+    templates_dir: &Path,
+    out_dir: &Path,
+    profile: &Profile,
+    tiles_code_id: u64,
+    start_time: Timestamp,
+) -> Result<Vec<PathBuf>, CliError> {
+    let vars = template_vars(profile, tiles_code_id, start_time);
+    let instantiate_tile = instantiate_tile(
+        &fs::read_to_string(templates_dir.join(INSTANTIATE_TILE))?,
+        &vars,
+    )?;
+    let create_minter = create_minter(
+        &fs::read_to_string(templates_dir.join(CREATE_MINTER))?,
+        &vars,
+        profile,
+    )?;
+
+    fs::create_dir_all(out_dir)?;
+    let written = [
+        (
+            INSTANTIATE_TILE,
+            serde_json::to_string_pretty(&instantiate_tile)?,
+        ),
+        (CREATE_MINTER, serde_json::to_string_pretty(&create_minter)?),
+    ]
+    .into_iter()
+    .map(|(name, contents)| {
+        let path = out_dir.join(name);
+        fs::write(&path, contents)?;
+        Ok(path)
+    })
+    .collect::<Result<_, CliError>>()?;
+    Ok(written)
+}
@@ -0,0 +1,16 @@
+pub mod chain;
+pub mod commands;
+pub mod dry_run;
+pub mod error;
+pub mod generate;
+pub mod messages;
+pub mod profile;
+pub mod settings;
+pub mod starsd;
+pub mod state;
+
+pub use chain::{Chain, TxOutcome};
+pub use error::CliError;
+pub use profile::Profile;
+pub use settings::DeploySettings;
+pub use state::DeployState;
@@ -0,0 +1,108 @@
+use std::{
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::Parser;
+use cosmwasm_std::Timestamp;
+use serde_json::{json, Value};
+use tiles_cli::{
+    commands::{self, Command},
+    dry_run::DryRun,
+    starsd::{StarsdChain, StarsdConfig},
+    CliError, DeployState, Profile,
+};
+
+#[derive(Parser)]
+#[command(name = "tiles-cli", about = "Deploy and operate the tiles collection")]
+struct Cli {
+    /// Deployment state file
+    #[arg(long, global = true, default_value = "scripts/state/deploy.json")]
+    state: PathBuf,
+
+    /// Profile name from the profiles directory, or a path to a TOML/JSON profile
+    #[arg(long, global = true, default_value = "testnet")]
+    profile: String,
+
+    /// Print the messages instead of broadcasting them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Key name or address signing the transactions, defaults to the profile deployer
+    #[arg(long, global = true)]
+    from: Option<String>,
+
+    /// Overrides the profile node
+    #[arg(long, global = true)]
+    node: Option<String>,
+
+    #[arg(long, global = true, default_value = "test")]
+    keyring_backend: String,
+
+    #[arg(long, global = true, default_value = "starsd")]
+    starsd: String,
+
+    /// Seconds to wait for a transaction to be included
+    #[arg(long, global = true, default_value_t = 60)]
+    timeout: u64,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(output) => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output).unwrap_or_default()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<Value, CliError> {
+    let profile = Profile::load(&Profile::resolve(&cli.profile))?;
+    profile.validate()?;
+    let mut state = DeployState::load(&cli.state)?;
+    let network = profile.network.clone();
+    let chain = StarsdChain::new(StarsdConfig {
+        binary: cli.starsd,
+        from: cli.from.unwrap_or_else(|| profile.deploy.deployer.clone()),
+        node: cli.node.unwrap_or(network.node),
+        chain_id: network.chain_id,
+        keyring_backend: cli.keyring_backend,
+        gas_price: network.gas_price,
+        gas_adjustment: network.gas_adjustment,
+        broadcast_mode: network.broadcast_mode,
+        timeout: Duration::from_secs(cli.timeout),
+    })?;
+    let now = Timestamp::from_seconds(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default(),
+    );
+
+    if cli.dry_run {
+        let mut chain = DryRun::new(chain);
+        let output = commands::run(&cli.command, &mut chain, &mut state, &profile, now)?;
+        let messages = chain.into_messages();
+        // Queries have nothing to sign, show their result instead
+        if messages.is_empty() {
+            return Ok(output);
+        }
+        return Ok(json!({ "messages": messages }));
+    }
+
+    let mut chain = chain;
+    let output = commands::run(&cli.command, &mut chain, &mut state, &profile, now)?;
+    state.save(&cli.state)?;
+    Ok(output)
+}
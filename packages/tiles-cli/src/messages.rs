@@ -0,0 +1,81 @@
+use cosmwasm_std::{Coin, Decimal, Timestamp};
+use sg2::msg::{CollectionParams, CreateMinterMsg};
+use sg721::{CollectionInfo, RoyaltyInfoResponse};
+use vending_factory::{
+    msg::{
+        ExecuteMsg as FactoryExecuteMsg, InstantiateMsg as FactoryInstantiateMsg,
+        VendingMinterInitMsgExtension,
+    },
+    state::{ParamsExtension, VendingMinterParams},
+};
+use vending_minter::msg::ExecuteMsg as MinterExecuteMsg;
+
+use crate::settings::DeploySettings;
+
+pub fn instantiate_factory(
+    settings: &DeploySettings,
+    minter_code_id: u64,
+    tiles_code_id: u64,
+) -> FactoryInstantiateMsg {
+    FactoryInstantiateMsg {
+        params: VendingMinterParams {
+            code_id: minter_code_id,
+            allowed_sg721_code_ids: vec![tiles_code_id],
+            frozen: false,
+            creation_fee: coin(settings, settings.creation_fee),
+            min_mint_price: coin(settings, settings.min_mint_price),
+            mint_fee_bps: settings.mint_fee_bps,
+            max_trading_offset_secs: settings.max_trading_offset_secs,
+            extension: ParamsExtension {
+                max_token_limit: settings.max_token_limit,
+                max_per_address_limit: settings.max_per_address_limit,
+                airdrop_mint_price: coin(settings, settings.airdrop_mint_price),
+                airdrop_mint_fee_bps: settings.airdrop_mint_fee_bps,
+                shuffle_fee: coin(settings, settings.shuffle_fee),
+            },
+        },
+    }
+}
+
+pub fn create_minter(
+    settings: &DeploySettings,
+    tiles_code_id: u64,
+    start_time: Timestamp,
+) -> FactoryExecuteMsg {
+    FactoryExecuteMsg::CreateMinter(CreateMinterMsg {
+        init_msg: VendingMinterInitMsgExtension {
+            base_token_uri: settings.base_token_uri.clone(),
+            payment_address: Some(settings.deployer.clone()),
+            start_time,
+            num_tokens: settings.num_tokens,
+            mint_price: coin(settings, settings.mint_price),
+            per_address_limit: settings.per_address_limit,
+            whitelist: None,
+        },
+        collection_params: CollectionParams {
+            code_id: tiles_code_id,
+            name: settings.collection_name.clone(),
+            symbol: settings.collection_symbol.clone(),
+            info: CollectionInfo {
+                creator: settings.deployer.clone(),
+                description: settings.collection_description.clone(),
+                image: settings.collection_image.clone(),
+                external_link: None,
+                explicit_content: Some(false),
+                start_trading_time: None,
+                royalty_info: Some(RoyaltyInfoResponse {
+                    payment_address: settings.deployer.clone(),
+                    share: Decimal::percent(settings.royalty_share_percent),
+                }),
+            },
+        },
+    })
+}
+
+pub fn mint() -> MinterExecuteMsg {
+    MinterExecuteMsg::Mint {}
+}
+
+pub fn coin(settings: &DeploySettings, amount: u64) -> Coin {
+    Coin::new(u128::from(amount), settings.denom.clone())
+}
@@ -0,0 +1,168 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use tiles::core::{config::Config, pricing::PriceScaling};
+
+use crate::{error::CliError, settings::DeploySettings};
+
+/// Directory holding the named profiles, relative to the repository root
+pub const PROFILES_DIR: &str = "profiles";
+
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Where and how transactions are broadcast
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkProfile {
+    pub chain_id: String,
+    pub node: String,
+    /// Gas price including the fee denom, e.g. `0.025ustars`
+    pub gas_price: String,
+    pub gas_adjustment: f64,
+    pub broadcast_mode: String,
+}
+
+/// Everything that differs between networks, loaded at deploy time from a
+/// TOML or JSON file
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub network: NetworkProfile,
+    pub deploy: DeploySettings,
+    /// Tiles config, applied after `create-minter` or used when instantiating
+    /// the collection directly
+    #[serde(default)]
+    pub tiles: Config,
+    /// Initial pricing, applied like `tiles`. The contract default is used when absent.
+    #[serde(default)]
+    pub price_scaling: Option<PriceScaling>,
+}
+
+impl Profile {
+    /// Resolves a profile name such as `testnet` to `profiles/testnet.toml`,
+    /// anything that looks like a file path is used as is
+    pub fn resolve(name_or_path: &str) -> PathBuf {
+        let path = Path::new(name_or_path);
+        if path.extension().is_some() || path.components().count() > 1 {
+            return path.to_path_buf();
+        }
+        Path::new(PROFILES_DIR).join(format!("{}.toml", name_or_path))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, CliError> {
+        let contents = fs::read_to_string(path)?;
+        let profile = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&contents)
+                .map_err(|err| CliError::InvalidProfile(format!("{}: {}", path.display(), err)))?,
+            Some("json") => serde_json::from_str(&contents)?,
+            _ => {
+                return Err(CliError::InvalidProfile(format!(
+                    "{}: expected a .toml or .json file",
+                    path.display()
+                )))
+            }
+        };
+        Ok(profile)
+    }
+
+    pub fn validate(&self) -> Result<(), CliError> {
+        let network = &self.network;
+        let deploy = &self.deploy;
+
+        check(!network.chain_id.is_empty(), "network.chain_id is required")?;
+        check(
+            network.node.starts_with("http://") || network.node.starts_with("https://"),
+            "network.node must be an http(s) URL",
+        )?;
+        let gas_amount = network.gas_price.strip_suffix(deploy.denom.as_str());
+        check(
+            gas_amount.is_some_and(|amount| amount.parse::<f64>().is_ok()),
+            "network.gas_price must be an amount followed by the deploy denom",
+        )?;
+        check(
+            network.gas_adjustment > 0.0,
+            "network.gas_adjustment must be positive",
+        )?;
+        check(
+            ["sync", "async", "block"].contains(&network.broadcast_mode.as_str()),
+            "network.broadcast_mode must be sync, async or block",
+        )?;
+
+        check(
+            is_stars_address(&deploy.deployer),
+            "deploy.deployer must be a stars address",
+        )?;
+        check(!deploy.denom.is_empty(), "deploy.denom is required")?;
+        check(
+            !deploy.collection_name.is_empty(),
+            "deploy.collection_name is required",
+        )?;
+        check(
+            !deploy.collection_symbol.is_empty(),
+            "deploy.collection_symbol is required",
+        )?;
+        check(
+            is_uri(&deploy.collection_image),
+            "deploy.collection_image must be an ipfs:// or https:// URI",
+        )?;
+        check(
+            is_uri(&deploy.base_token_uri),
+            "deploy.base_token_uri must be an ipfs:// or https:// URI",
+        )?;
+        check(
+            deploy.royalty_share_percent <= 100,
+            "deploy.royalty_share_percent cannot exceed 100",
+        )?;
+        check(
+            deploy.mint_price >= deploy.min_mint_price,
+            "deploy.mint_price is below deploy.min_mint_price",
+        )?;
+        check(
+            deploy.num_tokens > 0 && deploy.num_tokens <= deploy.max_token_limit,
+            "deploy.num_tokens must be between 1 and deploy.max_token_limit",
+        )?;
+        check(
+            deploy.per_address_limit > 0
+                && deploy.per_address_limit <= deploy.max_per_address_limit,
+            "deploy.per_address_limit must be between 1 and deploy.max_per_address_limit",
+        )?;
+        check(
+            deploy.mint_fee_bps <= 10_000 && deploy.airdrop_mint_fee_bps <= 10_000,
+            "fee basis points cannot exceed 10000",
+        )?;
+
+        self.tiles
+            .validate()
+            .map_err(|err| CliError::InvalidProfile(format!("tiles: {}", err)))?;
+        if let Some(price_scaling) = &self.price_scaling {
+            price_scaling
+                .validate()
+                .map_err(|err| CliError::InvalidProfile(format!("price_scaling: {}", err)))?;
+        }
+        Ok(())
+    }
+}
+
+fn check(condition: bool, reason: &str) -> Result<(), CliError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(CliError::InvalidProfile(reason.to_string()))
+    }
+}
+
+fn is_stars_address(address: &str) -> bool {
+    match address.strip_prefix("stars1") {
+        Some(data) => data.len() >= 38 && data.chars().all(|c| BECH32_CHARSET.contains(c)),
+        None => false,
+    }
+}
+
+fn is_uri(uri: &str) -> bool {
+    ["ipfs://", "https://"]
+        .iter()
+        .any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme))
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// Parameters of the collection being deployed, read from the `[deploy]`
+/// section of a profile
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DeploySettings {
+    pub deployer: String,
+    pub denom: String,
+    pub collection_name: String,
+    pub collection_symbol: String,
+    pub collection_description: String,
+    pub collection_image: String,
+    pub base_token_uri: String,
+    pub royalty_share_percent: u64,
+    pub mint_price: u64,
+    pub num_tokens: u32,
+    pub per_address_limit: u32,
+    pub creation_fee: u64,
+    pub min_mint_price: u64,
+    pub mint_fee_bps: u64,
+    pub max_token_limit: u32,
+    pub max_per_address_limit: u32,
+    pub max_trading_offset_secs: u64,
+    pub airdrop_mint_price: u64,
+    pub airdrop_mint_fee_bps: u64,
+    pub shuffle_fee: u64,
+}
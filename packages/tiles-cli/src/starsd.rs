@@ -0,0 +1,285 @@
+use std::{
+    path::Path,
+    process::Command,
+    thread,
+    time::{Duration, Instant},
+};
+
+use cosmwasm_std::{Attribute, Binary, Coin, Event};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    chain::{Chain, TxOutcome},
+    error::CliError,
+};
+
+/// Connection and signing options passed to every `starsd` call
+#[derive(Clone, Debug)]
+pub struct StarsdConfig {
+    pub binary: String,
+    pub from: String,
+    pub node: String,
+    pub chain_id: String,
+    pub keyring_backend: String,
+    pub gas_price: String,
+    pub gas_adjustment: f64,
+    pub broadcast_mode: String,
+    /// How long to wait for a broadcast transaction to be included
+    pub timeout: Duration,
+}
+
+/// Broadcasts through the `starsd` binary, which holds the signing keys
+pub struct StarsdChain {
+    config: StarsdConfig,
+    /// Address of `config.from`, which may be a keyring name
+    sender: String,
+}
+
+#[derive(Deserialize)]
+struct BroadcastResponse {
+    txhash: String,
+    #[serde(default)]
+    code: u32,
+    #[serde(default)]
+    raw_log: String,
+}
+
+#[derive(Deserialize)]
+struct TxResponse {
+    #[serde(default)]
+    code: u32,
+    #[serde(default)]
+    raw_log: String,
+    #[serde(default)]
+    events: Vec<RawEvent>,
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default)]
+    attributes: Vec<RawAttribute>,
+}
+
+#[derive(Deserialize)]
+struct RawAttribute {
+    key: String,
+    #[serde(default)]
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct SmartQueryResponse {
+    data: Value,
+}
+
+impl StarsdChain {
+    /// Looks up the signing address when `from` names a key, since message
+    /// senders and contract admins have to be addresses
+    pub fn new(config: StarsdConfig) -> Result<Self, CliError> {
+        let mut chain = Self {
+            sender: config.from.clone(),
+            config,
+        };
+        if !is_address(&chain.config.from) {
+            let args = [
+                "keys",
+                "show",
+                &chain.config.from,
+                "-a",
+                "--keyring-backend",
+                &chain.config.keyring_backend,
+            ]
+            .map(str::to_string);
+            let stdout = chain.run(&args)?;
+            chain.sender = String::from_utf8_lossy(&stdout).trim().to_string();
+        }
+        Ok(chain)
+    }
+
+    fn run(&self, args: &[String]) -> Result<Vec<u8>, CliError> {
+        let output = Command::new(&self.config.binary).args(args).output()?;
+        if !output.status.success() {
+            return Err(CliError::Chain(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        Ok(output.stdout)
+    }
+
+    fn tx(&self, mut args: Vec<String>, funds: &[Coin]) -> Result<TxOutcome, CliError> {
+        if !funds.is_empty() {
+            args.push("--amount".to_string());
+            args.push(
+                funds
+                    .iter()
+                    .map(|coin| format!("{}{}", coin.amount, coin.denom))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+        }
+        let config = &self.config;
+        args.extend(
+            [
+                "--from",
+                &config.from,
+                "--keyring-backend",
+                &config.keyring_backend,
+                "--gas-prices",
+                &config.gas_price,
+                "--gas-adjustment",
+                &config.gas_adjustment.to_string(),
+                "--gas",
+                "auto",
+                "--chain-id",
+                &config.chain_id,
+                "--node",
+                &config.node,
+                "--broadcast-mode",
+                &config.broadcast_mode,
+                "-y",
+                "--output",
+                "json",
+            ]
+            .map(str::to_string),
+        );
+
+        let broadcast: BroadcastResponse = serde_json::from_slice(&self.run(&args)?)?;
+        if broadcast.code != 0 {
+            return Err(CliError::Chain(broadcast.raw_log));
+        }
+        self.wait_for_tx(broadcast.txhash)
+    }
+
+    fn wait_for_tx(&self, txhash: String) -> Result<TxOutcome, CliError> {
+        let started = Instant::now();
+        loop {
+            let args = [
+                "query",
+                "tx",
+                &txhash,
+                "--node",
+                &self.config.node,
+                "--output",
+                "json",
+            ]
+            .map(str::to_string);
+            match self.run(&args) {
+                Ok(stdout) => {
+                    let tx: TxResponse = serde_json::from_slice(&stdout)?;
+                    if tx.code != 0 {
+                        return Err(CliError::Chain(tx.raw_log));
+                    }
+                    return Ok(TxOutcome {
+                        txhash: Some(txhash),
+                        events: tx.events.into_iter().map(into_event).collect(),
+                    });
+                }
+                // Not indexed yet
+                Err(_) if started.elapsed() < self.config.timeout => {
+                    thread::sleep(Duration::from_secs(2))
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+fn into_event(event: RawEvent) -> Event {
+    Event::new(event.ty).add_attributes(
+        event
+            .attributes
+            .into_iter()
+            .map(|attr| Attribute::new(attr.key, attr.value)),
+    )
+}
+
+/// Bech32 addresses are a lowercase prefix, a `1` separator and at least 38
+/// data characters. Anything else is taken to be a keyring name.
+pub fn is_address(from: &str) -> bool {
+    from.rsplit_once('1').is_some_and(|(prefix, data)| {
+        !prefix.is_empty()
+            && data.len() >= 38
+            && from
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    })
+}
+
+fn json_arg(msg: &Binary) -> Result<String, CliError> {
+    String::from_utf8(msg.to_vec())
+        .map_err(|err| CliError::InvalidArgument(format!("message is not UTF-8: {}", err)))
+}
+
+impl Chain for StarsdChain {
+    fn sender(&self) -> &str {
+        &self.sender
+    }
+
+    fn store_code(&mut self, wasm: &Path) -> Result<TxOutcome, CliError> {
+        let args = vec![
+            "tx".to_string(),
+            "wasm".to_string(),
+            "store".to_string(),
+            wasm.display().to_string(),
+        ];
+        self.tx(args, &[])
+    }
+
+    fn instantiate(
+        &mut self,
+        code_id: u64,
+        msg: &Binary,
+        funds: &[Coin],
+        label: &str,
+    ) -> Result<TxOutcome, CliError> {
+        let args = vec![
+            "tx".to_string(),
+            "wasm".to_string(),
+            "instantiate".to_string(),
+            code_id.to_string(),
+            json_arg(msg)?,
+            "--label".to_string(),
+            label.to_string(),
+            "--admin".to_string(),
+            self.sender.clone(),
+        ];
+        self.tx(args, funds)
+    }
+
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<TxOutcome, CliError> {
+        let args = vec![
+            "tx".to_string(),
+            "wasm".to_string(),
+            "execute".to_string(),
+            contract.to_string(),
+            json_arg(msg)?,
+        ];
+        self.tx(args, funds)
+    }
+
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, CliError> {
+        let args = [
+            "query",
+            "wasm",
+            "contract-state",
+            "smart",
+            contract,
+            &json_arg(msg)?,
+            "--node",
+            &self.config.node,
+            "--output",
+            "json",
+        ]
+        .map(str::to_string);
+        let response: SmartQueryResponse = serde_json::from_slice(&self.run(&args)?)?;
+        Ok(Binary::from(serde_json::to_vec(&response.data)?))
+    }
+}
@@ -0,0 +1,87 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::CliError;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct CodeIds {
+    pub tiles: Option<u64>,
+    pub minter: Option<u64>,
+    pub factory: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Contracts {
+    pub factory: Option<String>,
+    pub minter: Option<String>,
+    pub tiles: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TxRecord {
+    pub step: String,
+    pub txhash: String,
+}
+
+/// Everything a deployment has produced so far, kept in a single JSON file
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DeployState {
+    pub code_ids: CodeIds,
+    pub contracts: Contracts,
+    pub minted_tokens: Vec<String>,
+    pub transactions: Vec<TxRecord>,
+}
+
+impl DeployState {
+    /// Loads the state file, starting fresh when it does not exist yet
+    pub fn load(path: &Path) -> Result<Self, CliError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), CliError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn record(&mut self, step: &str, txhash: &str) {
+        self.transactions.push(TxRecord {
+            step: step.to_string(),
+            txhash: txhash.to_string(),
+        });
+    }
+
+    pub fn tiles_code_id(&self) -> Result<u64, CliError> {
+        required(self.code_ids.tiles, "tiles code id")
+    }
+
+    pub fn minter_code_id(&self) -> Result<u64, CliError> {
+        required(self.code_ids.minter, "minter code id")
+    }
+
+    pub fn factory_code_id(&self) -> Result<u64, CliError> {
+        required(self.code_ids.factory, "factory code id")
+    }
+
+    pub fn factory_contract(&self) -> Result<&str, CliError> {
+        required(self.contracts.factory.as_deref(), "factory contract")
+    }
+
+    pub fn minter_contract(&self) -> Result<&str, CliError> {
+        required(self.contracts.minter.as_deref(), "minter contract")
+    }
+
+    pub fn tiles_contract(&self) -> Result<&str, CliError> {
+        required(self.contracts.tiles.as_deref(), "tiles contract")
+    }
+}
+
+fn required<T>(value: Option<T>, what: &str) -> Result<T, CliError> {
+    value.ok_or_else(|| CliError::MissingState(what.to_string()))
+}
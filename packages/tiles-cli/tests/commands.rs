@@ -0,0 +1,482 @@
+use std::{fs, path::PathBuf};
+
+use cosmwasm_std::{from_json, to_json_binary, Binary, Coin, Event, Timestamp, Uint128};
+use serde_json::{json, Value};
+use tiles::{
+    contract::msg::{ExecuteMsg, QueryMsg, TileExecuteMsg},
+    core::{
+        config::Config,
+        pricing::PriceScaling,
+        tile::{metadata::PixelUpdate, Tile},
+    },
+};
+use tiles_cli::{
+    commands::{self, parse_pixel_update, Command, ContractKind, Query},
+    dry_run::DryRun,
+    Chain, CliError, DeployState, Profile, TxOutcome,
+};
+
+/// Answers like a chain would, with canned events for each kind of transaction
+#[derive(Default)]
+struct MockChain {
+    txs: u64,
+    stored: Vec<PathBuf>,
+    instantiated: Vec<(u64, Value, Vec<Coin>)>,
+    executed: Vec<(String, Value, Vec<Coin>)>,
+}
+
+impl MockChain {
+    fn outcome(&mut self, events: Vec<Event>) -> TxOutcome {
+        self.txs += 1;
+        TxOutcome {
+            txhash: Some(format!("TX{}", self.txs)),
+            events,
+        }
+    }
+}
+
+fn instantiate_event(address: &str) -> Event {
+    Event::new("instantiate").add_attribute("_contract_address", address)
+}
+
+impl Chain for MockChain {
+    fn sender(&self) -> &str {
+        "stars1deployer"
+    }
+
+    fn store_code(&mut self, wasm: &std::path::Path) -> Result<TxOutcome, CliError> {
+        self.stored.push(wasm.to_path_buf());
+        let code_id = self.stored.len().to_string();
+        Ok(self.outcome(vec![
+            Event::new("store_code").add_attribute("code_id", code_id)
+        ]))
+    }
+
+    fn instantiate(
+        &mut self,
+        code_id: u64,
+        msg: &Binary,
+        funds: &[Coin],
+        _label: &str,
+    ) -> Result<TxOutcome, CliError> {
+        self.instantiated
+            .push((code_id, from_json(msg)?, funds.to_vec()));
+        Ok(self.outcome(vec![instantiate_event("stars1factory")]))
+    }
+
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<TxOutcome, CliError> {
+        self.executed
+            .push((contract.to_string(), from_json(msg)?, funds.to_vec()));
+        let events = match contract {
+            "stars1factory" => vec![
+                instantiate_event("stars1minter"),
+                instantiate_event("stars1tiles"),
+            ],
+            "stars1minter" => vec![Event::new("wasm").add_attribute("token_id", "1")],
+            _ => vec![],
+        };
+        Ok(self.outcome(events))
+    }
+
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, CliError> {
+        assert_eq!(contract, "stars1tiles");
+        match from_json(msg)? {
+            QueryMsg::Config {} => Ok(to_json_binary(&Config::default())?),
+            QueryMsg::PriceScaling {} => Ok(to_json_binary(&PriceScaling::default())?),
+            QueryMsg::NftInfo { .. } => Ok(to_json_binary(&json!({
+                "token_uri": null,
+                "extension": Tile::new(String::new()),
+            }))?),
+            _ => Err(CliError::Chain("unsupported query".to_string())),
+        }
+    }
+}
+
+fn profile() -> Profile {
+    Profile::load(
+        &PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../profiles")
+            .join("local.toml"),
+    )
+    .unwrap()
+}
+
+fn now() -> Timestamp {
+    Timestamp::from_seconds(1_700_000_000)
+}
+
+fn run(
+    command: Command,
+    chain: &mut impl Chain,
+    state: &mut DeployState,
+) -> Result<Value, CliError> {
+    commands::run(&command, chain, state, &profile(), now())
+}
+
+fn store(contract: ContractKind) -> Command {
+    Command::StoreCode {
+        contract,
+        wasm: PathBuf::from(format!("artifacts/{:?}.wasm", contract)),
+    }
+}
+
+fn deploy(chain: &mut MockChain, state: &mut DeployState) {
+    run(store(ContractKind::Tiles), chain, state).unwrap();
+    run(store(ContractKind::Minter), chain, state).unwrap();
+    run(store(ContractKind::Factory), chain, state).unwrap();
+    run(Command::InstantiateFactory, chain, state).unwrap();
+    run(Command::CreateMinter { start_time: None }, chain, state).unwrap();
+}
+
+#[test]
+fn full_deploy_records_code_ids_and_contracts() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+
+    assert_eq!(state.code_ids.tiles, Some(1));
+    assert_eq!(state.code_ids.minter, Some(2));
+    assert_eq!(state.code_ids.factory, Some(3));
+    assert_eq!(state.contracts.factory.as_deref(), Some("stars1factory"));
+    assert_eq!(state.contracts.minter.as_deref(), Some("stars1minter"));
+    assert_eq!(state.contracts.tiles.as_deref(), Some("stars1tiles"));
+    assert_eq!(state.transactions.len(), 6);
+
+    // The factory only accepts the stored tiles code
+    let (code_id, msg, _) = &chain.instantiated[0];
+    assert_eq!(*code_id, 3);
+    assert_eq!(msg["params"]["code_id"], 2);
+    assert_eq!(msg["params"]["allowed_sg721_code_ids"][0], 1);
+
+    let settings = profile().deploy;
+    let (contract, msg, funds) = &chain.executed[0];
+    assert_eq!(contract, "stars1factory");
+    assert_eq!(
+        funds,
+        &vec![Coin::new(u128::from(settings.creation_fee), settings.denom)]
+    );
+    assert_eq!(msg["create_minter"]["collection_params"]["code_id"], 1);
+    assert_eq!(
+        msg["create_minter"]["init_msg"]["start_time"],
+        now()
+            .plus_seconds(commands::DEFAULT_START_DELAY)
+            .nanos()
+            .to_string()
+    );
+}
+
+#[test]
+fn create_minter_applies_profile_tiles_config() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+
+    // The local profile keeps pixels around for an hour at most
+    let config = profile().tiles;
+    assert_ne!(config, Config::default());
+    let (contract, msg, funds) = &chain.executed[1];
+    assert_eq!(contract, "stars1tiles");
+    assert!(funds.is_empty());
+    assert_eq!(
+        serde_json::from_value::<ExecuteMsg>(msg.clone()).unwrap(),
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::UpdateConfig(config)
+        }
+    );
+    assert_eq!(chain.executed.len(), 2);
+    assert_eq!(state.transactions.last().unwrap().step, "update_config");
+}
+
+#[test]
+fn create_minter_applies_profile_price_scaling() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    let mut profile = profile();
+    let price_scaling = PriceScaling {
+        quadratic_base: Uint128::new(4),
+        ..PriceScaling::default()
+    };
+    profile.price_scaling = Some(price_scaling.clone());
+
+    run(store(ContractKind::Tiles), &mut chain, &mut state).unwrap();
+    run(store(ContractKind::Minter), &mut chain, &mut state).unwrap();
+    run(store(ContractKind::Factory), &mut chain, &mut state).unwrap();
+    run(Command::InstantiateFactory, &mut chain, &mut state).unwrap();
+    let output = commands::run(
+        &Command::CreateMinter { start_time: None },
+        &mut chain,
+        &mut state,
+        &profile,
+        now(),
+    )
+    .unwrap();
+
+    let (contract, msg, _) = chain.executed.last().unwrap();
+    assert_eq!(contract, "stars1tiles");
+    assert_eq!(
+        serde_json::from_value::<ExecuteMsg>(msg.clone()).unwrap(),
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::UpdatePriceScaling(price_scaling)
+        }
+    );
+    assert_eq!(output["price_scaling_txhash"], "TX7");
+}
+
+#[test]
+fn mint_pays_mint_price_and_records_token() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+
+    let output = run(Command::Mint, &mut chain, &mut state).unwrap();
+    assert_eq!(output["token_id"], "1");
+    assert_eq!(state.minted_tokens, vec!["1".to_string()]);
+
+    let settings = profile().deploy;
+    let (contract, _, funds) = chain.executed.last().unwrap();
+    assert_eq!(contract, "stars1minter");
+    assert_eq!(
+        funds,
+        &vec![Coin::new(u128::from(settings.mint_price), settings.denom)]
+    );
+}
+
+#[test]
+fn paint_sends_priced_set_pixel_color() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+
+    run(
+        Command::Paint {
+            token_id: "1".to_string(),
+            pixels: vec![parse_pixel_update("3:#FF0000:3600").unwrap()],
+            metadata: None,
+            denom: None,
+        },
+        &mut chain,
+        &mut state,
+    )
+    .unwrap();
+
+    let (contract, msg, funds) = chain.executed.last().unwrap();
+    assert_eq!(contract, "stars1tiles");
+    let expected = PriceScaling::default().calculate_price(3600);
+    assert_eq!(funds, &vec![Coin::new(expected.u128(), "ustars")]);
+    match serde_json::from_value(msg.clone()).unwrap() {
+        ExecuteMsg::Extension {
+            msg:
+                TileExecuteMsg::SetPixelColor {
+                    current_metadata,
+                    updates,
+                    ..
+                },
+        } => {
+            assert_eq!(
+                current_metadata.pixels.len() as u32,
+                Config::default().pixels_per_tile()
+            );
+            assert_eq!(updates[0].id, 3);
+        }
+        _ => panic!("unexpected message"),
+    }
+    assert_eq!(state.transactions.last().unwrap().step, "paint");
+}
+
+#[test]
+fn set_price_scaling_targets_tiles_contract() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+
+    run(
+        Command::SetPriceScaling {
+            hour_1_price: 1,
+            hour_12_price: 2,
+            hour_24_price: 3,
+            quadratic_base: 4,
+            annotation_byte_price: None,
+        },
+        &mut chain,
+        &mut state,
+    )
+    .unwrap();
+
+    let (contract, msg, funds) = chain.executed.last().unwrap();
+    assert_eq!(contract, "stars1tiles");
+    assert!(funds.is_empty());
+    assert_eq!(
+        msg["extension"]["msg"]["update_price_scaling"]["quadratic_base"],
+        "4"
+    );
+}
+
+#[test]
+fn queries_read_the_tiles_contract() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+
+    let config = run(Command::Query(Query::Config), &mut chain, &mut state).unwrap();
+    assert_eq!(
+        serde_json::from_value::<Config>(config).unwrap(),
+        Config::default()
+    );
+}
+
+#[test]
+fn steps_require_earlier_state() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+
+    for command in [
+        Command::InstantiateFactory,
+        Command::CreateMinter { start_time: None },
+        Command::Mint,
+        Command::Query(Query::Config),
+    ] {
+        let err = run(command, &mut chain, &mut state).unwrap_err();
+        assert!(matches!(err, CliError::MissingState(_)));
+    }
+    assert!(chain.executed.is_empty());
+    assert_eq!(state, DeployState::default());
+}
+
+#[test]
+fn missing_events_are_reported() {
+    #[derive(Default)]
+    struct SilentChain(MockChain);
+
+    impl Chain for SilentChain {
+        fn sender(&self) -> &str {
+            self.0.sender()
+        }
+
+        fn store_code(&mut self, _wasm: &std::path::Path) -> Result<TxOutcome, CliError> {
+            Ok(self.0.outcome(vec![]))
+        }
+
+        fn instantiate(
+            &mut self,
+            _code_id: u64,
+            _msg: &Binary,
+            _funds: &[Coin],
+            _label: &str,
+        ) -> Result<TxOutcome, CliError> {
+            Ok(self.0.outcome(vec![]))
+        }
+
+        fn execute(
+            &mut self,
+            _contract: &str,
+            _msg: &Binary,
+            _funds: &[Coin],
+        ) -> Result<TxOutcome, CliError> {
+            Ok(self.0.outcome(vec![]))
+        }
+
+        fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, CliError> {
+            self.0.query(contract, msg)
+        }
+    }
+
+    let mut state = DeployState::default();
+    let err = run(
+        store(ContractKind::Tiles),
+        &mut SilentChain::default(),
+        &mut state,
+    )
+    .unwrap_err();
+    assert!(matches!(err, CliError::MissingEvent { txhash, .. } if txhash == "TX1"));
+    assert_eq!(state.code_ids.tiles, None);
+}
+
+#[test]
+fn dry_run_prints_messages_and_keeps_state() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+    let before = state.clone();
+
+    let mut dry_run = DryRun::new(chain);
+    run(Command::Mint, &mut dry_run, &mut state).unwrap();
+    run(
+        Command::Paint {
+            token_id: "1".to_string(),
+            pixels: vec![PixelUpdate::new(0, "#00FF00", 3600)],
+            metadata: None,
+            denom: None,
+        },
+        &mut dry_run,
+        &mut state,
+    )
+    .unwrap();
+
+    assert_eq!(state, before);
+    let messages = dry_run.messages();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0]["@type"], "/cosmwasm.wasm.v1.MsgExecuteContract");
+    assert_eq!(messages[0]["sender"], "stars1deployer");
+    assert_eq!(messages[0]["contract"], "stars1minter");
+    assert_eq!(messages[0]["msg"]["mint"], serde_json::json!({}));
+    assert_eq!(messages[1]["contract"], "stars1tiles");
+    assert!(messages[1]["msg"]["extension"]["msg"]["set_pixel_color"].is_object());
+}
+
+#[test]
+fn dry_run_store_code_describes_the_wasm() {
+    let wasm = std::env::temp_dir().join("tiles-cli-dry-run.wasm");
+    fs::write(&wasm, b"\0asm").unwrap();
+
+    let mut dry_run = DryRun::new(MockChain::default());
+    let mut state = DeployState::default();
+    run(
+        Command::StoreCode {
+            contract: ContractKind::Tiles,
+            wasm: wasm.clone(),
+        },
+        &mut dry_run,
+        &mut state,
+    )
+    .unwrap();
+
+    let message = &dry_run.messages()[0];
+    assert_eq!(message["@type"], "/cosmwasm.wasm.v1.MsgStoreCode");
+    // Base64 of the file, as MsgStoreCode expects
+    assert_eq!(message["wasm_byte_code"], "AGFzbQ==");
+    assert_eq!(state.code_ids.tiles, None);
+    fs::remove_file(wasm).unwrap();
+}
+
+#[test]
+fn state_round_trips_through_file() {
+    let mut chain = MockChain::default();
+    let mut state = DeployState::default();
+    deploy(&mut chain, &mut state);
+    run(Command::Mint, &mut chain, &mut state).unwrap();
+
+    let path = std::env::temp_dir()
+        .join("tiles-cli-state")
+        .join("deploy.json");
+    state.save(&path).unwrap();
+    assert_eq!(DeployState::load(&path).unwrap(), state);
+    fs::remove_file(&path).unwrap();
+
+    // A missing file is a fresh deployment
+    assert_eq!(DeployState::load(&path).unwrap(), DeployState::default());
+}
+
+#[test]
+fn parses_pixel_updates() {
+    assert_eq!(
+        parse_pixel_update("12:#ABCDEF:7200").unwrap(),
+        PixelUpdate::new(12, "#ABCDEF", 7200)
+    );
+    assert!(parse_pixel_update("12:#ABCDEF").is_err());
+    assert!(parse_pixel_update("x:#ABCDEF:7200").is_err());
+}
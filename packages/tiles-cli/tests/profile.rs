@@ -0,0 +1,224 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+use serde_json::json;
+use tiles::contract::msg::InstantiateMsg;
+use tiles_cli::{
+    generate::{self, template_vars, CREATE_MINTER, INSTANTIATE_TILE},
+    CliError, Profile,
+};
+
+fn repo_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn load(name: &str) -> Profile {
+    Profile::load(&repo_root().join("profiles").join(format!("{}.toml", name))).unwrap()
+}
+
+fn template(name: &str) -> String {
+    fs::read_to_string(repo_root().join(generate::TEMPLATES_DIR).join(name)).unwrap()
+}
+
+fn start_time() -> Timestamp {
+    Timestamp::from_seconds(1_700_000_060)
+}
+
+fn assert_invalid(profile: &Profile) {
+    assert!(matches!(
+        profile.validate(),
+        Err(CliError::InvalidProfile(_))
+    ));
+}
+
+#[test]
+fn shipped_profiles_load() {
+    load("local").validate().unwrap();
+    load("testnet").validate().unwrap();
+
+    // Mainnet ships without a deployer so it cannot be used by accident
+    let mainnet = load("mainnet");
+    assert_eq!(mainnet.network.chain_id, "stargaze-1");
+    assert_invalid(&mainnet);
+}
+
+#[test]
+fn resolves_names_and_paths() {
+    assert_eq!(
+        Profile::resolve("testnet"),
+        Path::new("profiles").join("testnet.toml")
+    );
+    assert_eq!(
+        Profile::resolve("deploy/custom.json"),
+        PathBuf::from("deploy/custom.json")
+    );
+}
+
+#[test]
+fn loads_json_profiles() {
+    let profile = load("testnet");
+    let path = std::env::temp_dir().join("tiles-cli-profile.json");
+    fs::write(&path, serde_json::to_string(&profile).unwrap()).unwrap();
+    assert_eq!(Profile::load(&path).unwrap(), profile);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn rejects_unknown_fields() {
+    let path = std::env::temp_dir().join("tiles-cli-unknown.toml");
+    let contents = fs::read_to_string(repo_root().join("profiles/testnet.toml")).unwrap();
+    fs::write(
+        &path,
+        contents.replace("[deploy]", "[deploy]\nmint_prize = 1"),
+    )
+    .unwrap();
+    assert!(matches!(
+        Profile::load(&path),
+        Err(CliError::InvalidProfile(_))
+    ));
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn rejects_invalid_profiles() {
+    let valid = load("testnet");
+
+    let mut profile = valid.clone();
+    profile.deploy.deployer = "cosmos1pnet2e7tz7klwy48r7h3wl0n97td0haqjvs7mx".to_string();
+    assert_invalid(&profile);
+
+    let mut profile = valid.clone();
+    profile.network.gas_price = "0.025uatom".to_string();
+    assert_invalid(&profile);
+
+    let mut profile = valid.clone();
+    profile.network.node = "localhost:26657".to_string();
+    assert_invalid(&profile);
+
+    let mut profile = valid.clone();
+    profile.deploy.base_token_uri = "bafybeid".to_string();
+    assert_invalid(&profile);
+
+    let mut profile = valid.clone();
+    profile.deploy.num_tokens = profile.deploy.max_token_limit + 1;
+    assert_invalid(&profile);
+
+    let mut profile = valid.clone();
+    profile.deploy.min_mint_price = profile.deploy.mint_price + 1;
+    assert_invalid(&profile);
+
+    let mut profile = valid.clone();
+    profile.tiles.tile_width = 0;
+    assert_invalid(&profile);
+}
+
+#[test]
+fn renders_instantiate_tile_from_profile() {
+    let profile = load("local");
+    let vars = template_vars(&profile, 7, start_time());
+    let msg = generate::instantiate_tile(&template(INSTANTIATE_TILE), &vars).unwrap();
+
+    assert_eq!(msg.name, profile.deploy.collection_name);
+    assert_eq!(msg.minter, profile.deploy.deployer);
+    assert_eq!(msg.config(), profile.tiles);
+    assert_eq!(msg.price_scaling, None);
+    assert_eq!(
+        msg.collection_info.royalty_info.unwrap().share,
+        Decimal::percent(profile.deploy.royalty_share_percent)
+    );
+}
+
+#[test]
+fn renders_create_minter_from_profile() {
+    let profile = load("testnet");
+    let vars = template_vars(&profile, 7, start_time());
+    let msg = generate::create_minter(&template(CREATE_MINTER), &vars, &profile).unwrap();
+
+    assert_eq!(msg.collection_params.code_id, 7);
+    assert_eq!(msg.init_msg.start_time, start_time());
+    assert_eq!(msg.init_msg.num_tokens, profile.deploy.num_tokens);
+    assert_eq!(
+        msg.init_msg.mint_price.amount,
+        Uint128::from(profile.deploy.mint_price)
+    );
+    assert_eq!(msg.init_msg.mint_price.denom, profile.deploy.denom);
+}
+
+#[test]
+fn render_keeps_types_and_interpolates() {
+    let profile = load("testnet");
+    let vars = template_vars(&profile, 7, start_time());
+    let rendered = generate::render(
+        &json!({
+            "code_id": "$TILE_CODE_ID",
+            "denom": "$PAINT_DENOM",
+            "price_scaling": "$PRICE_SCALING",
+            "label": "$COLLECTION_NAME on $TOKEN_DENOM",
+            "literal": "costs 5$",
+        }),
+        &vars,
+    )
+    .unwrap();
+
+    assert_eq!(
+        rendered,
+        json!({
+            "code_id": 7,
+            "denom": "ustars",
+            "price_scaling": null,
+            "label": "Tiles on ustars",
+            "literal": "costs 5$",
+        })
+    );
+}
+
+#[test]
+fn rejects_bad_templates() {
+    let profile = load("testnet");
+    let vars = template_vars(&profile, 7, start_time());
+
+    let unknown = template(INSTANTIATE_TILE).replace("$COLLECTION_NAME", "$COLLECTION_TITLE");
+    assert!(matches!(
+        generate::instantiate_tile(&unknown, &vars),
+        Err(CliError::InvalidTemplate(_))
+    ));
+
+    // Renders to a message the contract would refuse
+    let too_wide = template(INSTANTIATE_TILE).replace("\"$TILE_WIDTH\"", "100");
+    assert!(matches!(
+        generate::instantiate_tile(&too_wide, &vars),
+        Err(CliError::InvalidTemplate(_))
+    ));
+
+    let wrong_denom = template(CREATE_MINTER).replace("$TOKEN_DENOM", "uatom");
+    assert!(matches!(
+        generate::create_minter(&wrong_denom, &vars, &profile),
+        Err(CliError::InvalidTemplate(_))
+    ));
+}
+
+#[test]
+fn generate_writes_both_messages() {
+    let profile = load("local");
+    let out_dir = std::env::temp_dir().join("tiles-cli-generated");
+    let written = generate::generate(
+        &repo_root().join(generate::TEMPLATES_DIR),
+        &out_dir,
+        &profile,
+        7,
+        start_time(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        written,
+        vec![out_dir.join(INSTANTIATE_TILE), out_dir.join(CREATE_MINTER)]
+    );
+    let msg: InstantiateMsg =
+        serde_json::from_str(&fs::read_to_string(&written[0]).unwrap()).unwrap();
+    assert_eq!(msg.config(), profile.tiles);
+    fs::remove_dir_all(out_dir).unwrap();
+}
@@ -0,0 +1,65 @@
+use std::{
+    fs,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use tiles_cli::{
+    starsd::{is_address, StarsdChain, StarsdConfig},
+    Chain,
+};
+
+const ADDRESS: &str = "stars1pnet2e7tz7klwy48r7h3wl0n97td0haqjvs7mx";
+
+/// Stand-in `starsd` that only knows `keys show <name> -a`
+fn fake_starsd(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("tiles-cli-starsd-{}", name));
+    fs::write(
+        &path,
+        format!(
+            "#!/bin/sh\n[ \"$1 $2 $3 $4\" = \"keys show {} -a\" ] && echo {} && exit 0\nexit 1\n",
+            name, ADDRESS
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+fn config(binary: &Path, from: &str) -> StarsdConfig {
+    StarsdConfig {
+        binary: binary.display().to_string(),
+        from: from.to_string(),
+        node: "http://localhost:26657".to_string(),
+        chain_id: "testing".to_string(),
+        keyring_backend: "test".to_string(),
+        gas_price: "0.025ustars".to_string(),
+        gas_adjustment: 1.3,
+        broadcast_mode: "sync".to_string(),
+        timeout: Duration::from_secs(1),
+    }
+}
+
+#[test]
+fn key_names_resolve_to_addresses() {
+    let binary = fake_starsd("deployer");
+
+    let chain = StarsdChain::new(config(&binary, "deployer")).unwrap();
+    assert_eq!(chain.sender(), ADDRESS);
+
+    // Addresses are used as given, without asking starsd
+    let chain = StarsdChain::new(config(Path::new("/nonexistent"), ADDRESS)).unwrap();
+    assert_eq!(chain.sender(), ADDRESS);
+
+    assert!(StarsdChain::new(config(&binary, "unknown")).is_err());
+    fs::remove_file(binary).unwrap();
+}
+
+#[test]
+fn addresses_are_told_apart_from_key_names() {
+    assert!(is_address(ADDRESS));
+    assert!(!is_address("deployer"));
+    assert!(!is_address("my1key"));
+    assert!(!is_address(&ADDRESS.to_uppercase()));
+}
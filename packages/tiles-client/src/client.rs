@@ -0,0 +1,401 @@
+use cosmwasm_std::{from_json, to_json_binary, Binary, Coin};
+use cw721::{ContractInfoResponse, NftInfoResponse, NumTokensResponse};
+use cw721_base::MinterResponse;
+use serde::de::DeserializeOwned;
+use sg721_base::msg::CollectionInfoResponse;
+use tiles::{
+    contract::msg::{
+        Cw20TokensResponse, ExecuteMsg, OwnerEarningsResponse, PaintCommitResponse,
+        PainterStatsResponse, PaintersResponse, PendingBalanceResponse, PixelResponse, QueryMsg,
+        TakeoverPriceResponse, TileEarningsResponse, TileExecuteMsg,
+    },
+    core::{
+        config::Config,
+        painter::PainterRanking,
+        pricing::PriceScaling,
+        tile::{
+            image::ImageFormat,
+            layer::BasePixel,
+            metadata::{PixelUpdate, TileMetadata},
+            policy::TilePolicy,
+            Tile,
+        },
+    },
+};
+
+use crate::{
+    error::ClientError,
+    execute::{
+        CommitPaint, GrantPainter, PaintImage, SetCw20PriceScaling, SetPixelColor, SetTilePolicy,
+        UpdateConfig, UpdatePriceScaling, Withdraw,
+    },
+    query::{
+        AllNftInfo, AllOperators, AllTokens, Approval, Approvals, OwnerOf, PixelHistory,
+        TileHistory, TileSvg, Tokens, TopPainters,
+    },
+    transport::Transport,
+};
+
+pub type ClientResult<T, R> = Result<R, ClientError<<T as Transport>::Error>>;
+
+/// Typed entry point to one deployed tiles contract
+pub struct TilesClient<T: Transport> {
+    transport: T,
+    contract: String,
+}
+
+impl<T: Transport> TilesClient<T> {
+    pub fn new(transport: T, contract: impl Into<String>) -> Self {
+        Self {
+            transport,
+            contract: contract.into(),
+        }
+    }
+
+    pub fn contract(&self) -> &str {
+        &self.contract
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    pub fn into_transport(self) -> T {
+        self.transport
+    }
+
+    /// Sends any query and decodes the response into `R`
+    pub fn query<R: DeserializeOwned>(&self, msg: &QueryMsg) -> ClientResult<T, R> {
+        let response = self
+            .transport
+            .query(&self.contract, &to_json_binary(msg)?)
+            .map_err(ClientError::Transport)?;
+        Ok(from_json(response)?)
+    }
+
+    /// Sends any execute message with the given funds
+    pub fn execute(&mut self, msg: &ExecuteMsg, funds: &[Coin]) -> ClientResult<T, T::Response> {
+        let msg = to_json_binary(msg)?;
+        self.transport
+            .execute(&self.contract, &msg, funds)
+            .map_err(ClientError::Transport)
+    }
+
+    pub fn set_pixel_color(
+        &mut self,
+        token_id: impl Into<String>,
+        current_metadata: TileMetadata,
+    ) -> SetPixelColor<'_, T> {
+        SetPixelColor::new(self, token_id.into(), current_metadata)
+    }
+
+    pub fn update_price_scaling(
+        &mut self,
+        price_scaling: PriceScaling,
+    ) -> UpdatePriceScaling<'_, T> {
+        UpdatePriceScaling::new(self, price_scaling)
+    }
+
+    pub fn update_config(&mut self, config: Config) -> UpdateConfig<'_, T> {
+        UpdateConfig::new(self, config)
+    }
+
+    pub fn set_cw20_price_scaling(
+        &mut self,
+        token: impl Into<String>,
+    ) -> SetCw20PriceScaling<'_, T> {
+        SetCw20PriceScaling::new(self, token.into())
+    }
+
+    pub fn set_tile_policy(
+        &mut self,
+        token_id: impl Into<String>,
+        policy: TilePolicy,
+    ) -> SetTilePolicy<'_, T> {
+        SetTilePolicy::new(self, token_id.into(), policy)
+    }
+
+    pub fn grant_painter(
+        &mut self,
+        token_id: impl Into<String>,
+        painter: impl Into<String>,
+    ) -> GrantPainter<'_, T> {
+        GrantPainter::new(self, token_id.into(), painter.into())
+    }
+
+    pub fn revoke_painter(
+        &mut self,
+        token_id: impl Into<String>,
+        painter: impl Into<String>,
+    ) -> ClientResult<T, T::Response> {
+        let msg = ExecuteMsg::Extension {
+            msg: TileExecuteMsg::RevokePainter {
+                token_id: token_id.into(),
+                painter: painter.into(),
+            },
+        };
+        self.execute(&msg, &[])
+    }
+
+    pub fn set_base_layer(
+        &mut self,
+        token_id: impl Into<String>,
+        pixels: Vec<BasePixel>,
+    ) -> ClientResult<T, T::Response> {
+        let msg = ExecuteMsg::Extension {
+            msg: TileExecuteMsg::SetBaseLayer {
+                token_id: token_id.into(),
+                pixels,
+            },
+        };
+        self.execute(&msg, &[])
+    }
+
+    /// Removes pixel annotations, only moderators may send it
+    pub fn clear_annotations(
+        &mut self,
+        token_id: impl Into<String>,
+        pixel_ids: Vec<u32>,
+    ) -> ClientResult<T, T::Response> {
+        let msg = ExecuteMsg::Extension {
+            msg: TileExecuteMsg::ClearAnnotations {
+                token_id: token_id.into(),
+                pixel_ids,
+            },
+        };
+        self.execute(&msg, &[])
+    }
+
+    /// Paints a bitmap over the whole tile, priced by the pixels it changes
+    pub fn paint_image(
+        &mut self,
+        token_id: impl Into<String>,
+        current_metadata: TileMetadata,
+        format: ImageFormat,
+        data: impl Into<Binary>,
+        duration: u64,
+    ) -> PaintImage<'_, T> {
+        PaintImage::new(
+            self,
+            token_id.into(),
+            current_metadata,
+            format,
+            data.into(),
+            duration,
+        )
+    }
+
+    /// Hides a paint behind a commitment bound to `committer` and `salt`, see
+    /// [`paint_commitment`](tiles::core::tile::commit::paint_commitment)
+    pub fn commit_paint(
+        &mut self,
+        token_id: impl Into<String>,
+        current_metadata: TileMetadata,
+        committer: impl Into<String>,
+        salt: impl Into<String>,
+    ) -> CommitPaint<'_, T> {
+        CommitPaint::new(
+            self,
+            token_id.into(),
+            current_metadata,
+            committer.into(),
+            salt.into(),
+        )
+    }
+
+    pub fn reveal_paint(
+        &mut self,
+        token_id: impl Into<String>,
+        updates: Vec<PixelUpdate>,
+        salt: impl Into<String>,
+    ) -> ClientResult<T, T::Response> {
+        let msg = ExecuteMsg::Extension {
+            msg: TileExecuteMsg::RevealPaint {
+                token_id: token_id.into(),
+                updates,
+                salt: salt.into(),
+            },
+        };
+        self.execute(&msg, &[])
+    }
+
+    pub fn expire_commit(
+        &mut self,
+        token_id: impl Into<String>,
+        committer: impl Into<String>,
+    ) -> ClientResult<T, T::Response> {
+        let msg = ExecuteMsg::Extension {
+            msg: TileExecuteMsg::ExpireCommit {
+                token_id: token_id.into(),
+                committer: committer.into(),
+            },
+        };
+        self.execute(&msg, &[])
+    }
+
+    pub fn withdraw(&mut self, denom: impl Into<String>) -> Withdraw<'_, T> {
+        Withdraw::new(self, denom.into())
+    }
+
+    pub fn owner_of(&self, token_id: impl Into<String>) -> OwnerOf<'_, T> {
+        OwnerOf::new(self, token_id.into())
+    }
+
+    pub fn approval(
+        &self,
+        token_id: impl Into<String>,
+        spender: impl Into<String>,
+    ) -> Approval<'_, T> {
+        Approval::new(self, token_id.into(), spender.into())
+    }
+
+    pub fn approvals(&self, token_id: impl Into<String>) -> Approvals<'_, T> {
+        Approvals::new(self, token_id.into())
+    }
+
+    pub fn all_operators(&self, owner: impl Into<String>) -> AllOperators<'_, T> {
+        AllOperators::new(self, owner.into())
+    }
+
+    pub fn num_tokens(&self) -> ClientResult<T, u64> {
+        let response: NumTokensResponse = self.query(&QueryMsg::NumTokens {})?;
+        Ok(response.count)
+    }
+
+    pub fn contract_info(&self) -> ClientResult<T, ContractInfoResponse> {
+        self.query(&QueryMsg::ContractInfo {})
+    }
+
+    pub fn nft_info(&self, token_id: impl Into<String>) -> ClientResult<T, NftInfoResponse<Tile>> {
+        self.query(&QueryMsg::NftInfo {
+            token_id: token_id.into(),
+        })
+    }
+
+    pub fn all_nft_info(&self, token_id: impl Into<String>) -> AllNftInfo<'_, T> {
+        AllNftInfo::new(self, token_id.into())
+    }
+
+    pub fn tokens(&self, owner: impl Into<String>) -> Tokens<'_, T> {
+        Tokens::new(self, owner.into())
+    }
+
+    pub fn all_tokens(&self) -> AllTokens<'_, T> {
+        AllTokens::new(self)
+    }
+
+    pub fn minter(&self) -> ClientResult<T, MinterResponse> {
+        self.query(&QueryMsg::Minter {})
+    }
+
+    pub fn collection_info(&self) -> ClientResult<T, CollectionInfoResponse> {
+        self.query(&QueryMsg::CollectionInfo {})
+    }
+
+    pub fn price_scaling(&self) -> ClientResult<T, PriceScaling> {
+        self.query(&QueryMsg::PriceScaling {})
+    }
+
+    pub fn config(&self) -> ClientResult<T, Config> {
+        self.query(&QueryMsg::Config {})
+    }
+
+    pub fn cw20_tokens(&self) -> ClientResult<T, Cw20TokensResponse> {
+        self.query(&QueryMsg::Cw20Tokens {})
+    }
+
+    pub fn painters(&self, token_id: impl Into<String>) -> ClientResult<T, PaintersResponse> {
+        self.query(&QueryMsg::Painters {
+            token_id: token_id.into(),
+        })
+    }
+
+    pub fn paint_commit(
+        &self,
+        token_id: impl Into<String>,
+        committer: impl Into<String>,
+    ) -> ClientResult<T, PaintCommitResponse> {
+        self.query(&QueryMsg::PaintCommit {
+            token_id: token_id.into(),
+            committer: committer.into(),
+        })
+    }
+
+    pub fn takeover_price(
+        &self,
+        token_id: impl Into<String>,
+        pixel_id: u32,
+    ) -> ClientResult<T, TakeoverPriceResponse> {
+        self.query(&QueryMsg::TakeoverPrice {
+            token_id: token_id.into(),
+            pixel_id,
+        })
+    }
+
+    pub fn pixel(
+        &self,
+        token_id: impl Into<String>,
+        pixel_id: u32,
+    ) -> ClientResult<T, PixelResponse> {
+        self.query(&QueryMsg::Pixel {
+            token_id: token_id.into(),
+            pixel_id,
+        })
+    }
+
+    pub fn tile_svg(&self, token_id: impl Into<String>) -> TileSvg<'_, T> {
+        TileSvg::new(self, token_id.into())
+    }
+
+    pub fn pixel_history(&self, token_id: impl Into<String>, pixel_id: u32) -> PixelHistory<'_, T> {
+        PixelHistory::new(self, token_id.into(), pixel_id)
+    }
+
+    pub fn tile_history(&self, token_id: impl Into<String>) -> TileHistory<'_, T> {
+        TileHistory::new(self, token_id.into())
+    }
+
+    pub fn painter_stats(
+        &self,
+        address: impl Into<String>,
+    ) -> ClientResult<T, PainterStatsResponse> {
+        self.query(&QueryMsg::PainterStats {
+            address: address.into(),
+        })
+    }
+
+    pub fn top_painters(&self, by: PainterRanking) -> TopPainters<'_, T> {
+        TopPainters::new(self, by)
+    }
+
+    pub fn tile_earnings(
+        &self,
+        token_id: impl Into<String>,
+    ) -> ClientResult<T, TileEarningsResponse> {
+        self.query(&QueryMsg::TileEarnings {
+            token_id: token_id.into(),
+        })
+    }
+
+    pub fn pending_balance(
+        &self,
+        address: impl Into<String>,
+    ) -> ClientResult<T, PendingBalanceResponse> {
+        self.query(&QueryMsg::PendingBalance {
+            address: address.into(),
+        })
+    }
+
+    pub fn owner_earnings(
+        &self,
+        owner: impl Into<String>,
+    ) -> ClientResult<T, OwnerEarningsResponse> {
+        self.query(&QueryMsg::OwnerEarnings {
+            owner: owner.into(),
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ClientError<E> {
+    #[error("Transport error: {0}")]
+    Transport(E),
+
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("No pixel updates to send")]
+    NoUpdates,
+
+    #[error("Invalid image: {0}")]
+    InvalidImage(String),
+}
@@ -0,0 +1,644 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cosmwasm_std::{Addr, Binary, Coin, Uint128};
+use cw721::Expiration;
+use tiles::{
+    contract::msg::{ExecuteMsg, TileExecuteMsg},
+    core::{
+        config::Config,
+        pricing::PriceScaling,
+        tile::{
+            commit::paint_commitment,
+            image::ImageFormat,
+            metadata::{PixelUpdate, TileMetadata},
+            policy::TilePolicy,
+        },
+    },
+};
+
+use crate::{
+    client::{ClientResult, TilesClient},
+    error::ClientError,
+    transport::Transport,
+};
+
+/// Paints pixels of a tile, pricing the updates and attaching the funds on send
+pub struct SetPixelColor<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    token_id: String,
+    current_metadata: TileMetadata,
+    updates: Vec<PixelUpdate>,
+    denom: Option<String>,
+    funds: Option<Coin>,
+    free: bool,
+}
+
+impl<'a, T: Transport> SetPixelColor<'a, T> {
+    pub(crate) fn new(
+        client: &'a mut TilesClient<T>,
+        token_id: String,
+        current_metadata: TileMetadata,
+    ) -> Self {
+        Self {
+            client,
+            token_id,
+            current_metadata,
+            updates: vec![],
+            denom: None,
+            funds: None,
+            free: false,
+        }
+    }
+
+    pub fn pixel(self, id: u32, color: impl Into<String>, expiration_duration: u64) -> Self {
+        self.update(PixelUpdate::new(id, color, expiration_duration))
+    }
+
+    pub fn update(mut self, update: PixelUpdate) -> Self {
+        self.updates.push(update);
+        self
+    }
+
+    pub fn updates(mut self, updates: impl IntoIterator<Item = PixelUpdate>) -> Self {
+        self.updates.extend(updates);
+        self
+    }
+
+    /// Pays in this denom instead of the contract's, without asking the
+    /// contract for it
+    pub fn denom(mut self, denom: impl Into<String>) -> Self {
+        self.denom = Some(denom.into());
+        self
+    }
+
+    /// Attaches exactly these funds instead of the computed price
+    pub fn funds(mut self, funds: Coin) -> Self {
+        self.funds = Some(funds);
+        self
+    }
+
+    /// Sends no funds, for owners and their grantees when the collection has
+    /// free owner painting
+    pub fn free(mut self) -> Self {
+        self.free = true;
+        self
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::SetPixelColor {
+                token_id: self.token_id.clone(),
+                current_metadata: self.current_metadata.clone(),
+                updates: self.updates.clone(),
+            },
+        }
+    }
+
+    /// Total cost of the updates at the contract's current pricing, see
+    /// [`paint_price`]
+    pub fn price(&self) -> ClientResult<T, Coin> {
+        if let Some(funds) = &self.funds {
+            return Ok(funds.clone());
+        }
+        if self.free {
+            return Ok(free_funds(&self.denom));
+        }
+        paint_price(
+            self.client,
+            &self.token_id,
+            &self.current_metadata,
+            &self.updates,
+            self.denom.as_deref(),
+            self.client.transport().sender(),
+        )
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        if self.updates.is_empty() {
+            return Err(ClientError::NoUpdates);
+        }
+        let funds = self.price()?;
+        let msg = self.msg();
+        self.client.execute(&msg, &attached(funds))
+    }
+}
+
+/// Paints a bitmap over a tile, pricing only the pixels it changes
+pub struct PaintImage<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    token_id: String,
+    current_metadata: TileMetadata,
+    format: ImageFormat,
+    data: Binary,
+    duration: u64,
+    time: Option<u64>,
+    denom: Option<String>,
+    funds: Option<Coin>,
+    free: bool,
+}
+
+impl<'a, T: Transport> PaintImage<'a, T> {
+    pub(crate) fn new(
+        client: &'a mut TilesClient<T>,
+        token_id: String,
+        current_metadata: TileMetadata,
+        format: ImageFormat,
+        data: Binary,
+        duration: u64,
+    ) -> Self {
+        Self {
+            client,
+            token_id,
+            current_metadata,
+            format,
+            data,
+            duration,
+            time: None,
+            denom: None,
+            funds: None,
+            free: false,
+        }
+    }
+
+    /// Block time in seconds to diff the tile at, the system clock by default.
+    /// The contract diffs at the block the message lands in, so pixels expiring
+    /// in between change the price.
+    pub fn at(mut self, time: u64) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Pays in this denom instead of the contract's, without asking the
+    /// contract for it
+    pub fn denom(mut self, denom: impl Into<String>) -> Self {
+        self.denom = Some(denom.into());
+        self
+    }
+
+    /// Attaches exactly these funds instead of the computed price
+    pub fn funds(mut self, funds: Coin) -> Self {
+        self.funds = Some(funds);
+        self
+    }
+
+    /// Sends no funds, for owners and their grantees when the collection has
+    /// free owner painting
+    pub fn free(mut self) -> Self {
+        self.free = true;
+        self
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::PaintImage {
+                token_id: self.token_id.clone(),
+                format: self.format.clone(),
+                data: self.data.clone(),
+                duration: self.duration,
+            },
+        }
+    }
+
+    /// Pixels the image changes, as the contract would paint them
+    pub fn updates(&self) -> ClientResult<T, Vec<PixelUpdate>> {
+        let config = self.client.config()?;
+        let colors = self
+            .format
+            .decode(self.data.as_slice(), config.pixels_per_tile())
+            .map_err(|err| ClientError::InvalidImage(err.to_string()))?;
+        let time = self.time.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs())
+        });
+        Ok(self
+            .current_metadata
+            .image_updates(&colors, &config.default_color, time, self.duration))
+    }
+
+    /// Cost of the pixels the image changes, see [`paint_price`]. Fails with
+    /// [`ClientError::NoUpdates`] when it changes none.
+    pub fn price(&self) -> ClientResult<T, Coin> {
+        if let Some(funds) = &self.funds {
+            return Ok(funds.clone());
+        }
+        if self.free {
+            return Ok(free_funds(&self.denom));
+        }
+        let updates = self.updates()?;
+        if updates.is_empty() {
+            return Err(ClientError::NoUpdates);
+        }
+        paint_price(
+            self.client,
+            &self.token_id,
+            &self.current_metadata,
+            &updates,
+            self.denom.as_deref(),
+            self.client.transport().sender(),
+        )
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        let funds = self.price()?;
+        let msg = self.msg();
+        self.client.execute(&msg, &attached(funds))
+    }
+}
+
+/// Commits to a paint without revealing its colors, escrowing its price at the
+/// current pricing. Reveal it with the same updates and salt through
+/// [`TilesClient::reveal_paint`].
+pub struct CommitPaint<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    token_id: String,
+    current_metadata: TileMetadata,
+    committer: String,
+    salt: String,
+    updates: Vec<PixelUpdate>,
+    denom: Option<String>,
+    funds: Option<Coin>,
+}
+
+impl<'a, T: Transport> CommitPaint<'a, T> {
+    pub(crate) fn new(
+        client: &'a mut TilesClient<T>,
+        token_id: String,
+        current_metadata: TileMetadata,
+        committer: String,
+        salt: String,
+    ) -> Self {
+        Self {
+            client,
+            token_id,
+            current_metadata,
+            committer,
+            salt,
+            updates: vec![],
+            denom: None,
+            funds: None,
+        }
+    }
+
+    pub fn pixel(self, id: u32, color: impl Into<String>, expiration_duration: u64) -> Self {
+        self.update(PixelUpdate::new(id, color, expiration_duration))
+    }
+
+    pub fn update(mut self, update: PixelUpdate) -> Self {
+        self.updates.push(update);
+        self
+    }
+
+    pub fn updates(mut self, updates: impl IntoIterator<Item = PixelUpdate>) -> Self {
+        self.updates.extend(updates);
+        self
+    }
+
+    /// Pays in this denom instead of the contract's, without asking the
+    /// contract for it
+    pub fn denom(mut self, denom: impl Into<String>) -> Self {
+        self.denom = Some(denom.into());
+        self
+    }
+
+    /// Escrows exactly these funds instead of the computed price, anything the
+    /// reveal does not need is refunded
+    pub fn funds(mut self, funds: Coin) -> Self {
+        self.funds = Some(funds);
+        self
+    }
+
+    pub fn commitment(&self) -> String {
+        paint_commitment(
+            &Addr::unchecked(&self.committer),
+            &self.token_id,
+            &self.updates,
+            &self.salt,
+        )
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::CommitPaint {
+                token_id: self.token_id.clone(),
+                pixel_ids: self.updates.iter().map(|update| update.id).collect(),
+                commitment: self.commitment(),
+            },
+        }
+    }
+
+    /// Cost of the updates if they were painted now, see [`paint_price`]
+    pub fn price(&self) -> ClientResult<T, Coin> {
+        if let Some(funds) = &self.funds {
+            return Ok(funds.clone());
+        }
+        paint_price(
+            self.client,
+            &self.token_id,
+            &self.current_metadata,
+            &self.updates,
+            self.denom.as_deref(),
+            &self.committer,
+        )
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        if self.updates.is_empty() {
+            return Err(ClientError::NoUpdates);
+        }
+        let funds = self.price()?;
+        let msg = self.msg();
+        self.client.execute(&msg, &attached(funds))
+    }
+}
+
+/// Price of painting `updates` over `current_metadata` at the contract's
+/// current pricing, scaled by the tile's price multiplier, plus the takeover of
+/// live pixels of painters other than `painter` when the collection enables
+/// outbids
+pub fn paint_price<T: Transport>(
+    client: &TilesClient<T>,
+    token_id: &str,
+    current_metadata: &TileMetadata,
+    updates: &[PixelUpdate],
+    denom: Option<&str>,
+    painter: &str,
+) -> ClientResult<T, Coin> {
+    let price_scaling = client.price_scaling()?;
+    let policy = client.nft_info(token_id)?.extension.policy;
+    // Only pixels painted before can still be live
+    let repainted: Vec<u32> = updates
+        .iter()
+        .map(|update| update.id)
+        .filter(|id| {
+            current_metadata
+                .pixels
+                .get(*id as usize)
+                .is_some_and(|pixel| pixel.expiration_timestamp > 0)
+        })
+        .collect();
+    // The config holds the surcharge rate, the denom and the outbid rules,
+    // only ask for it when one of them applies
+    let config = if denom.is_none()
+        || !repainted.is_empty()
+        || updates.iter().any(|update| !update.keyframes.is_empty())
+    {
+        Some(client.config()?)
+    } else {
+        None
+    };
+
+    let mut amount = Uint128::zero();
+    for update in updates {
+        let price = price_scaling.calculate_price(update.expiration_duration);
+        let surcharge = config.as_ref().map_or(Uint128::zero(), |config| {
+            config.keyframe_surcharge(price, update.keyframes.len())
+        });
+        // Rounded per pixel, like the contract does
+        amount += (price + surcharge + price_scaling.annotation_price(update.annotation_bytes()))
+            * policy.price_multiplier;
+    }
+    if config
+        .as_ref()
+        .is_some_and(|config| config.outbid.is_some())
+    {
+        for pixel_id in repainted {
+            let response = client.takeover_price(token_id, pixel_id)?;
+            // Extending your own pixels is not a takeover
+            if let Some(displaced) = &response.painter {
+                if displaced.as_str() != painter {
+                    amount += response.takeover.price();
+                }
+            }
+        }
+    }
+
+    let denom = match (denom, config) {
+        (Some(denom), _) => denom.to_string(),
+        // Always fetched above when no denom is given
+        (None, config) => config.map(|config| config.denom).unwrap_or_default(),
+    };
+    Ok(Coin { denom, amount })
+}
+
+fn free_funds(denom: &Option<String>) -> Coin {
+    Coin {
+        denom: denom.clone().unwrap_or_default(),
+        amount: Uint128::zero(),
+    }
+}
+
+/// Funds to attach for `funds`, none for paints that cost nothing since the
+/// contract rejects funds on those
+fn attached(funds: Coin) -> Vec<Coin> {
+    if funds.amount.is_zero() {
+        vec![]
+    } else {
+        vec![funds]
+    }
+}
+
+/// Replaces the contract price scaling, only the royalty payment address may send it
+pub struct UpdatePriceScaling<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    price_scaling: PriceScaling,
+}
+
+impl<'a, T: Transport> UpdatePriceScaling<'a, T> {
+    pub(crate) fn new(client: &'a mut TilesClient<T>, price_scaling: PriceScaling) -> Self {
+        Self {
+            client,
+            price_scaling,
+        }
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::UpdatePriceScaling(self.price_scaling.clone()),
+        }
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        let msg = self.msg();
+        self.client.execute(&msg, &[])
+    }
+}
+
+/// Replaces the tiles config, only the royalty payment address may send it
+pub struct UpdateConfig<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    config: Config,
+}
+
+impl<'a, T: Transport> UpdateConfig<'a, T> {
+    pub(crate) fn new(client: &'a mut TilesClient<T>, config: Config) -> Self {
+        Self { client, config }
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::UpdateConfig(self.config.clone()),
+        }
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        let msg = self.msg();
+        self.client.execute(&msg, &[])
+    }
+}
+
+/// Replaces the painting policy of a tile, only its owner may send it
+pub struct SetTilePolicy<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    token_id: String,
+    policy: TilePolicy,
+}
+
+impl<'a, T: Transport> SetTilePolicy<'a, T> {
+    pub(crate) fn new(
+        client: &'a mut TilesClient<T>,
+        token_id: String,
+        policy: TilePolicy,
+    ) -> Self {
+        Self {
+            client,
+            token_id,
+            policy,
+        }
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::SetTilePolicy {
+                token_id: self.token_id.clone(),
+                policy: self.policy.clone(),
+            },
+        }
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        let msg = self.msg();
+        self.client.execute(&msg, &[])
+    }
+}
+
+/// Lets another address paint a tile as its owner would, by default forever and
+/// without a pixel cap. Grants are not checked on tiles anyone may paint unless
+/// the collection has free owner painting, so the pixel cap does nothing there.
+pub struct GrantPainter<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    token_id: String,
+    painter: String,
+    expires: Option<Expiration>,
+    max_pixels: Option<u32>,
+}
+
+impl<'a, T: Transport> GrantPainter<'a, T> {
+    pub(crate) fn new(client: &'a mut TilesClient<T>, token_id: String, painter: String) -> Self {
+        Self {
+            client,
+            token_id,
+            painter,
+            expires: None,
+            max_pixels: None,
+        }
+    }
+
+    pub fn expires(mut self, expires: Expiration) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    pub fn max_pixels(mut self, max_pixels: u32) -> Self {
+        self.max_pixels = Some(max_pixels);
+        self
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::GrantPainter {
+                token_id: self.token_id.clone(),
+                painter: self.painter.clone(),
+                expires: self.expires,
+                max_pixels: self.max_pixels,
+            },
+        }
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        let msg = self.msg();
+        self.client.execute(&msg, &[])
+    }
+}
+
+/// Whitelists a CW20 token with its own price scaling, or removes it when no scaling is set
+pub struct SetCw20PriceScaling<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    token: String,
+    price_scaling: Option<PriceScaling>,
+}
+
+impl<'a, T: Transport> SetCw20PriceScaling<'a, T> {
+    pub(crate) fn new(client: &'a mut TilesClient<T>, token: String) -> Self {
+        Self {
+            client,
+            token,
+            price_scaling: None,
+        }
+    }
+
+    pub fn price_scaling(mut self, price_scaling: PriceScaling) -> Self {
+        self.price_scaling = Some(price_scaling);
+        self
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::SetCw20PriceScaling {
+                token: self.token.clone(),
+                price_scaling: self.price_scaling.clone(),
+            },
+        }
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        let msg = self.msg();
+        self.client.execute(&msg, &[])
+    }
+}
+
+/// Claims escrowed payouts in one denom, everything pending unless an amount is set
+pub struct Withdraw<'a, T: Transport> {
+    client: &'a mut TilesClient<T>,
+    denom: String,
+    amount: Option<Uint128>,
+}
+
+impl<'a, T: Transport> Withdraw<'a, T> {
+    pub(crate) fn new(client: &'a mut TilesClient<T>, denom: String) -> Self {
+        Self {
+            client,
+            denom,
+            amount: None,
+        }
+    }
+
+    pub fn amount(mut self, amount: u128) -> Self {
+        self.amount = Some(Uint128::new(amount));
+        self
+    }
+
+    pub fn msg(&self) -> ExecuteMsg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::Withdraw {
+                denom: self.denom.clone(),
+                amount: self.amount,
+            },
+        }
+    }
+
+    pub fn send(self) -> ClientResult<T, T::Response> {
+        let msg = self.msg();
+        self.client.execute(&msg, &[])
+    }
+}
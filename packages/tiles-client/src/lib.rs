@@ -0,0 +1,11 @@
+pub mod client;
+pub mod error;
+pub mod execute;
+#[cfg(feature = "multi-test")]
+pub mod multi_test;
+pub mod query;
+pub mod transport;
+
+pub use client::TilesClient;
+pub use error::ClientError;
+pub use transport::Transport;
@@ -0,0 +1,68 @@
+use anyhow::anyhow;
+use cosmwasm_std::{
+    to_json_vec, Addr, Binary, Coin, ContractResult, Empty, QueryRequest, SystemResult, WasmMsg,
+    WasmQuery,
+};
+use cw_multi_test::{AppResponse, Executor};
+use sg_multi_test::StargazeApp;
+
+use crate::transport::Transport;
+
+/// Drives the contract inside a cw-multi-test app, signing every message as `sender`
+pub struct MultiTestTransport<'a> {
+    app: &'a mut StargazeApp,
+    sender: Addr,
+}
+
+impl<'a> MultiTestTransport<'a> {
+    pub fn new(app: &'a mut StargazeApp, sender: Addr) -> Self {
+        Self { app, sender }
+    }
+
+    pub fn sender(&self) -> &Addr {
+        &self.sender
+    }
+
+    pub fn set_sender(&mut self, sender: Addr) {
+        self.sender = sender;
+    }
+}
+
+impl Transport for MultiTestTransport<'_> {
+    type Error = anyhow::Error;
+    type Response = AppResponse;
+
+    fn sender(&self) -> &str {
+        self.sender.as_str()
+    }
+
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, Self::Error> {
+        let request: QueryRequest<Empty> = WasmQuery::Smart {
+            contract_addr: contract.to_string(),
+            msg: msg.clone(),
+        }
+        .into();
+        match self.app.wrap().raw_query(&to_json_vec(&request)?) {
+            SystemResult::Ok(ContractResult::Ok(response)) => Ok(response),
+            SystemResult::Ok(ContractResult::Err(err)) => Err(anyhow!(err)),
+            SystemResult::Err(err) => Err(anyhow!(err)),
+        }
+    }
+
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<Self::Response, Self::Error> {
+        self.app.execute(
+            self.sender.clone(),
+            WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: msg.clone(),
+                funds: funds.to_vec(),
+            }
+            .into(),
+        )
+    }
+}
@@ -0,0 +1,436 @@
+use cw721::{
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, OperatorsResponse, OwnerOfResponse,
+    TokensResponse,
+};
+use tiles::{
+    contract::msg::{
+        PixelHistoryResponse, QueryMsg, TileHistoryResponse, TileSvgResponse, TopPaintersResponse,
+    },
+    core::{painter::PainterRanking, tile::Tile},
+};
+
+use crate::{
+    client::{ClientResult, TilesClient},
+    transport::Transport,
+};
+
+pub struct OwnerOf<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    token_id: String,
+    include_expired: Option<bool>,
+}
+
+impl<'a, T: Transport> OwnerOf<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, token_id: String) -> Self {
+        Self {
+            client,
+            token_id,
+            include_expired: None,
+        }
+    }
+
+    pub fn include_expired(mut self, include_expired: bool) -> Self {
+        self.include_expired = Some(include_expired);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::OwnerOf {
+            token_id: self.token_id.clone(),
+            include_expired: self.include_expired,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, OwnerOfResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct Approval<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    token_id: String,
+    spender: String,
+    include_expired: Option<bool>,
+}
+
+impl<'a, T: Transport> Approval<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, token_id: String, spender: String) -> Self {
+        Self {
+            client,
+            token_id,
+            spender,
+            include_expired: None,
+        }
+    }
+
+    pub fn include_expired(mut self, include_expired: bool) -> Self {
+        self.include_expired = Some(include_expired);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::Approval {
+            token_id: self.token_id.clone(),
+            spender: self.spender.clone(),
+            include_expired: self.include_expired,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, ApprovalResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct Approvals<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    token_id: String,
+    include_expired: Option<bool>,
+}
+
+impl<'a, T: Transport> Approvals<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, token_id: String) -> Self {
+        Self {
+            client,
+            token_id,
+            include_expired: None,
+        }
+    }
+
+    pub fn include_expired(mut self, include_expired: bool) -> Self {
+        self.include_expired = Some(include_expired);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::Approvals {
+            token_id: self.token_id.clone(),
+            include_expired: self.include_expired,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, ApprovalsResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct AllOperators<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    owner: String,
+    include_expired: Option<bool>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+}
+
+impl<'a, T: Transport> AllOperators<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, owner: String) -> Self {
+        Self {
+            client,
+            owner,
+            include_expired: None,
+            start_after: None,
+            limit: None,
+        }
+    }
+
+    pub fn include_expired(mut self, include_expired: bool) -> Self {
+        self.include_expired = Some(include_expired);
+        self
+    }
+
+    pub fn start_after(mut self, start_after: impl Into<String>) -> Self {
+        self.start_after = Some(start_after.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::AllOperators {
+            owner: self.owner.clone(),
+            include_expired: self.include_expired,
+            start_after: self.start_after.clone(),
+            limit: self.limit,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, OperatorsResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct AllNftInfo<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    token_id: String,
+    include_expired: Option<bool>,
+}
+
+impl<'a, T: Transport> AllNftInfo<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, token_id: String) -> Self {
+        Self {
+            client,
+            token_id,
+            include_expired: None,
+        }
+    }
+
+    pub fn include_expired(mut self, include_expired: bool) -> Self {
+        self.include_expired = Some(include_expired);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::AllNftInfo {
+            token_id: self.token_id.clone(),
+            include_expired: self.include_expired,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, AllNftInfoResponse<Tile>> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct Tokens<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+}
+
+impl<'a, T: Transport> Tokens<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, owner: String) -> Self {
+        Self {
+            client,
+            owner,
+            start_after: None,
+            limit: None,
+        }
+    }
+
+    pub fn start_after(mut self, start_after: impl Into<String>) -> Self {
+        self.start_after = Some(start_after.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::Tokens {
+            owner: self.owner.clone(),
+            start_after: self.start_after.clone(),
+            limit: self.limit,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, TokensResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct AllTokens<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+}
+
+impl<'a, T: Transport> AllTokens<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>) -> Self {
+        Self {
+            client,
+            start_after: None,
+            limit: None,
+        }
+    }
+
+    pub fn start_after(mut self, start_after: impl Into<String>) -> Self {
+        self.start_after = Some(start_after.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::AllTokens {
+            start_after: self.start_after.clone(),
+            limit: self.limit,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, TokensResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct TileSvg<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    token_id: String,
+    scale: Option<u32>,
+    grid_lines: Option<bool>,
+}
+
+impl<'a, T: Transport> TileSvg<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, token_id: String) -> Self {
+        Self {
+            client,
+            token_id,
+            scale: None,
+            grid_lines: None,
+        }
+    }
+
+    pub fn scale(mut self, scale: u32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    pub fn grid_lines(mut self, grid_lines: bool) -> Self {
+        self.grid_lines = Some(grid_lines);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::TileSvg {
+            token_id: self.token_id.clone(),
+            scale: self.scale,
+            grid_lines: self.grid_lines,
+        }
+    }
+
+    /// Returns the SVG document itself
+    pub fn query(self) -> ClientResult<T, String> {
+        let response: TileSvgResponse = self.client.query(&self.msg())?;
+        Ok(response.svg)
+    }
+}
+
+pub struct PixelHistory<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    token_id: String,
+    pixel_id: u32,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+}
+
+impl<'a, T: Transport> PixelHistory<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, token_id: String, pixel_id: u32) -> Self {
+        Self {
+            client,
+            token_id,
+            pixel_id,
+            start_after: None,
+            limit: None,
+        }
+    }
+
+    pub fn start_after(mut self, seq: u64) -> Self {
+        self.start_after = Some(seq);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::PixelHistory {
+            token_id: self.token_id.clone(),
+            pixel_id: self.pixel_id,
+            start_after: self.start_after,
+            limit: self.limit,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, PixelHistoryResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct TileHistory<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    token_id: String,
+    since: Option<u64>,
+    limit: Option<u32>,
+}
+
+impl<'a, T: Transport> TileHistory<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, token_id: String) -> Self {
+        Self {
+            client,
+            token_id,
+            since: None,
+            limit: None,
+        }
+    }
+
+    pub fn since(mut self, since: u64) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::TileHistory {
+            token_id: self.token_id.clone(),
+            since: self.since,
+            limit: self.limit,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, TileHistoryResponse> {
+        self.client.query(&self.msg())
+    }
+}
+
+pub struct TopPainters<'a, T: Transport> {
+    client: &'a TilesClient<T>,
+    by: PainterRanking,
+    start_after: Option<String>,
+    limit: Option<u32>,
+}
+
+impl<'a, T: Transport> TopPainters<'a, T> {
+    pub(crate) fn new(client: &'a TilesClient<T>, by: PainterRanking) -> Self {
+        Self {
+            client,
+            by,
+            start_after: None,
+            limit: None,
+        }
+    }
+
+    pub fn start_after(mut self, address: impl Into<String>) -> Self {
+        self.start_after = Some(address.into());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn msg(&self) -> QueryMsg {
+        QueryMsg::TopPainters {
+            by: self.by.clone(),
+            start_after: self.start_after.clone(),
+            limit: self.limit,
+        }
+    }
+
+    pub fn query(self) -> ClientResult<T, TopPaintersResponse> {
+        self.client.query(&self.msg())
+    }
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Binary, Coin};
+
+/// Moves JSON encoded messages between the client and a tiles contract.
+///
+/// Implementations decide who signs and how messages are broadcast, so the same
+/// client drives an in-process test app or a live chain.
+pub trait Transport {
+    type Error;
+    type Response;
+
+    /// Address messages are signed by, and so the painter of any paint sent
+    fn sender(&self) -> &str;
+
+    /// Runs a smart query and returns the raw JSON response
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, Self::Error>;
+
+    /// Executes a message on the contract, attaching the given funds
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<Self::Response, Self::Error>;
+}
@@ -0,0 +1,556 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+use cosmwasm_std::{from_json, to_json_binary, Addr, Binary, Coin, Decimal, Uint128};
+use cw721::NftInfoResponse;
+use tiles::{
+    contract::msg::{ExecuteMsg, QueryMsg, TakeoverPriceResponse, TileExecuteMsg, TileSvgResponse},
+    core::{
+        config::Config,
+        outbid::{OutbidConfig, Takeover},
+        pricing::PriceScaling,
+        tile::{
+            commit::paint_commitment,
+            image::ImageFormat,
+            metadata::{Keyframe, PixelUpdate, TileMetadata},
+            policy::TilePolicy,
+            Tile,
+        },
+    },
+};
+use tiles_client::{ClientError, TilesClient, Transport};
+
+/// Answers queries from fixed state and records every message it is given
+#[derive(Default)]
+struct MockTransport {
+    config: Config,
+    price_scaling: PriceScaling,
+    policy: TilePolicy,
+    /// Live pixels by id, with their painter and takeover price
+    takeovers: BTreeMap<u32, (Addr, Takeover)>,
+    queries: RefCell<Vec<QueryMsg>>,
+    executed: Vec<(ExecuteMsg, Vec<Coin>)>,
+}
+
+impl Transport for MockTransport {
+    type Error = String;
+    type Response = ();
+
+    fn sender(&self) -> &str {
+        "me"
+    }
+
+    fn query(&self, contract: &str, msg: &Binary) -> Result<Binary, Self::Error> {
+        assert_eq!(contract, "tiles");
+        let msg: QueryMsg = from_json(msg).map_err(|e| e.to_string())?;
+        self.queries.borrow_mut().push(msg.clone());
+        match msg {
+            QueryMsg::Config {} => to_json_binary(&self.config),
+            QueryMsg::PriceScaling {} => to_json_binary(&self.price_scaling),
+            QueryMsg::NftInfo { .. } => to_json_binary(&NftInfoResponse {
+                token_uri: None,
+                extension: Tile {
+                    policy: self.policy.clone(),
+                    ..Tile::new(String::new())
+                },
+            }),
+            QueryMsg::TakeoverPrice { token_id, pixel_id } => {
+                let live = self.takeovers.get(&pixel_id).cloned();
+                to_json_binary(&TakeoverPriceResponse {
+                    token_id,
+                    pixel_id,
+                    painter: live.as_ref().map(|(painter, _)| painter.clone()),
+                    denom: live.as_ref().map(|_| "ustars".to_string()),
+                    takeover: live.map(|(_, takeover)| takeover).unwrap_or_default(),
+                })
+            }
+            QueryMsg::TileSvg { token_id, .. } => to_json_binary(&TileSvgResponse {
+                svg: format!("<svg>{}</svg>", token_id),
+            }),
+            _ => return Err("unsupported query".to_string()),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    fn execute(
+        &mut self,
+        contract: &str,
+        msg: &Binary,
+        funds: &[Coin],
+    ) -> Result<Self::Response, Self::Error> {
+        assert_eq!(contract, "tiles");
+        let msg = from_json(msg).map_err(|e| e.to_string())?;
+        self.executed.push((msg, funds.to_vec()));
+        Ok(())
+    }
+}
+
+fn client() -> TilesClient<MockTransport> {
+    TilesClient::new(MockTransport::default(), "tiles")
+}
+
+#[test]
+fn set_pixel_color_attaches_computed_price() {
+    let mut client = client();
+    let scaling = PriceScaling::default();
+    let expected = scaling.calculate_price(3600) + scaling.calculate_price(7200);
+
+    client
+        .set_pixel_color("1", TileMetadata::default())
+        .pixel(0, "#FF0000", 3600)
+        .pixel(1, "#00FF00", 7200)
+        .send()
+        .unwrap();
+
+    let (msg, funds) = &client.transport().executed[0];
+    assert_eq!(funds, &vec![Coin::new(expected.u128(), "ustars")]);
+    match msg {
+        ExecuteMsg::Extension {
+            msg: TileExecuteMsg::SetPixelColor {
+                token_id, updates, ..
+            },
+        } => {
+            assert_eq!(token_id, "1");
+            assert_eq!(updates.len(), 2);
+        }
+        _ => panic!("unexpected message"),
+    }
+}
+
+#[test]
+fn set_pixel_color_applies_the_tile_price_multiplier() {
+    let mut client = TilesClient::new(
+        MockTransport {
+            policy: TilePolicy {
+                price_multiplier: Decimal::percent(150),
+                ..TilePolicy::default()
+            },
+            ..MockTransport::default()
+        },
+        "tiles",
+    );
+    let scaling = PriceScaling::default();
+
+    let price = client
+        .set_pixel_color("1", TileMetadata::default())
+        .pixel(0, "#FF0000", 3600)
+        .pixel(1, "#00FF00", 7200)
+        .price()
+        .unwrap();
+    assert_eq!(
+        price.amount,
+        (scaling.calculate_price(3600) + scaling.calculate_price(7200)) * Decimal::percent(150)
+    );
+    assert!(client
+        .transport()
+        .queries
+        .borrow()
+        .contains(&QueryMsg::NftInfo {
+            token_id: "1".to_string()
+        }));
+}
+
+#[test]
+fn set_pixel_color_adds_takeovers_of_other_painters() {
+    let takeover = |remaining_value: u128| Takeover {
+        remaining_value: Uint128::new(remaining_value),
+        premium: Uint128::new(10),
+        displaced_refund: Uint128::new(remaining_value + 5),
+    };
+    let mut client = TilesClient::new(
+        MockTransport {
+            config: Config {
+                outbid: Some(OutbidConfig {
+                    premium_bps: 1_000,
+                    displaced_share_bps: 5_000,
+                }),
+                ..Config::default()
+            },
+            takeovers: BTreeMap::from([
+                (0, (Addr::unchecked("rival"), takeover(100))),
+                (1, (Addr::unchecked("me"), takeover(200))),
+            ]),
+            ..MockTransport::default()
+        },
+        "tiles",
+    );
+    let mut metadata = TileMetadata::default();
+    for id in [0, 1] {
+        metadata.pixels[id].expiration_timestamp = 1;
+    }
+    let pixel_price = PriceScaling::default().calculate_price(3600);
+
+    let price = client
+        .set_pixel_color("1", metadata)
+        .pixel(0, "#FF0000", 3600)
+        .pixel(1, "#00FF00", 3600)
+        .pixel(2, "#0000FF", 3600)
+        .price()
+        .unwrap();
+    // Only the rival's pixel is bought out, blank pixels are never asked about
+    assert_eq!(
+        price.amount,
+        pixel_price * Uint128::new(3) + Uint128::new(110)
+    );
+    let queries = client.transport().queries.borrow();
+    let takeover_queries = queries
+        .iter()
+        .filter(|msg| matches!(msg, QueryMsg::TakeoverPrice { .. }))
+        .count();
+    assert_eq!(takeover_queries, 2);
+}
+
+#[test]
+fn repainting_own_live_pixel_is_not_a_takeover() {
+    let mut client = TilesClient::new(
+        MockTransport {
+            config: Config {
+                outbid: Some(OutbidConfig {
+                    premium_bps: 1_000,
+                    displaced_share_bps: 5_000,
+                }),
+                ..Config::default()
+            },
+            takeovers: BTreeMap::from([(
+                0,
+                (
+                    Addr::unchecked("me"),
+                    Takeover {
+                        remaining_value: Uint128::new(100),
+                        premium: Uint128::new(10),
+                        displaced_refund: Uint128::new(105),
+                    },
+                ),
+            )]),
+            ..MockTransport::default()
+        },
+        "tiles",
+    );
+    let mut metadata = TileMetadata::default();
+    metadata.pixels[0].expiration_timestamp = 1;
+
+    client
+        .set_pixel_color("1", metadata)
+        .pixel(0, "#00FF00", 3600)
+        .send()
+        .unwrap();
+
+    // Priced as the sender, the contract's painter, so only the paint is paid
+    let (_, funds) = &client.transport().executed[0];
+    assert_eq!(
+        funds,
+        &vec![Coin::new(
+            PriceScaling::default().calculate_price(3600).u128(),
+            "ustars"
+        )]
+    );
+}
+
+#[test]
+fn set_pixel_color_sends_overridden_funds() {
+    let mut client = client();
+    let funds = Coin::new(42, "uatom");
+
+    client
+        .set_pixel_color("1", TileMetadata::default())
+        .pixel(0, "#FF0000", 3600)
+        .funds(funds.clone())
+        .send()
+        .unwrap();
+
+    assert_eq!(client.transport().executed[0].1, vec![funds]);
+    assert!(client.transport().queries.borrow().is_empty());
+}
+
+#[test]
+fn free_paints_attach_no_funds() {
+    let mut client = client();
+
+    client
+        .set_pixel_color("1", TileMetadata::default())
+        .pixel(0, "#FF0000", 3600)
+        .free()
+        .send()
+        .unwrap();
+
+    let (_, funds) = &client.transport().executed[0];
+    assert!(funds.is_empty());
+    assert!(client.transport().queries.borrow().is_empty());
+}
+
+#[test]
+fn set_pixel_color_uses_requested_denom() {
+    let mut client = client();
+    let price = client
+        .set_pixel_color("1", TileMetadata::default())
+        .pixel(0, "#FF0000", 3600)
+        .denom("uatom")
+        .price()
+        .unwrap();
+
+    assert_eq!(price.denom, "uatom");
+    // No need to ask the contract for its denoms when one is given
+    assert!(!client
+        .transport()
+        .queries
+        .borrow()
+        .contains(&QueryMsg::Config {}));
+}
+
+#[test]
+fn set_pixel_color_prices_keyframes() {
+    let mut client = client();
+    let scaling = PriceScaling::default();
+    let price = scaling.calculate_price(3600);
+    let config = Config::default();
+
+    let funds = client
+        .set_pixel_color("1", TileMetadata::default())
+        .update(PixelUpdate {
+            id: 0,
+            color: "#000000".to_string(),
+            expiration_duration: 3600,
+            annotation: None,
+            keyframes: vec![
+                Keyframe {
+                    start_offset: 600,
+                    color: "#FFFFFF".to_string(),
+                },
+                Keyframe {
+                    start_offset: 1200,
+                    color: "#000000".to_string(),
+                },
+            ],
+        })
+        .price()
+        .unwrap();
+
+    assert_eq!(funds.amount, price + config.keyframe_surcharge(price, 2));
+}
+
+#[test]
+fn set_pixel_color_prices_annotations() {
+    let scaling = PriceScaling {
+        annotation_byte_price: Uint128::new(10),
+        ..PriceScaling::default()
+    };
+    let mut client = TilesClient::new(
+        MockTransport {
+            price_scaling: scaling.clone(),
+            ..MockTransport::default()
+        },
+        "tiles",
+    );
+
+    let funds = client
+        .set_pixel_color("1", TileMetadata::default())
+        .update(PixelUpdate {
+            id: 0,
+            color: "#000000".to_string(),
+            expiration_duration: 3600,
+            keyframes: vec![],
+            annotation: Some("gm".to_string()),
+        })
+        .price()
+        .unwrap();
+
+    assert_eq!(
+        funds.amount,
+        scaling.calculate_price(3600) + Uint128::new(20)
+    );
+}
+
+#[test]
+fn set_pixel_color_requires_updates() {
+    let mut client = client();
+    let result = client.set_pixel_color("1", TileMetadata::default()).send();
+    assert!(matches!(result, Err(ClientError::NoUpdates)));
+    assert!(client.transport().executed.is_empty());
+}
+
+#[test]
+fn paint_image_prices_only_changed_pixels() {
+    let mut client = client();
+    let mut metadata = TileMetadata::default();
+    metadata.pixels[0].color = "#FF0000".to_string();
+    metadata.pixels[0].expiration_timestamp = 10_000;
+    // Red over the live red pixel, green over a blank one, blank elsewhere
+    let data = vec![
+        1, 0xFF, 0x00, 0x00, 1, 0x00, 0xFF, 0x00, 98, 0xFF, 0xFF, 0xFF,
+    ];
+
+    client
+        .paint_image(
+            "1",
+            metadata.clone(),
+            ImageFormat::RgbRle,
+            data.clone(),
+            3600,
+        )
+        .at(1000)
+        .send()
+        .unwrap();
+
+    let (msg, funds) = &client.transport().executed[0];
+    let price = PriceScaling::default().calculate_price(3600);
+    assert_eq!(funds, &vec![Coin::new(price.u128(), "ustars")]);
+    assert_eq!(
+        msg,
+        &ExecuteMsg::Extension {
+            msg: TileExecuteMsg::PaintImage {
+                token_id: "1".to_string(),
+                format: ImageFormat::RgbRle,
+                data: Binary::from(data),
+                duration: 3600,
+            },
+        }
+    );
+
+    // An image the tile already shows has nothing to pay for
+    let blank = vec![100, 0xFF, 0xFF, 0xFF];
+    let result = client
+        .paint_image(
+            "1",
+            TileMetadata::default(),
+            ImageFormat::RgbRle,
+            blank,
+            3600,
+        )
+        .at(1000)
+        .send();
+    assert!(matches!(result, Err(ClientError::NoUpdates)));
+    // Images that do not cover the tile are caught before sending
+    let result = client
+        .paint_image("1", metadata, ImageFormat::Rgb, vec![0xFF; 3], 3600)
+        .send();
+    assert!(matches!(result, Err(ClientError::InvalidImage(_))));
+    assert_eq!(client.transport().executed.len(), 1);
+}
+
+#[test]
+fn commit_paint_escrows_the_price_of_its_updates() {
+    let mut client = client();
+    let scaling = PriceScaling::default();
+    let updates = vec![
+        PixelUpdate::new(0, "#FF0000", 3600),
+        PixelUpdate::new(2, "#00FF00", 7200),
+    ];
+
+    client
+        .commit_paint("1", TileMetadata::default(), "painter", "salt")
+        .updates(updates.clone())
+        .send()
+        .unwrap();
+
+    let (msg, funds) = &client.transport().executed[0];
+    let expected = scaling.calculate_price(3600) + scaling.calculate_price(7200);
+    assert_eq!(funds, &vec![Coin::new(expected.u128(), "ustars")]);
+    assert_eq!(
+        msg,
+        &ExecuteMsg::Extension {
+            msg: TileExecuteMsg::CommitPaint {
+                token_id: "1".to_string(),
+                pixel_ids: vec![0, 2],
+                commitment: paint_commitment(&Addr::unchecked("painter"), "1", &updates, "salt"),
+            },
+        }
+    );
+
+    let result = client
+        .commit_paint("1", TileMetadata::default(), "painter", "salt")
+        .send();
+    assert!(matches!(result, Err(ClientError::NoUpdates)));
+}
+
+#[test]
+fn update_price_scaling_sends_no_funds() {
+    let mut client = client();
+    let scaling = PriceScaling {
+        hour_1_price: Uint128::new(1),
+        ..PriceScaling::default()
+    };
+    client.update_price_scaling(scaling.clone()).send().unwrap();
+
+    assert_eq!(
+        client.transport().executed,
+        vec![(
+            ExecuteMsg::Extension {
+                msg: TileExecuteMsg::UpdatePriceScaling(scaling)
+            },
+            vec![]
+        )]
+    );
+}
+
+#[test]
+fn update_config_sends_no_funds() {
+    let mut client = client();
+    let config = Config {
+        tile_width: 4,
+        tile_height: 4,
+        ..Config::default()
+    };
+    client.update_config(config.clone()).send().unwrap();
+
+    assert_eq!(
+        client.transport().executed,
+        vec![(
+            ExecuteMsg::Extension {
+                msg: TileExecuteMsg::UpdateConfig(config)
+            },
+            vec![]
+        )]
+    );
+}
+
+#[test]
+fn queries_are_decoded() {
+    let client = client();
+    assert_eq!(client.config().unwrap(), Config::default());
+    assert_eq!(client.price_scaling().unwrap(), PriceScaling::default());
+    assert_eq!(
+        client
+            .tile_svg("7")
+            .scale(2)
+            .grid_lines(true)
+            .query()
+            .unwrap(),
+        "<svg>7</svg>"
+    );
+    assert_eq!(
+        client.transport().queries.borrow().last(),
+        Some(&QueryMsg::TileSvg {
+            token_id: "7".to_string(),
+            scale: Some(2),
+            grid_lines: Some(true),
+        })
+    );
+}
+
+#[test]
+fn builders_produce_query_messages() {
+    let client = client();
+    assert_eq!(
+        client.tokens("owner").start_after("3").limit(5).msg(),
+        QueryMsg::Tokens {
+            owner: "owner".to_string(),
+            start_after: Some("3".to_string()),
+            limit: Some(5),
+        }
+    );
+    assert_eq!(
+        client.owner_of("1").include_expired(true).msg(),
+        QueryMsg::OwnerOf {
+            token_id: "1".to_string(),
+            include_expired: Some(true),
+        }
+    );
+}
+
+#[test]
+fn transport_errors_are_surfaced() {
+    let client = client();
+    assert!(matches!(
+        client.num_tokens(),
+        Err(ClientError::Transport(ref err)) if err == "unsupported query"
+    ));
+}
@@ -0,0 +1,207 @@
+use tiles::core::{config::Config, tile::metadata::TileMetadata};
+
+use crate::{
+    color::{parse_hex, Rgb, GRID_COLOR},
+    error::RenderError,
+    state::CanvasState,
+};
+
+/// Arrangement of tiles on the canvas. Token `n` sits at index `n - 1`,
+/// filled row by row from the top left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CanvasLayout {
+    pub columns: u32,
+    pub rows: u32,
+}
+
+impl CanvasLayout {
+    pub fn new(columns: u32, rows: u32) -> Result<Self, RenderError> {
+        if columns == 0 || rows == 0 {
+            return Err(RenderError::InvalidOptions(
+                "canvas must have at least one row and one column".to_string(),
+            ));
+        }
+        Ok(Self { columns, rows })
+    }
+
+    /// Smallest layout with the given number of columns that holds every tile in the state
+    pub fn fit(state: &CanvasState, columns: u32) -> Result<Self, RenderError> {
+        let max_index = state
+            .tiles
+            .keys()
+            .map(|token_id| token_index(token_id))
+            .try_fold(0u32, |max, index| index.map(|index| max.max(index)))?;
+        Self::new(columns, (max_index / columns.max(1)) + 1)
+    }
+
+    pub fn position(&self, token_id: &str) -> Result<(u32, u32), RenderError> {
+        let index = token_index(token_id)?;
+        let (column, row) = (index % self.columns, index / self.columns);
+        if row >= self.rows {
+            return Err(RenderError::InvalidOptions(format!(
+                "token {} does not fit in a {}x{} canvas",
+                token_id, self.columns, self.rows
+            )));
+        }
+        Ok((column, row))
+    }
+}
+
+fn token_index(token_id: &str) -> Result<u32, RenderError> {
+    match token_id.parse::<u32>() {
+        Ok(id) if id > 0 => Ok(id - 1),
+        _ => Err(RenderError::InvalidTokenId(token_id.to_string())),
+    }
+}
+
+/// Row-major RGB image buffer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Raster {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl Raster {
+    pub fn filled(width: u32, height: u32, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            data: color.repeat((width * height) as usize),
+        }
+    }
+
+    pub fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: Rgb) {
+        for row in y..(y + height).min(self.height) {
+            for column in x..(x + width).min(self.width) {
+                let offset = ((row * self.width + column) * 3) as usize;
+                self.data[offset..offset + 3].copy_from_slice(&color);
+            }
+        }
+    }
+}
+
+/// Largest image the renderer produces, in output pixels. Fits the full
+/// 100x100 canvas of 10x10 tiles at the default scale of 10.
+pub const MAX_OUTPUT_PIXELS: u64 = 1 << 27;
+
+/// Output size of a `width`x`height` image drawn `scale` times larger
+pub fn scaled_size(width: u64, height: u64, scale: u32) -> Result<(u32, u32), RenderError> {
+    if scale == 0 {
+        return Err(RenderError::InvalidOptions(
+            "scale must be at least 1".to_string(),
+        ));
+    }
+    let too_large = || {
+        RenderError::InvalidOptions(format!(
+            "a {}x{} image at scale {} exceeds {} output pixels",
+            width, height, scale, MAX_OUTPUT_PIXELS
+        ))
+    };
+    let scaled_width = width.checked_mul(scale.into()).ok_or_else(too_large)?;
+    let scaled_height = height.checked_mul(scale.into()).ok_or_else(too_large)?;
+    match scaled_width.checked_mul(scaled_height) {
+        Some(pixels) if pixels <= MAX_OUTPUT_PIXELS => Ok((
+            u32::try_from(scaled_width).map_err(|_| too_large())?,
+            u32::try_from(scaled_height).map_err(|_| too_large())?,
+        )),
+        _ => Err(too_large()),
+    }
+}
+
+pub fn rasterize_tile(
+    metadata: &TileMetadata,
+    config: &Config,
+    time: u64,
+    scale: u32,
+    grid_lines: bool,
+) -> Result<Raster, RenderError> {
+    let (width, height) = scaled_size(config.tile_width.into(), config.tile_height.into(), scale)?;
+    let mut raster = Raster::filled(width, height, parse_hex(&config.default_color)?);
+    draw_tile(&mut raster, metadata, config, time, 0, 0, scale)?;
+    if grid_lines {
+        draw_grid(
+            &mut raster,
+            config.tile_width,
+            config.tile_height,
+            scale,
+            scale,
+        );
+    }
+    Ok(raster)
+}
+
+pub fn rasterize_canvas(
+    state: &CanvasState,
+    layout: CanvasLayout,
+    scale: u32,
+    grid_lines: bool,
+) -> Result<Raster, RenderError> {
+    let (width, height) = scaled_size(
+        u64::from(layout.columns) * u64::from(state.config.tile_width),
+        u64::from(layout.rows) * u64::from(state.config.tile_height),
+        scale,
+    )?;
+    let tile_width = state.config.tile_width * scale;
+    let tile_height = state.config.tile_height * scale;
+    let mut raster = Raster::filled(width, height, parse_hex(&state.config.default_color)?);
+
+    for (token_id, metadata) in &state.tiles {
+        let (column, row) = layout.position(token_id)?;
+        draw_tile(
+            &mut raster,
+            metadata,
+            &state.config,
+            state.render_time(token_id),
+            column * tile_width,
+            row * tile_height,
+            scale,
+        )?;
+    }
+
+    if grid_lines {
+        draw_grid(
+            &mut raster,
+            layout.columns,
+            layout.rows,
+            tile_width,
+            tile_height,
+        );
+    }
+
+    Ok(raster)
+}
+
+/// One pixel wide lines around `columns`x`rows` cells, the last ones on the edge
+fn draw_grid(raster: &mut Raster, columns: u32, rows: u32, cell_width: u32, cell_height: u32) {
+    for column in 0..=columns {
+        let x = (column * cell_width).min(raster.width - 1);
+        raster.fill_rect(x, 0, 1, raster.height, GRID_COLOR);
+    }
+    for row in 0..=rows {
+        let y = (row * cell_height).min(raster.height - 1);
+        raster.fill_rect(0, y, raster.width, 1, GRID_COLOR);
+    }
+}
+
+fn draw_tile(
+    raster: &mut Raster,
+    metadata: &TileMetadata,
+    config: &Config,
+    time: u64,
+    origin_x: u32,
+    origin_y: u32,
+    scale: u32,
+) -> Result<(), RenderError> {
+    for index in 0..metadata.pixels.len() {
+        let (x, y) = config.pixel_position(index as u32);
+        raster.fill_rect(
+            origin_x + x * scale,
+            origin_y + y * scale,
+            scale,
+            scale,
+            parse_hex(metadata.pixel_color(index, &config.default_color, time))?,
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use crate::error::RenderError;
+
+pub type Rgb = [u8; 3];
+
+pub const GRID_COLOR: Rgb = [0, 0, 0];
+
+/// Parses a `#RRGGBB` color as stored in `PixelData`
+pub fn parse_hex(color: &str) -> Result<Rgb, RenderError> {
+    let invalid = || RenderError::InvalidColor(color.to_string());
+    let hex = color.strip_prefix('#').ok_or_else(invalid)?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
@@ -0,0 +1,29 @@
+use thiserror::Error;
+use tiles::core::replay::ReplayError;
+
+#[derive(Error, Debug)]
+pub enum RenderError {
+    #[error("Invalid color: {0}")]
+    InvalidColor(String),
+
+    #[error("Invalid token ID: {0} is not a positive integer")]
+    InvalidTokenId(String),
+
+    #[error("Unknown token: {0}")]
+    UnknownToken(String),
+
+    #[error("Invalid render options: {0}")]
+    InvalidOptions(String),
+
+    #[error("Replay error: {0}")]
+    Replay(#[from] ReplayError),
+
+    #[error("PNG encoding error: {0}")]
+    Png(#[from] ::png::EncodingError),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
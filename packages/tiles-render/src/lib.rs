@@ -0,0 +1,11 @@
+pub mod canvas;
+pub mod color;
+pub mod error;
+pub mod pattern;
+pub mod png;
+pub mod state;
+pub mod svg;
+
+pub use canvas::CanvasLayout;
+pub use error::RenderError;
+pub use state::CanvasState;
@@ -0,0 +1,207 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use cosmwasm_std::Event;
+use tiles::core::config::Config;
+use tiles_render::{
+    pattern::{generate_canvas, Palette, Pattern},
+    png, svg, CanvasLayout, CanvasState, RenderError,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "tiles-render",
+    about = "Render tiles and the tiles canvas to PNG or SVG"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Render a single tile
+    Tile {
+        #[arg(long)]
+        token_id: String,
+        #[command(flatten)]
+        options: RenderOptions,
+    },
+    /// Render every tile onto the full canvas
+    Canvas {
+        /// Number of tiles per canvas row
+        #[arg(long, default_value_t = 100)]
+        columns: u32,
+        #[command(flatten)]
+        options: RenderOptions,
+    },
+    /// Render a canvas of randomly generated test tiles
+    Pattern {
+        #[arg(long, default_value_t = 100)]
+        columns: u32,
+        #[arg(long, default_value_t = 100)]
+        rows: u32,
+        #[arg(long, value_enum, default_value_t = Pattern::Mixed)]
+        pattern: Pattern,
+        #[arg(long, value_enum, default_value_t = Palette::Modern)]
+        palette: Palette,
+        /// Seed for the pattern choices, defaults to the current time
+        #[arg(long)]
+        seed: Option<u64>,
+        #[arg(long, value_enum, default_value_t = Format::Png)]
+        format: Format,
+        /// Output pixels per tile pixel
+        #[arg(long, default_value_t = 1)]
+        scale: u32,
+        #[arg(long)]
+        grid_lines: bool,
+        /// Also write the generated tiles as a JSON state dump for `--state`
+        #[arg(long)]
+        state_out: Option<PathBuf>,
+        #[arg(long, short)]
+        out: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct RenderOptions {
+    /// JSON state dump (`{"time": ..., "tiles": {...}}`)
+    #[arg(long, conflicts_with = "events", required_unless_present = "events")]
+    state: Option<PathBuf>,
+    /// JSON array of contract events to replay
+    #[arg(long)]
+    events: Option<PathBuf>,
+    /// Block time in seconds, overrides the state dump time
+    #[arg(long)]
+    time: Option<u64>,
+    #[arg(long, value_enum, default_value_t = Format::Png)]
+    format: Format,
+    /// Output pixels per tile pixel
+    #[arg(long, default_value_t = 10)]
+    scale: u32,
+    #[arg(long)]
+    grid_lines: bool,
+    #[arg(long, short)]
+    out: PathBuf,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Png,
+    Svg,
+}
+
+impl RenderOptions {
+    fn load_state(&self) -> Result<CanvasState, RenderError> {
+        let mut state = match (&self.state, &self.events) {
+            (Some(path), _) => CanvasState::from_json(&fs::read_to_string(path)?)?,
+            (None, Some(path)) => {
+                let events: Vec<Event> = serde_json::from_str(&fs::read_to_string(path)?)?;
+                CanvasState::from_events(&events, self.time.unwrap_or_default())?
+            }
+            (None, None) => {
+                return Err(RenderError::InvalidOptions(
+                    "either --state or --events is required".to_string(),
+                ))
+            }
+        };
+        if let Some(time) = self.time {
+            state.time = time;
+        }
+        Ok(state)
+    }
+}
+
+fn run(cli: Cli) -> Result<(), RenderError> {
+    match cli.command {
+        Command::Tile { token_id, options } => {
+            let state = options.load_state()?;
+            let metadata = state.tile(&token_id)?;
+            let time = state.render_time(&token_id);
+            let bytes = match options.format {
+                Format::Png => png::render_tile(
+                    metadata,
+                    &state.config,
+                    time,
+                    options.scale,
+                    options.grid_lines,
+                )?,
+                Format::Svg => svg::render_tile(
+                    metadata,
+                    &state.config,
+                    time,
+                    options.scale,
+                    options.grid_lines,
+                )?
+                .into_bytes(),
+            };
+            fs::write(&options.out, bytes)?;
+        }
+        Command::Canvas { columns, options } => {
+            let state = options.load_state()?;
+            let layout = CanvasLayout::fit(&state, columns)?;
+            let bytes = render_canvas(
+                &state,
+                layout,
+                options.format,
+                options.scale,
+                options.grid_lines,
+            )?;
+            fs::write(&options.out, bytes)?;
+        }
+        Command::Pattern {
+            columns,
+            rows,
+            pattern,
+            palette,
+            seed,
+            format,
+            scale,
+            grid_lines,
+            state_out,
+            out,
+        } => {
+            let layout = CanvasLayout::new(columns, rows)?;
+            let seed = seed.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+            });
+            let state = generate_canvas(&Config::default(), layout, pattern, palette, seed);
+            let bytes = render_canvas(&state, layout, format, scale, grid_lines)?;
+            if let Some(path) = state_out {
+                fs::write(path, serde_json::to_string(&state)?)?;
+            }
+            fs::write(&out, bytes)?;
+        }
+    }
+    Ok(())
+}
+
+fn render_canvas(
+    state: &CanvasState,
+    layout: CanvasLayout,
+    format: Format,
+    scale: u32,
+    grid_lines: bool,
+) -> Result<Vec<u8>, RenderError> {
+    Ok(match format {
+        Format::Png => png::render_canvas(state, layout, scale, grid_lines)?,
+        Format::Svg => svg::render_canvas(state, layout, scale, grid_lines)?.into_bytes(),
+    })
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+
+use clap::ValueEnum;
+use cosmwasm_std::Addr;
+use tiles::core::{
+    config::Config,
+    tile::metadata::{PixelData, TileMetadata},
+};
+
+use crate::{canvas::CanvasLayout, state::CanvasState};
+
+/// Fill of a generated test tile
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Pattern {
+    Checkerboard,
+    Gradient,
+    Random,
+    Spiral,
+    Wave,
+    /// A different pattern per tile, picked at random
+    Mixed,
+}
+
+/// Color sets shared with `scripts/tools/colors.py`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Palette {
+    Modern,
+    Basic,
+    Natural,
+    Pastel,
+    Vibrant,
+}
+
+impl Palette {
+    pub fn colors(self) -> &'static [&'static str] {
+        match self {
+            Palette::Modern => &[
+                "#9B87F5", "#7E69AB", "#6E59A5", "#D6BCFA", "#FF719A", "#FFA99F", "#FFE29F",
+                "#ABECD6", "#8B5CF6", "#EC4899", "#F472B6", "#34D399", "#A78BFA", "#93C5FD",
+                "#C4B5FD", "#6EE7B7",
+            ],
+            Palette::Basic => &[
+                "#000000", "#FFFFFF", "#FF0000", "#00FF00", "#0000FF", "#FFFF00", "#FF00FF",
+                "#00FFFF", "#FF8800", "#88FF00", "#0088FF", "#8800FF", "#FF0088", "#008888",
+                "#888800", "#880088",
+            ],
+            Palette::Natural => &[
+                "#1A1A1A", "#F2F2F2", "#D4A373", "#CCD5AE", "#E9EDC9", "#FEFAE0", "#FAEDCD",
+                "#D4A373", "#A98467", "#6C584C", "#ADC178", "#A98467", "#DDA15E", "#BC6C25",
+                "#606C38", "#283618",
+            ],
+            Palette::Pastel => &[
+                "#264653", "#F4F1DE", "#E9C46A", "#F4A261", "#E76F51", "#2A9D8F", "#FFB5A7",
+                "#FCD5CE", "#F8EDEB", "#F9DCC4", "#FEC89A", "#B7B7A4", "#A5A58D", "#6B705C",
+                "#3D405B", "#81B29A",
+            ],
+            Palette::Vibrant => &[
+                "#2D00F7", "#F20089", "#FF0000", "#00FF00", "#6A00F4", "#8900F2", "#A100F2",
+                "#B100E8", "#BC00DD", "#D100D1", "#DB00B6", "#E500A4", "#F20089", "#FF0F7B",
+                "#FF3366", "#FF6B6B",
+            ],
+        }
+    }
+}
+
+/// SplitMix64, so the same seed always yields the same canvas
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+/// Builds a canvas of test tiles that never expire, for trying out the
+/// renderer without a chain
+pub fn generate_canvas(
+    config: &Config,
+    layout: CanvasLayout,
+    pattern: Pattern,
+    palette: Palette,
+    seed: u64,
+) -> CanvasState {
+    let mut rng = Rng(seed);
+    let tiles = (1..=layout.columns * layout.rows)
+        .map(|token_id| {
+            let metadata = generate_tile(config, pattern, palette, &mut rng);
+            (token_id.to_string(), metadata)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    CanvasState {
+        time: 0,
+        config: config.clone(),
+        tiles,
+        frozen_at: BTreeMap::new(),
+    }
+}
+
+fn generate_tile(
+    config: &Config,
+    pattern: Pattern,
+    palette: Palette,
+    rng: &mut Rng,
+) -> TileMetadata {
+    const PATTERNS: [Pattern; 5] = [
+        Pattern::Checkerboard,
+        Pattern::Gradient,
+        Pattern::Random,
+        Pattern::Spiral,
+        Pattern::Wave,
+    ];
+    let pattern = match pattern {
+        Pattern::Mixed => PATTERNS[rng.below(PATTERNS.len())],
+        pattern => pattern,
+    };
+    let colors = palette.colors();
+    let offset = rng.below(colors.len());
+    let second = rng.below(colors.len());
+    let (width, height) = (config.tile_width, config.tile_height);
+
+    let mut metadata = TileMetadata::new(config);
+    for (index, pixel) in metadata.pixels.iter_mut().enumerate() {
+        let (x, y) = config.pixel_position(index as u32);
+        let shade = match pattern {
+            Pattern::Checkerboard if (x + y) % 2 == 0 => offset,
+            Pattern::Checkerboard => second,
+            Pattern::Gradient => {
+                let span = (width + height).saturating_sub(2).max(1) as usize;
+                offset + (x + y) as usize * (colors.len() - 1) / span
+            }
+            Pattern::Random | Pattern::Mixed => rng.below(colors.len()),
+            Pattern::Spiral => {
+                let ring = x.min(y).min(width - 1 - x).min(height - 1 - y);
+                offset + ring as usize
+            }
+            Pattern::Wave => {
+                let crest = (f64::from(x) * 0.8).sin() * 2.0;
+                offset + ((f64::from(y) + crest + 2.0) / 2.0) as usize
+            }
+        };
+        *pixel = PixelData {
+            color: colors[shade % colors.len()].to_string(),
+            expiration_timestamp: u64::MAX,
+            last_updated_by: Addr::unchecked("pattern"),
+            ..pixel.clone()
+        };
+    }
+    metadata
+}
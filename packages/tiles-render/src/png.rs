@@ -0,0 +1,42 @@
+use tiles::core::{config::Config, tile::metadata::TileMetadata};
+
+use crate::{
+    canvas::{rasterize_canvas, rasterize_tile, CanvasLayout, Raster},
+    error::RenderError,
+    state::CanvasState,
+};
+
+/// Encodes the raster with fixed settings so identical input yields identical bytes
+pub fn encode(raster: &Raster) -> Result<Vec<u8>, RenderError> {
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = ::png::Encoder::new(&mut bytes, raster.width, raster.height);
+        encoder.set_color(::png::ColorType::Rgb);
+        encoder.set_depth(::png::BitDepth::Eight);
+        encoder.set_compression(::png::Compression::Default);
+        encoder.set_filter(::png::FilterType::NoFilter);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&raster.data)?;
+        writer.finish()?;
+    }
+    Ok(bytes)
+}
+
+pub fn render_tile(
+    metadata: &TileMetadata,
+    config: &Config,
+    time: u64,
+    scale: u32,
+    grid_lines: bool,
+) -> Result<Vec<u8>, RenderError> {
+    encode(&rasterize_tile(metadata, config, time, scale, grid_lines)?)
+}
+
+pub fn render_canvas(
+    state: &CanvasState,
+    layout: CanvasLayout,
+    scale: u32,
+    grid_lines: bool,
+) -> Result<Vec<u8>, RenderError> {
+    encode(&rasterize_canvas(state, layout, scale, grid_lines)?)
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::Event;
+use serde::{Deserialize, Serialize};
+use tiles::core::{config::Config, replay::TileStateReplayer, tile::metadata::TileMetadata};
+
+use crate::error::RenderError;
+
+/// Snapshot of every tile's metadata at a point in time, as dumped by indexers
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct CanvasState {
+    /// Block time (seconds) used to decide which pixels are expired
+    pub time: u64,
+    /// Contract config, defaults to the stock tile size and color when absent
+    #[serde(default)]
+    pub config: Config,
+    pub tiles: BTreeMap<String, TileMetadata>,
+    /// Freeze time of frozen tiles by token id, see [`CanvasState::render_time`]
+    #[serde(default)]
+    pub frozen_at: BTreeMap<String, u64>,
+}
+
+impl CanvasState {
+    pub fn from_json(json: &str) -> Result<Self, RenderError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Rebuilds the canvas by replaying the ordered events of the tiles contract
+    pub fn from_events<'a>(
+        events: impl IntoIterator<Item = &'a Event>,
+        time: u64,
+    ) -> Result<Self, RenderError> {
+        let mut replayer = TileStateReplayer::new();
+        replayer.replay(events)?;
+
+        let config = replayer.config().cloned().unwrap_or_default();
+        let mut state = Self {
+            time,
+            config,
+            ..Self::default()
+        };
+        for (token_id, tile) in replayer.into_tiles() {
+            if let Some(frozen_at) = tile.frozen_at {
+                state.frozen_at.insert(token_id.clone(), frozen_at);
+            }
+            state.tiles.insert(token_id, tile.metadata);
+        }
+        Ok(state)
+    }
+
+    /// Time to render a tile at. Frozen tiles render as they looked when they
+    /// were frozen, like the contract's `TileSvg` query does.
+    pub fn render_time(&self, token_id: &str) -> u64 {
+        self.frozen_at
+            .get(token_id)
+            .map_or(self.time, |frozen_at| (*frozen_at).min(self.time))
+    }
+
+    pub fn tile(&self, token_id: &str) -> Result<&TileMetadata, RenderError> {
+        self.tiles
+            .get(token_id)
+            .ok_or_else(|| RenderError::UnknownToken(token_id.to_string()))
+    }
+}
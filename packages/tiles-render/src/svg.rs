@@ -0,0 +1,89 @@
+use std::fmt::Write;
+
+use tiles::core::{config::Config, tile::metadata::TileMetadata};
+
+use crate::{
+    canvas::{scaled_size, CanvasLayout},
+    error::RenderError,
+    state::CanvasState,
+};
+
+pub fn render_tile(
+    metadata: &TileMetadata,
+    config: &Config,
+    time: u64,
+    scale: u32,
+    grid_lines: bool,
+) -> Result<String, RenderError> {
+    scaled_size(config.tile_width.into(), config.tile_height.into(), scale)?;
+    Ok(metadata.to_svg(config, time, scale, grid_lines))
+}
+
+/// Renders every tile of the state onto one SVG, in canvas pixel units
+pub fn render_canvas(
+    state: &CanvasState,
+    layout: CanvasLayout,
+    scale: u32,
+    grid_lines: bool,
+) -> Result<String, RenderError> {
+    let config = &state.config;
+    let (scaled_width, scaled_height) = scaled_size(
+        u64::from(layout.columns) * u64::from(config.tile_width),
+        u64::from(layout.rows) * u64::from(config.tile_height),
+        scale,
+    )?;
+    let default_color = config.default_color.as_str();
+    let width = layout.columns * config.tile_width;
+    let height = layout.rows * config.tile_height;
+    let mut svg = String::new();
+
+    write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{scaled_width}" height="{scaled_height}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#
+    )
+    .unwrap();
+    write!(
+        svg,
+        r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{default_color}"/>"#
+    )
+    .unwrap();
+
+    for (token_id, metadata) in &state.tiles {
+        let (column, row) = layout.position(token_id)?;
+        let (origin_x, origin_y) = (column * config.tile_width, row * config.tile_height);
+        let time = state.render_time(token_id);
+        for index in 0..metadata.pixels.len() {
+            let color = metadata.pixel_color(index, default_color, time);
+            // The background already covers unpainted pixels
+            if color == default_color {
+                continue;
+            }
+            let (x, y) = config.pixel_position(index as u32);
+            write!(
+                svg,
+                r#"<rect x="{}" y="{}" width="1" height="1" fill="{color}"/>"#,
+                origin_x + x,
+                origin_y + y
+            )
+            .unwrap();
+        }
+    }
+
+    if grid_lines {
+        let mut path = String::new();
+        for column in 0..=layout.columns {
+            write!(path, "M{} 0V{height}", column * config.tile_width).unwrap();
+        }
+        for row in 0..=layout.rows {
+            write!(path, "M0 {}H{width}", row * config.tile_height).unwrap();
+        }
+        write!(
+            svg,
+            r#"<path d="{path}" stroke="black" stroke-width="1" vector-effect="non-scaling-stroke" fill="none"/>"#
+        )
+        .unwrap();
+    }
+
+    svg.push_str("</svg>");
+    Ok(svg)
+}
@@ -0,0 +1,279 @@
+use cosmwasm_std::{Addr, Event};
+use tiles::{
+    core::{
+        config::Config,
+        tile::{
+            metadata::{PixelData, TileMetadata},
+            policy::TilePolicy,
+            Tile,
+        },
+    },
+    events::{EventData, MintMetadataEventData, PixelUpdateEventData, TilePolicyUpdateEventData},
+};
+use tiles_render::{
+    canvas::{rasterize_canvas, scaled_size},
+    pattern::{generate_canvas, Palette, Pattern},
+    png, svg, CanvasLayout, CanvasState,
+};
+
+fn painted_tile(color: &str) -> TileMetadata {
+    let mut metadata = TileMetadata::default();
+    metadata.pixels[0] = PixelData {
+        id: 0,
+        color: color.to_string(),
+        expiration_timestamp: 2000,
+        last_updated_by: Addr::unchecked("painter"),
+        last_updated_at: 1000,
+        keyframes: vec![],
+        annotation: None,
+    };
+    metadata
+}
+
+fn sample_state() -> CanvasState {
+    let mut state = CanvasState {
+        time: 1500,
+        ..CanvasState::default()
+    };
+    state.tiles.insert("1".to_string(), painted_tile("#FF0000"));
+    state.tiles.insert("4".to_string(), painted_tile("#0000FF"));
+    state
+}
+
+fn decode_png(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+    let decoder = ::png::Decoder::new(bytes);
+    let mut reader = decoder.read_info().unwrap();
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data).unwrap();
+    data.truncate(info.buffer_size());
+    (info.width, info.height, data)
+}
+
+fn rgb_at(data: &[u8], width: u32, x: u32, y: u32) -> [u8; 3] {
+    let offset = ((y * width + x) * 3) as usize;
+    [data[offset], data[offset + 1], data[offset + 2]]
+}
+
+#[test]
+fn png_output_is_byte_identical_for_same_state() {
+    let state = sample_state();
+    let layout = CanvasLayout::fit(&state, 3).unwrap();
+
+    let first = png::render_canvas(&state, layout, 4, true).unwrap();
+    let second = png::render_canvas(&state.clone(), layout, 4, true).unwrap();
+    assert_eq!(first, second);
+
+    let svg_first = svg::render_canvas(&state, layout, 4, true).unwrap();
+    let svg_second = svg::render_canvas(&state, layout, 4, true).unwrap();
+    assert_eq!(svg_first, svg_second);
+}
+
+#[test]
+fn canvas_places_tiles_by_token_id() {
+    let state = sample_state();
+    let layout = CanvasLayout::fit(&state, 3).unwrap();
+    assert_eq!(layout, CanvasLayout::new(3, 2).unwrap());
+
+    let (width, height, data) = decode_png(&png::render_canvas(&state, layout, 1, false).unwrap());
+    assert_eq!((width, height), (30, 20));
+
+    // Token 1 is the first tile, token 4 starts the second row
+    assert_eq!(rgb_at(&data, width, 0, 0), [0xFF, 0, 0]);
+    assert_eq!(rgb_at(&data, width, 0, 10), [0, 0, 0xFF]);
+    assert_eq!(rgb_at(&data, width, 10, 0), [0xFF, 0xFF, 0xFF]);
+}
+
+#[test]
+fn expired_pixels_render_default_color() {
+    let mut state = sample_state();
+    state.time = 2000;
+    let layout = CanvasLayout::fit(&state, 3).unwrap();
+
+    let raster = rasterize_canvas(&state, layout, 1, false).unwrap();
+    assert!(raster.data.iter().all(|channel| *channel == 0xFF));
+}
+
+#[test]
+fn tile_png_is_scaled() {
+    let (width, height, data) = decode_png(
+        &png::render_tile(&painted_tile("#00FF00"), &Config::default(), 1500, 3, false).unwrap(),
+    );
+    assert_eq!((width, height), (30, 30));
+    assert_eq!(rgb_at(&data, width, 2, 2), [0, 0xFF, 0]);
+    assert_eq!(rgb_at(&data, width, 3, 0), [0xFF, 0xFF, 0xFF]);
+}
+
+#[test]
+fn tile_png_draws_pixel_grid_lines() {
+    let (width, _, data) = decode_png(
+        &png::render_tile(&painted_tile("#00FF00"), &Config::default(), 1500, 3, true).unwrap(),
+    );
+    // Lines run along every pixel boundary, pixel interiors keep their color
+    assert_eq!(rgb_at(&data, width, 0, 1), [0, 0, 0]);
+    assert_eq!(rgb_at(&data, width, 3, 4), [0, 0, 0]);
+    assert_eq!(rgb_at(&data, width, 4, 4), [0xFF, 0xFF, 0xFF]);
+    assert_eq!(rgb_at(&data, width, 1, 1), [0, 0xFF, 0]);
+}
+
+#[test]
+fn oversized_output_is_rejected() {
+    let state = sample_state();
+    let layout = CanvasLayout::new(100, 100).unwrap();
+
+    // Would overflow u32 when multiplied out
+    assert!(png::render_canvas(&state, layout, 70_000, false).is_err());
+    assert!(svg::render_canvas(&state, layout, 70_000, false).is_err());
+    assert!(png::render_tile(
+        &painted_tile("#00FF00"),
+        &Config::default(),
+        1500,
+        u32::MAX,
+        false
+    )
+    .is_err());
+    // Fits in u32 but is larger than the renderer allows
+    assert!(png::render_canvas(&state, layout, 12, false).is_err());
+    assert!(scaled_size(100 * 10, 100 * 10, 10).is_ok());
+}
+
+#[test]
+fn patterns_are_reproducible_from_the_seed() {
+    let layout = CanvasLayout::new(3, 2).unwrap();
+    let config = Config::default();
+    let first = generate_canvas(&config, layout, Pattern::Mixed, Palette::Modern, 7);
+    let second = generate_canvas(&config, layout, Pattern::Mixed, Palette::Modern, 7);
+    assert_eq!(first, second);
+    assert_eq!(first.tiles.len(), 6);
+
+    let checkerboard = generate_canvas(&config, layout, Pattern::Checkerboard, Palette::Basic, 7);
+    let tile = checkerboard.tile("1").unwrap();
+    assert_eq!(tile.pixels[0].color, tile.pixels[2].color);
+    assert!(Palette::Basic.colors().contains(&tile.pixel_color(
+        1,
+        &config.default_color,
+        checkerboard.time
+    )));
+}
+
+#[test]
+fn state_can_be_rebuilt_from_events() {
+    let on_chain = |event: Event| {
+        let mut event = event;
+        event.ty = format!("wasm-{}", event.ty);
+        event
+    };
+    let initial = TileMetadata::default();
+    let painted = painted_tile("#FF0000");
+    let events = vec![
+        on_chain(
+            MintMetadataEventData {
+                token_id: "1".to_string(),
+                owner: Addr::unchecked("owner"),
+                tile_hash: initial.hash(),
+                new_pixels: initial.pixels,
+            }
+            .into_event(),
+        ),
+        on_chain(
+            PixelUpdateEventData {
+                token_id: "1".to_string(),
+                new_pixels: vec![painted.pixels[0].clone()],
+                tile_hash: painted.hash(),
+            }
+            .into_event(),
+        ),
+    ];
+
+    let state = CanvasState::from_events(&events, 1500).unwrap();
+    assert_eq!(state.tile("1").unwrap(), &painted);
+}
+
+#[test]
+fn frozen_tiles_render_at_their_freeze_time() {
+    let mut state = sample_state();
+    state.time = 2500;
+    state.frozen_at.insert("1".to_string(), 1500);
+    let layout = CanvasLayout::fit(&state, 3).unwrap();
+
+    // Token 1 keeps its expired pixel, token 4 fades as usual
+    let (width, _, data) = decode_png(&png::render_canvas(&state, layout, 1, false).unwrap());
+    assert_eq!(rgb_at(&data, width, 0, 0), [0xFF, 0, 0]);
+    assert_eq!(rgb_at(&data, width, 0, 10), [0xFF, 0xFF, 0xFF]);
+    let canvas = svg::render_canvas(&state, layout, 1, false).unwrap();
+    assert!(canvas.contains("#FF0000"));
+    assert!(!canvas.contains("#0000FF"));
+}
+
+#[test]
+fn frozen_tiles_rebuilt_from_events_match_the_contract_svg() {
+    let on_chain = |event: Event| {
+        let mut event = event;
+        event.ty = format!("wasm-{}", event.ty);
+        event
+    };
+    let initial = TileMetadata::default();
+    let painted = painted_tile("#FF0000");
+    let events = vec![
+        on_chain(
+            MintMetadataEventData {
+                token_id: "1".to_string(),
+                owner: Addr::unchecked("owner"),
+                tile_hash: initial.hash(),
+                new_pixels: initial.pixels,
+            }
+            .into_event(),
+        ),
+        on_chain(
+            PixelUpdateEventData {
+                token_id: "1".to_string(),
+                new_pixels: vec![painted.pixels[0].clone()],
+                tile_hash: painted.hash(),
+            }
+            .into_event(),
+        ),
+        on_chain(
+            TilePolicyUpdateEventData {
+                token_id: "1".to_string(),
+                owner: Addr::unchecked("owner"),
+                policy: TilePolicy {
+                    frozen: true,
+                    ..TilePolicy::default()
+                },
+                frozen_at: Some(1500),
+            }
+            .into_event(),
+        ),
+    ];
+
+    let state = CanvasState::from_events(&events, 2500).unwrap();
+    assert_eq!(state.render_time("1"), 1500);
+    let config = Config::default();
+    // The contract renders frozen tiles at `Tile::render_time`
+    let tile = Tile {
+        frozen_at: Some(1500),
+        ..Tile::new(painted.hash())
+    };
+    let rendered = svg::render_tile(
+        state.tile("1").unwrap(),
+        &config,
+        state.render_time("1"),
+        10,
+        false,
+    )
+    .unwrap();
+    assert_eq!(
+        rendered,
+        painted.to_svg(&config, tile.render_time(2500), 10, false)
+    );
+    assert!(rendered.contains("#FF0000"));
+}
+
+#[test]
+fn invalid_token_ids_are_rejected() {
+    let mut state = CanvasState::default();
+    state
+        .tiles
+        .insert("not-a-number".to_string(), TileMetadata::default());
+
+    assert!(CanvasLayout::fit(&state, 10).is_err());
+}
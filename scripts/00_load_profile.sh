@@ -0,0 +1,10 @@
+#!/bin/bash
+set -x
+
+# Load the deployment profile (profiles/<name>.toml), testnet unless PROFILE is set
+PROFILE="${PROFILE:-testnet}"
+PROFILE_JSON=$(cargo run -q -p tiles-cli -- --profile "$PROFILE" profile)
+
+export CHAIN_ID=$(echo "$PROFILE_JSON" | jq -r '.network.chain_id')
+export NODE_URL=$(echo "$PROFILE_JSON" | jq -r '.network.node')
+export DEPLOYER_ADDRESS=$(echo "$PROFILE_JSON" | jq -r '.deploy.deployer')
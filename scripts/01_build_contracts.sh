@@ -1,9 +1,6 @@
 #!/bin/bash
 set -e
 
-# Load constants
-source scripts/00_load_constants.sh
-
 # Create state directory
 mkdir -p scripts/state
 
@@ -1,7 +1,7 @@
 #!/bin/bash
 set -e
 
-source scripts/00_load_constants.sh
+source scripts/00_load_profile.sh
 
 # Get tx hash from argument
 TX_HASH=$1
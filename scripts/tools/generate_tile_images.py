@@ -3,23 +3,26 @@
 import os
 import json
 import random
+import subprocess
 from datetime import datetime, timedelta
 from pathlib import Path
 from PIL import Image, ImageDraw
 from colors import MODERN_PALETTE
 
-# Constants from messages/constants.json
-with open('scripts/messages/constants.json', 'r') as f:
-    constants = json.load(f)
-    
-DEFAULT_COLOR = constants['DEFAULT_COLOR']
-TILE_SIZE = constants['TILE_SIZE']
-PIXELS_PER_TILE = constants['PIXELS_PER_TILE']
-PIXEL_MIN_EXPIRATION = constants['PIXEL_MIN_EXPIRATION']
-PIXEL_MAX_EXPIRATION = constants['PIXEL_MAX_EXPIRATION']
-COLLECTION_NAME = constants['COLLECTION_NAME']
-COLLECTION_DESCRIPTION = constants['COLLECTION_DESCRIPTION']
-MAX_TOKEN_LIMIT = constants['MAX_TOKEN_LIMIT']
+# Settings from the deployment profile, testnet unless PROFILE is set
+profile = json.loads(subprocess.check_output([
+    "cargo", "run", "-q", "-p", "tiles-cli", "--",
+    "--profile", os.environ.get("PROFILE", "testnet"), "profile",
+]))
+
+DEFAULT_COLOR = profile['tiles']['default_color']
+TILE_WIDTH = profile['tiles']['tile_width']
+TILE_HEIGHT = profile['tiles']['tile_height']
+PIXEL_MIN_EXPIRATION = profile['tiles']['pixel_min_expiration']
+PIXEL_MAX_EXPIRATION = profile['tiles']['pixel_max_expiration']
+COLLECTION_NAME = profile['deploy']['collection_name']
+COLLECTION_DESCRIPTION = profile['deploy']['collection_description']
+MAX_TOKEN_LIMIT = profile['deploy']['num_tokens']
 
 # Sample Stargaze addresses for randomization
 SAMPLE_ADDRESSES = [
@@ -46,21 +49,21 @@ def get_tile_coordinates(tile_id: int) -> tuple:
     """Convert tile ID to grid coordinates."""
     # Assuming tiles are numbered from left to right, top to bottom
     # tile_id starts from 1
-    x = (tile_id - 1) % TILE_SIZE
-    y = (tile_id - 1) // TILE_SIZE
+    x = (tile_id - 1) % TILE_WIDTH
+    y = (tile_id - 1) // TILE_WIDTH
     return (x, y)
 
 def create_tile_image(tile_id: int, size: tuple = (500, 500)) -> Image:
     """Create a tile image with random colored pixels."""
     background_color = DEFAULT_COLOR
-    cell_size = size[0] // TILE_SIZE
+    cell_size = min(size[0] // TILE_WIDTH, size[1] // TILE_HEIGHT)
     
     # Create base image
     img = Image.new('RGB', size, background_color)
     draw = ImageDraw.Draw(img)
     
     # Create a list of all possible positions
-    positions = [(x, y) for x in range(TILE_SIZE) for y in range(TILE_SIZE)]
+    positions = [(x, y) for x in range(TILE_WIDTH) for y in range(TILE_HEIGHT)]
     
     # Randomly select 30-50% of positions to color
     num_colored = random.randint(30, 50)
@@ -78,9 +81,11 @@ def create_tile_image(tile_id: int, size: tuple = (500, 500)) -> Image:
     
     # Draw grid lines
     grid_color = "#000000"
-    for i in range(TILE_SIZE + 1):
+    for i in range(TILE_WIDTH + 1):
         line_pos = i * cell_size
         draw.line([(line_pos, 0), (line_pos, size[1])], fill=grid_color, width=2)
+    for i in range(TILE_HEIGHT + 1):
+        line_pos = i * cell_size
         draw.line([(0, line_pos), (size[0], line_pos)], fill=grid_color, width=2)
     
     return img, pixel_colors
@@ -93,7 +98,7 @@ def create_metadata(tile_id: int, pixel_colors: dict) -> dict:
     attributes = [
         {
             "trait_type": "Grid Size",
-            "value": f"{TILE_SIZE}x{TILE_SIZE}"
+            "value": f"{TILE_WIDTH}x{TILE_HEIGHT}"
         },
         {
             "trait_type": "Active Colors",
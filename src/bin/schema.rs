@@ -0,0 +1,9 @@
+use std::path::Path;
+
+use tiles::schema::{write_schemas, SCHEMA_DIR};
+
+fn main() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(SCHEMA_DIR);
+    write_schemas(&dir).expect("failed to write schemas");
+    println!("Schemas written to {}", dir.display());
+}
@@ -0,0 +1,50 @@
+use cosmwasm_std::{Addr, Deps, Order, StdResult, Storage};
+
+use crate::{
+    contract::{
+        msg::PaintCommitResponse,
+        state::{CONFIG, PAINT_COMMITS, PIXEL_RESERVATIONS},
+    },
+    core::tile::commit::PaintCommit,
+};
+
+pub fn query_paint_commit(
+    deps: Deps,
+    token_id: String,
+    committer: String,
+) -> StdResult<PaintCommitResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let committer = deps.api.addr_validate(&committer)?;
+    let commit = PAINT_COMMITS.may_load(deps.storage, (&token_id, &committer))?;
+    Ok(PaintCommitResponse {
+        token_id,
+        committer,
+        reveal_until: commit
+            .as_ref()
+            .map(|commit| commit.reveal_until(config.reveal_window_blocks)),
+        commit,
+    })
+}
+
+/// Drops every paint commit on a tile together with its pixel reservations,
+/// returning the commits so their escrow can be refunded
+pub fn clear_paint_commits(
+    storage: &mut dyn Storage,
+    token_id: &str,
+) -> StdResult<Vec<(Addr, PaintCommit)>> {
+    let commits: Vec<(Addr, PaintCommit)> = PAINT_COMMITS
+        .prefix(token_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (committer, _) in &commits {
+        PAINT_COMMITS.remove(storage, (token_id, committer));
+    }
+    let reserved: Vec<u32> = PIXEL_RESERVATIONS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for id in reserved {
+        PIXEL_RESERVATIONS.remove(storage, (token_id, id));
+    }
+    Ok(commits)
+}
@@ -6,7 +6,7 @@ use crate::contract::{
     error::ContractError,
     execute::execute_handler,
     instantiate::instantiate_handler,
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    msg::{ContractExecuteMsg, InstantiateMsg, QueryMsg},
     query::query_handler,
 };
 
@@ -25,7 +25,7 @@ pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    msg: ExecuteMsg,
+    msg: ContractExecuteMsg,
 ) -> Result<Response<StargazeMsgWrapper>, ContractError> {
     execute_handler(deps, env, info, msg)
 }
@@ -0,0 +1,56 @@
+use cosmwasm_std::{Addr, BlockInfo, Deps, Env, Order, StdResult, Storage};
+
+use crate::{
+    contract::{
+        msg::{PainterGrantInfo, PaintersResponse},
+        state::PAINTER_GRANTS,
+    },
+    core::tile::policy::TilePolicy,
+};
+
+/// Whether `painter` may paint `pixels` pixels of the tile, through its policy
+/// or a live grant. Does not use up the grant.
+pub fn may_paint(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    token_id: &str,
+    policy: &TilePolicy,
+    owner: &Addr,
+    painter: &Addr,
+    pixels: u32,
+) -> StdResult<bool> {
+    if policy.frozen {
+        return Ok(false);
+    }
+    if policy.allows(painter, owner) {
+        return Ok(true);
+    }
+    Ok(PAINTER_GRANTS
+        .may_load(storage, (token_id, painter))?
+        .is_some_and(|grant| !grant.is_expired(block) && grant.covers(pixels)))
+}
+
+/// Drops every grant on a tile, called whenever it changes hands or is burned
+pub fn clear_painter_grants(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    let painters: Vec<Addr> = PAINTER_GRANTS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for painter in painters {
+        PAINTER_GRANTS.remove(storage, (token_id, &painter));
+    }
+    Ok(())
+}
+
+pub fn query_painters(deps: Deps, env: Env, token_id: String) -> StdResult<PaintersResponse> {
+    let painters = PAINTER_GRANTS
+        .prefix(&token_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |(_, grant)| !grant.is_expired(&env.block))
+        })
+        .map(|item| item.map(|(painter, grant)| PainterGrantInfo { painter, grant }))
+        .collect::<StdResult<_>>()?;
+    Ok(PaintersResponse { token_id, painters })
+}
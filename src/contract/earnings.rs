@@ -0,0 +1,45 @@
+use cosmwasm_std::{Deps, Order, StdResult, Storage};
+
+use crate::{
+    contract::{
+        msg::{OwnerEarningsResponse, TileEarningsResponse},
+        state::{OWNER_EARNINGS, TILE_EARNINGS},
+    },
+    core::{earnings::Earnings, revenue::PaymentDistribution},
+};
+
+/// Adds one paint's payment split to the tile and owner totals
+pub fn record_earnings(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    denom: &str,
+    distribution: &PaymentDistribution,
+) -> StdResult<()> {
+    let add = |earnings: Option<Earnings>| -> StdResult<_> {
+        let mut earnings = earnings.unwrap_or_else(|| Earnings::new(denom));
+        earnings.add(distribution);
+        Ok(earnings)
+    };
+    TILE_EARNINGS.update(storage, (token_id, denom), add)?;
+    OWNER_EARNINGS.update(storage, (&distribution.owner.recipient, denom), add)?;
+    Ok(())
+}
+
+pub fn query_tile_earnings(deps: Deps, token_id: String) -> StdResult<TileEarningsResponse> {
+    let earnings = TILE_EARNINGS
+        .prefix(&token_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, earnings)| earnings))
+        .collect::<StdResult<_>>()?;
+    Ok(TileEarningsResponse { token_id, earnings })
+}
+
+pub fn query_owner_earnings(deps: Deps, owner: String) -> StdResult<OwnerEarningsResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let earnings = OWNER_EARNINGS
+        .prefix(&owner)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, earnings)| earnings))
+        .collect::<StdResult<_>>()?;
+    Ok(OwnerEarningsResponse { owner, earnings })
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{OverflowError, StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -9,6 +9,9 @@ pub enum ContractError {
     #[error("Base contract error: {0}")]
     Base(#[from] sg721_base::ContractError),
 
+    #[error("{0}")]
+    Payment(#[from] cw_utils::PaymentError),
+
     #[error("Unauthorized: sender '{sender}' is not allowed to perform this action")]
     Unauthorized { sender: String },
 
@@ -27,9 +30,92 @@ pub enum ContractError {
     #[error("Metadata hash mismatch: stored hash does not match provided metadata")]
     MetadataHashMismatch {},
 
+    #[error("Tile {token_id} cannot be burned while {live_pixels} pixels are still live")]
+    TileHasLivePixels { token_id: String, live_pixels: u32 },
+
+    #[error("Invalid config: {reason}")]
+    InvalidConfig { reason: String },
+
+    #[error("Tile dimensions cannot change after tiles have been minted")]
+    TileDimensionsLocked {},
+
+    #[error("Denom {denom} is not accepted for pixel payments")]
+    UnacceptedDenom { denom: String },
+
+    #[error("CW20 token {token} is not accepted for pixel payments")]
+    UnacceptedCw20 { token: String },
+
+    #[error("Tile {token_id} is frozen")]
+    TileFrozen { token_id: String },
+
+    #[error("{painter} is not allowed to paint tile {token_id}")]
+    PaintingNotAllowed { token_id: String, painter: String },
+
+    #[error("Invalid tile policy: {reason}")]
+    InvalidTilePolicy { reason: String },
+
+    #[error("Invalid painter grant: {reason}")]
+    InvalidPainterGrant { reason: String },
+
+    #[error("Painting grant of {painter} on tile {token_id} does not cover {pixels} more pixels")]
+    PainterGrantExceeded {
+        token_id: String,
+        painter: String,
+        pixels: u32,
+    },
+
+    #[error("Pixel {pixel_id} was paid in {denom}, taking it over has to be paid in the same")]
+    OutbidDenomMismatch { pixel_id: u32, denom: String },
+
+    #[error("Invalid commitment: expected a hex SHA-256 digest")]
+    InvalidCommitment {},
+
+    #[error("{committer} already has a paint commit on tile {token_id}")]
+    CommitPending { token_id: String, committer: String },
+
+    #[error("{committer} has no paint commit on tile {token_id}")]
+    NoPaintCommit { token_id: String, committer: String },
+
+    #[error("Revealed paint does not match the commitment")]
+    CommitmentMismatch {},
+
+    #[error("Pixel {id} is reserved by a paint commit until block {until}")]
+    PixelReserved { id: u32, until: u64 },
+
+    #[error("Pixel {id} was not reserved by the paint commit")]
+    PixelNotCommitted { id: u32 },
+
+    #[error(
+        "Paint commit on tile {token_id} can be revealed from block {from} until block {until}"
+    )]
+    OutsideRevealWindow {
+        token_id: String,
+        from: u64,
+        until: u64,
+    },
+
+    #[error("Paint commit on tile {token_id} can still be revealed until block {until}")]
+    CommitNotExpired { token_id: String, until: u64 },
+
+    #[error("Invalid image: {reason}")]
+    InvalidImage { reason: String },
+
+    #[error("Image does not change any pixel of tile {token_id}")]
+    ImageUnchanged { token_id: String },
+
     #[error("Insufficient funds: sent funds do not match required amount")]
     InsufficientFunds {},
 
+    #[error("No pending {denom} balance to withdraw")]
+    NothingToWithdraw { denom: String },
+
+    #[error("Cannot withdraw {requested}{denom}, only {available}{denom} is pending")]
+    InsufficientPendingBalance {
+        denom: String,
+        requested: Uint128,
+        available: Uint128,
+    },
+
     #[error("Overflow: {0}")]
     Overflow(String),
 }
@@ -0,0 +1,29 @@
+use cosmwasm_std::{Addr, Coin, Deps, Order, StdResult, Storage, Uint128};
+
+use crate::contract::{msg::PendingBalanceResponse, state::PENDING_BALANCES};
+
+/// Adds a payout to the recipient's pending balance
+pub fn credit_pending(
+    storage: &mut dyn Storage,
+    recipient: &Addr,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+    PENDING_BALANCES.update(storage, (recipient, denom), |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+pub fn query_pending_balance(deps: Deps, address: String) -> StdResult<PendingBalanceResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let balances = PENDING_BALANCES
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    Ok(PendingBalanceResponse { address, balances })
+}
@@ -5,16 +5,43 @@ use sg_std::StargazeMsgWrapper;
 use crate::{
     contract::{
         error::ContractError,
-        msg::{ExecuteMsg, Sg721ExecuteMsg, TileExecuteMsg},
+        msg::{ContractExecuteMsg, ExecuteMsg, ReceiveMsg, Sg721ExecuteMsg, TileExecuteMsg},
         tiles::{
-            mint::mint_handler, set_pixel_color::set_pixel_color,
+            burn::burn_handler,
+            clear_annotations::clear_annotations,
+            commit_paint::{commit_paint, expire_commit, reveal_paint},
+            grant_painter::{grant_painter, revoke_painter},
+            mint::mint_handler,
+            paint_image::paint_image,
+            receive::receive_cw20,
+            set_base_layer::set_base_layer,
+            set_cw20_price_scaling::set_cw20_price_scaling,
+            set_pixel_color::set_pixel_color,
+            set_tile_policy::set_tile_policy,
+            transfer::{send_handler, transfer_handler},
+            update_config::update_config,
             update_price_scaling::update_price_scaling,
+            withdraw::withdraw,
         },
     },
     core::tile::Tile,
 };
 
 pub fn execute_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ContractExecuteMsg,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    match msg {
+        ContractExecuteMsg::Receive(ReceiveMsg::Receive(receive_msg)) => {
+            receive_cw20(deps, env, info, receive_msg)
+        }
+        ContractExecuteMsg::Sg721(msg) => execute_sg721(deps, env, info, msg),
+    }
+}
+
+fn execute_sg721(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
@@ -32,6 +59,51 @@ pub fn execute_handler(
             TileExecuteMsg::UpdatePriceScaling(new_scaling) => {
                 update_price_scaling(deps, env, info, new_scaling)
             }
+            TileExecuteMsg::UpdateConfig(config) => update_config(deps, env, info, config),
+            TileExecuteMsg::Withdraw { denom, amount } => withdraw(deps, env, info, denom, amount),
+            TileExecuteMsg::SetCw20PriceScaling {
+                token,
+                price_scaling,
+            } => set_cw20_price_scaling(deps, env, info, token, price_scaling),
+            TileExecuteMsg::SetTilePolicy { token_id, policy } => {
+                set_tile_policy(deps, env, info, token_id, policy)
+            }
+            TileExecuteMsg::GrantPainter {
+                token_id,
+                painter,
+                expires,
+                max_pixels,
+            } => grant_painter(deps, env, info, token_id, painter, expires, max_pixels),
+            TileExecuteMsg::RevokePainter { token_id, painter } => {
+                revoke_painter(deps, env, info, token_id, painter)
+            }
+            TileExecuteMsg::SetBaseLayer { token_id, pixels } => {
+                set_base_layer(deps, env, info, token_id, pixels)
+            }
+            TileExecuteMsg::PaintImage {
+                token_id,
+                format,
+                data,
+                duration,
+            } => paint_image(deps, env, info, token_id, format, data, duration),
+            TileExecuteMsg::ClearAnnotations {
+                token_id,
+                pixel_ids,
+            } => clear_annotations(deps, env, info, token_id, pixel_ids),
+            TileExecuteMsg::CommitPaint {
+                token_id,
+                pixel_ids,
+                commitment,
+            } => commit_paint(deps, env, info, token_id, pixel_ids, commitment),
+            TileExecuteMsg::RevealPaint {
+                token_id,
+                updates,
+                salt,
+            } => reveal_paint(deps, env, info, token_id, updates, salt),
+            TileExecuteMsg::ExpireCommit {
+                token_id,
+                committer,
+            } => expire_commit(deps, env, info, token_id, committer),
         },
         ExecuteMsg::Mint {
             token_id,
@@ -46,25 +118,12 @@ pub fn execute_handler(
         ExecuteMsg::TransferNft {
             recipient,
             token_id,
-        } => {
-            let base_msg = Sg721ExecuteMsg::TransferNft {
-                recipient,
-                token_id,
-            };
-            Ok(contract.execute(deps, env, info, base_msg)?)
-        }
+        } => transfer_handler(deps, env, info, recipient, token_id),
         ExecuteMsg::SendNft {
             contract: contract_addr,
             token_id,
             msg,
-        } => {
-            let base_msg = Sg721ExecuteMsg::SendNft {
-                contract: contract_addr,
-                token_id,
-                msg,
-            };
-            Ok(contract.execute(deps, env, info, base_msg)?)
-        }
+        } => send_handler(deps, env, info, contract_addr, token_id, msg),
         ExecuteMsg::Approve {
             spender,
             token_id,
@@ -89,10 +148,7 @@ pub fn execute_handler(
             let base_msg = Sg721ExecuteMsg::RevokeAll { operator };
             Ok(contract.execute(deps, env, info, base_msg)?)
         }
-        ExecuteMsg::Burn { token_id } => {
-            let base_msg = Sg721ExecuteMsg::Burn { token_id };
-            Ok(contract.execute(deps, env, info, base_msg)?)
-        }
+        ExecuteMsg::Burn { token_id } => burn_handler(deps, env, info, token_id),
         ExecuteMsg::UpdateCollectionInfo { collection_info } => {
             let base_msg = Sg721ExecuteMsg::UpdateCollectionInfo { collection_info };
             Ok(contract.execute(deps, env, info, base_msg)?)
@@ -0,0 +1,201 @@
+use cosmwasm_std::{Deps, Empty, Order, StdError, StdResult, Storage};
+use cw_storage_plus::Bound;
+
+use crate::{
+    contract::{
+        msg::{PixelHistoryResponse, TileHistoryResponse},
+        state::{CONFIG, PIXEL_HISTORY, PIXEL_PAINT_COUNT, TILE_HISTORY_INDEX, TILE_METADATA},
+    },
+    core::tile::{history::PixelHistoryEntry, metadata::PixelData},
+    defaults::constants::{DEFAULT_HISTORY_LIMIT, MAX_HISTORY_LIMIT},
+};
+
+/// Appends the new pixel states to their ring buffers, evicting the oldest
+/// entry once a pixel has been painted `depth` times
+pub fn record_pixel_history(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    pixels: &[PixelData],
+    depth: u32,
+) -> StdResult<()> {
+    for pixel in pixels {
+        let seq = PIXEL_PAINT_COUNT
+            .may_load(storage, (token_id, pixel.id))?
+            .unwrap_or_default();
+        let key = (token_id, pixel.id, PixelHistoryEntry::slot(seq, depth));
+
+        if let Some(evicted) = PIXEL_HISTORY.may_load(storage, key)? {
+            TILE_HISTORY_INDEX.remove(
+                storage,
+                (
+                    token_id,
+                    evicted.pixel.last_updated_at,
+                    (pixel.id, evicted.seq),
+                ),
+            );
+        }
+
+        PIXEL_HISTORY.save(
+            storage,
+            key,
+            &PixelHistoryEntry {
+                seq,
+                pixel: pixel.clone(),
+            },
+        )?;
+        TILE_HISTORY_INDEX.save(
+            storage,
+            (token_id, pixel.last_updated_at, (pixel.id, seq)),
+            &Empty {},
+        )?;
+        PIXEL_PAINT_COUNT.save(storage, (token_id, pixel.id), &(seq + 1))?;
+    }
+    Ok(())
+}
+
+/// Drops every recorded paint of a burned tile
+pub fn clear_pixel_history(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    let slots: Vec<(u32, u32)> = PIXEL_HISTORY
+        .sub_prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (pixel_id, slot) in slots {
+        PIXEL_HISTORY.remove(storage, (token_id, pixel_id, slot));
+    }
+
+    let pixel_ids: Vec<u32> = PIXEL_PAINT_COUNT
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for pixel_id in pixel_ids {
+        PIXEL_PAINT_COUNT.remove(storage, (token_id, pixel_id));
+    }
+
+    let index_keys: Vec<(u64, (u32, u64))> = TILE_HISTORY_INDEX
+        .sub_prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (painted_at, paint) in index_keys {
+        TILE_HISTORY_INDEX.remove(storage, (token_id, painted_at, paint));
+    }
+    Ok(())
+}
+
+/// Removes the annotation from every stored paint of a pixel
+pub fn clear_history_annotations(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    pixel_id: u32,
+) -> StdResult<()> {
+    let annotated: Vec<(u32, PixelHistoryEntry)> = PIXEL_HISTORY
+        .prefix((token_id, pixel_id))
+        .range(storage, None, None, Order::Ascending)
+        .filter(|entry| {
+            entry
+                .as_ref()
+                .map_or(true, |(_, entry)| entry.pixel.annotation.is_some())
+        })
+        .collect::<StdResult<_>>()?;
+    for (slot, mut entry) in annotated {
+        entry.pixel.annotation = None;
+        PIXEL_HISTORY.save(storage, (token_id, pixel_id, slot), &entry)?;
+    }
+    Ok(())
+}
+
+pub fn query_pixel_history(
+    deps: Deps,
+    token_id: String,
+    pixel_id: u32,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PixelHistoryResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    ensure_tile_exists(deps, &token_id)?;
+    if pixel_id >= config.pixels_per_tile() {
+        return Err(StdError::generic_err(format!(
+            "Invalid pixel ID: {} (tile has {} pixels)",
+            pixel_id,
+            config.pixels_per_tile()
+        )));
+    }
+    if !config.history_enabled() {
+        return Ok(PixelHistoryResponse { entries: vec![] });
+    }
+
+    let count = PIXEL_PAINT_COUNT
+        .may_load(deps.storage, (&token_id, pixel_id))?
+        .unwrap_or_default();
+    let oldest = count.saturating_sub(config.pixel_history_depth as u64);
+    let newest = start_after.map_or(count, |seq| seq.min(count));
+
+    let entries = (oldest..newest)
+        .rev()
+        .take(page_limit(limit))
+        .map(|seq| {
+            let slot = PixelHistoryEntry::slot(seq, config.pixel_history_depth);
+            PIXEL_HISTORY.load(deps.storage, (&token_id, pixel_id, slot))
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(PixelHistoryResponse { entries })
+}
+
+pub fn query_tile_history(
+    deps: Deps,
+    token_id: String,
+    since: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TileHistoryResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    ensure_tile_exists(deps, &token_id)?;
+    if !config.history_enabled() {
+        return Ok(TileHistoryResponse { entries: vec![] });
+    }
+
+    let limit = page_limit(limit);
+    let min = since.map(|since| Bound::exclusive((since, (u32::MAX, u64::MAX))));
+    let mut keys = TILE_HISTORY_INDEX
+        .sub_prefix(&token_id)
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| item.map(|(painted_at, (pixel_id, seq))| (painted_at, pixel_id, seq)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // Pages end on a timestamp boundary so `since` can resume without gaps
+    if keys.len() > limit {
+        let boundary = keys[limit].0;
+        keys.truncate(limit);
+        if keys[0].0 == boundary {
+            // A single paint fills the page, return all of it
+            keys = TILE_HISTORY_INDEX
+                .prefix((&token_id, boundary))
+                .keys(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(pixel_id, seq)| (boundary, pixel_id, seq)))
+                .collect::<StdResult<_>>()?;
+        } else {
+            keys.retain(|(painted_at, _, _)| *painted_at != boundary);
+        }
+    }
+
+    let entries = keys
+        .into_iter()
+        .map(|(_, pixel_id, seq)| {
+            let slot = PixelHistoryEntry::slot(seq, config.pixel_history_depth);
+            PIXEL_HISTORY.load(deps.storage, (&token_id, pixel_id, slot))
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(TileHistoryResponse { entries })
+}
+
+fn ensure_tile_exists(deps: Deps, token_id: &str) -> StdResult<()> {
+    if !TILE_METADATA.has(deps.storage, token_id) {
+        return Err(StdError::not_found(format!("tile {}", token_id)));
+    }
+    Ok(())
+}
+
+fn page_limit(limit: Option<u32>) -> usize {
+    limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT) as usize
+}
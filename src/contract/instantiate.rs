@@ -5,8 +5,12 @@ use sg721_base::Sg721Contract;
 use sg_std::StargazeMsgWrapper;
 
 use crate::{
-    contract::{error::ContractError, msg::InstantiateMsg, state::PRICE_SCALING},
-    core::{pricing::PriceScaling, tile::Tile},
+    contract::{
+        error::ContractError,
+        msg::InstantiateMsg,
+        state::{CONFIG, PRICE_SCALING},
+    },
+    core::tile::Tile,
     defaults::constants::{CONTRACT_NAME, CONTRACT_VERSION},
     events::{EventData, InstantiatePriceScalingEventData},
 };
@@ -19,12 +23,31 @@ pub fn instantiate_handler(
 ) -> Result<Response<StargazeMsgWrapper>, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // Validate tiles config before touching the base contract
+    let config = msg.config();
+    config
+        .validate()
+        .map_err(|e| ContractError::InvalidConfig {
+            reason: e.to_string(),
+        })?;
+    for split in &config.revenue_splits {
+        deps.api.addr_validate(split.recipient.as_str())?;
+    }
+    for moderator in &config.moderators {
+        deps.api.addr_validate(moderator.as_str())?;
+    }
+    let price_scaling = msg.price_scaling();
+    price_scaling
+        .validate()
+        .map_err(|e| ContractError::InvalidConfig {
+            reason: e.to_string(),
+        })?;
+
     // Initialize base contract
     let contract = Sg721Contract::<Tile>::default();
-    contract.instantiate(deps.branch(), env.clone(), info.clone(), msg.clone())?;
+    contract.instantiate(deps.branch(), env.clone(), info.clone(), msg.clone().into())?;
 
-    // Save default price scaling
-    let price_scaling = PriceScaling::default();
+    CONFIG.save(deps.storage, &config)?;
     PRICE_SCALING.save(deps.storage, &price_scaling)?;
 
     // Create instantiate event with config
@@ -32,6 +55,7 @@ pub fn instantiate_handler(
         collection_info: serde_json::to_string(&msg.collection_info).unwrap_or_default(),
         minter: msg.minter,
         price_scaling: serde_json::to_string(&price_scaling).unwrap_or_default(),
+        config: serde_json::to_string(&config).unwrap_or_default(),
         time: env.block.time.to_string(),
     }
     .into_event();
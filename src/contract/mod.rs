@@ -1,8 +1,16 @@
+pub mod commit;
+#[allow(clippy::module_inception)]
 pub mod contract;
+pub mod delegation;
+pub mod earnings;
 pub mod error;
+pub mod escrow;
 pub mod execute;
+pub mod history;
 pub mod instantiate;
 pub mod msg;
+pub mod outbid;
+pub mod painter;
 pub mod query;
 pub mod state;
 pub mod tiles;
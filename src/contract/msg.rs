@@ -1,22 +1,124 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Empty;
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Empty, Uint128};
+use cw20::Cw20ReceiveMsg;
 use cw721::{
-    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, NftInfoResponse,
-    NumTokensResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, Expiration,
+    NftInfoResponse, NumTokensResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
 };
 use cw721_base::Extension;
-use sg721::InstantiateMsg as Sg721InstantiateMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sg721::{CollectionInfo, InstantiateMsg as Sg721InstantiateMsg, RoyaltyInfoResponse};
 use sg721_base::msg::CollectionInfoResponse;
 
 use crate::core::{
+    config::{Config, PayoutMode, UnrevealedCommits},
+    earnings::Earnings,
+    outbid::{OutbidConfig, Takeover},
+    painter::{PainterRanking, PainterStats},
     pricing::PriceScaling,
+    revenue::RevenueSplit,
     tile::{
-        metadata::{PixelUpdate, TileMetadata},
+        commit::PaintCommit,
+        grant::PainterGrant,
+        history::PixelHistoryEntry,
+        image::ImageFormat,
+        layer::BasePixel,
+        metadata::{PixelData, PixelUpdate, TileMetadata},
+        policy::TilePolicy,
         Tile,
     },
 };
 
-pub type InstantiateMsg = Sg721InstantiateMsg;
+// Superset of the sg721 message so the vending factory can still instantiate us;
+// every tiles specific field is optional and falls back to the defaults
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub name: String,
+    pub symbol: String,
+    pub minter: String,
+    pub collection_info: CollectionInfo<RoyaltyInfoResponse>,
+    pub price_scaling: Option<PriceScaling>,
+    pub denom: Option<String>,
+    pub pixel_min_expiration: Option<u64>,
+    pub pixel_max_expiration: Option<u64>,
+    pub tile_width: Option<u32>,
+    pub tile_height: Option<u32>,
+    pub default_color: Option<String>,
+    pub pixel_history_depth: Option<u32>,
+    pub payout_mode: Option<PayoutMode>,
+    pub revenue_splits: Option<Vec<RevenueSplit>>,
+    pub min_price_multiplier: Option<Decimal>,
+    pub max_price_multiplier: Option<Decimal>,
+    pub outbid: Option<OutbidConfig>,
+    pub reveal_window_blocks: Option<u64>,
+    pub unrevealed_commits: Option<UnrevealedCommits>,
+    pub keyframe_surcharge_bps: Option<u16>,
+    pub moderators: Option<Vec<Addr>>,
+    pub free_owner_painting: Option<bool>,
+}
+
+impl InstantiateMsg {
+    pub fn config(&self) -> Config {
+        let defaults = Config::default();
+        Config {
+            denom: self.denom.clone().unwrap_or(defaults.denom),
+            pixel_min_expiration: self
+                .pixel_min_expiration
+                .unwrap_or(defaults.pixel_min_expiration),
+            pixel_max_expiration: self
+                .pixel_max_expiration
+                .unwrap_or(defaults.pixel_max_expiration),
+            tile_width: self.tile_width.unwrap_or(defaults.tile_width),
+            tile_height: self.tile_height.unwrap_or(defaults.tile_height),
+            default_color: self.default_color.clone().unwrap_or(defaults.default_color),
+            pixel_history_depth: self
+                .pixel_history_depth
+                .unwrap_or(defaults.pixel_history_depth),
+            payout_mode: self.payout_mode.clone().unwrap_or(defaults.payout_mode),
+            revenue_splits: self
+                .revenue_splits
+                .clone()
+                .unwrap_or(defaults.revenue_splits),
+            min_price_multiplier: self
+                .min_price_multiplier
+                .unwrap_or(defaults.min_price_multiplier),
+            max_price_multiplier: self
+                .max_price_multiplier
+                .unwrap_or(defaults.max_price_multiplier),
+            outbid: self.outbid.clone().or(defaults.outbid),
+            reveal_window_blocks: self
+                .reveal_window_blocks
+                .unwrap_or(defaults.reveal_window_blocks),
+            unrevealed_commits: self
+                .unrevealed_commits
+                .clone()
+                .unwrap_or(defaults.unrevealed_commits),
+            keyframe_surcharge_bps: self
+                .keyframe_surcharge_bps
+                .unwrap_or(defaults.keyframe_surcharge_bps),
+            moderators: self.moderators.clone().unwrap_or(defaults.moderators),
+            free_owner_painting: self
+                .free_owner_painting
+                .unwrap_or(defaults.free_owner_painting),
+        }
+    }
+
+    pub fn price_scaling(&self) -> PriceScaling {
+        self.price_scaling.clone().unwrap_or_default()
+    }
+}
+
+impl From<InstantiateMsg> for Sg721InstantiateMsg {
+    fn from(msg: InstantiateMsg) -> Self {
+        Self {
+            name: msg.name,
+            symbol: msg.symbol,
+            minter: msg.minter,
+            collection_info: msg.collection_info,
+        }
+    }
+}
 
 #[cw_serde]
 pub enum TileExecuteMsg {
@@ -25,12 +127,119 @@ pub enum TileExecuteMsg {
         current_metadata: TileMetadata,
         updates: Vec<PixelUpdate>,
     },
+    /// Paints a bitmap of the whole tile for `duration` seconds. Pixels already
+    /// showing their color are skipped, the funds have to cover the rest.
+    PaintImage {
+        token_id: String,
+        format: ImageFormat,
+        data: Binary,
+        duration: u64,
+    },
     UpdatePriceScaling(PriceScaling),
+    /// Replaces the tiles config, only the royalty payment address may send it.
+    /// The tile dimensions cannot change once a tile has been minted.
+    UpdateConfig(Config),
+    /// Claims escrowed payouts, the whole pending balance when `amount` is omitted
+    Withdraw {
+        denom: String,
+        amount: Option<Uint128>,
+    },
+    /// Whitelists a CW20 token for painting with its own prices, `None` removes it.
+    /// Only the royalty payment address may send it.
+    SetCw20PriceScaling {
+        token: String,
+        price_scaling: Option<PriceScaling>,
+    },
+    /// Replaces the painting policy of a tile, only its owner may send it
+    SetTilePolicy {
+        token_id: String,
+        policy: TilePolicy,
+    },
+    /// Lets `painter` paint the tile as its owner would, until `expires` and for
+    /// at most `max_pixels` pixels. Replaces any earlier grant to the same painter.
+    /// With `free_owner_painting` the grantee paints for free as well. Tiles
+    /// anyone may paint do not check grants otherwise, so `max_pixels` has no
+    /// effect there.
+    GrantPainter {
+        token_id: String,
+        painter: String,
+        expires: Option<Expiration>,
+        max_pixels: Option<u32>,
+    },
+    RevokePainter {
+        token_id: String,
+        painter: String,
+    },
+    /// Writes the owner's base layer, shown under expired rental pixels. Only
+    /// the owner may send it and it costs nothing.
+    SetBaseLayer {
+        token_id: String,
+        pixels: Vec<BasePixel>,
+    },
+    /// Removes the annotations of the given pixels, only configured moderators
+    /// may send it
+    ClearAnnotations {
+        token_id: String,
+        pixel_ids: Vec<u32>,
+    },
+    /// Escrows the attached funds for a paint hidden behind `commitment`, the hex
+    /// SHA-256 of `paint_commitment`. Keeps the design out of the mempool until
+    /// `RevealPaint` applies it. `pixel_ids` are reserved for the committer until
+    /// the reveal window closes.
+    CommitPaint {
+        token_id: String,
+        pixel_ids: Vec<u32>,
+        commitment: String,
+    },
+    /// Paints the committed updates against the current tile, paid from the
+    /// escrowed funds. Anything left over is refunded. Only reserved pixels may
+    /// be painted.
+    RevealPaint {
+        token_id: String,
+        updates: Vec<PixelUpdate>,
+        salt: String,
+    },
+    /// Settles a commit whose reveal window has passed, anyone may send it
+    ExpireCommit {
+        token_id: String,
+        committer: String,
+    },
 }
 
 // For incoming messages (from vending minter), use Extension (Option<Empty>)
 pub type ExecuteMsg = sg721::ExecuteMsg<Extension, TileExecuteMsg>;
 
+/// Hook sent by CW20 token contracts, serialized as `{"receive": ...}`
+#[cw_serde]
+pub enum ReceiveMsg {
+    Receive(Cw20ReceiveMsg),
+}
+
+/// Everything the contract accepts: the CW20 receive hook has to sit at the top
+/// level, next to the sg721 messages, so it cannot be a tiles extension
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(untagged)]
+pub enum ContractExecuteMsg {
+    Receive(ReceiveMsg),
+    Sg721(ExecuteMsg),
+}
+
+impl From<ExecuteMsg> for ContractExecuteMsg {
+    fn from(msg: ExecuteMsg) -> Self {
+        ContractExecuteMsg::Sg721(msg)
+    }
+}
+
+/// Message embedded in `Cw20ReceiveMsg::msg`, the sent tokens pay for it
+#[cw_serde]
+pub enum Cw20HookMsg {
+    SetPixelColor {
+        token_id: String,
+        current_metadata: TileMetadata,
+        updates: Vec<PixelUpdate>,
+    },
+}
+
 // For outgoing messages (to sg721), use Tile
 pub type Sg721ExecuteMsg = sg721::ExecuteMsg<Tile, Empty>;
 
@@ -64,9 +273,9 @@ pub enum QueryMsg {
     NumTokens {},
     #[returns(ContractInfoResponse)]
     ContractInfo {},
-    #[returns(NftInfoResponse<Extension>)]
+    #[returns(NftInfoResponse<Tile>)]
     NftInfo { token_id: String },
-    #[returns(AllNftInfoResponse<Extension>)]
+    #[returns(AllNftInfoResponse<Tile>)]
     AllNftInfo {
         token_id: String,
         include_expired: Option<bool>,
@@ -88,4 +297,162 @@ pub enum QueryMsg {
     CollectionInfo {},
     #[returns(PriceScaling)]
     PriceScaling {},
+    #[returns(Config)]
+    Config {},
+    #[returns(TileSvgResponse)]
+    TileSvg {
+        token_id: String,
+        scale: Option<u32>,
+        grid_lines: Option<bool>,
+    },
+    /// Most recent paints of one pixel, newest first, paging backwards by `seq`
+    #[returns(PixelHistoryResponse)]
+    PixelHistory {
+        token_id: String,
+        pixel_id: u32,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Paints of any pixel of the tile after `since` (unix seconds), oldest first.
+    /// Pass the last `last_updated_at` seen as `since` to fetch the next page.
+    #[returns(TileHistoryResponse)]
+    TileHistory {
+        token_id: String,
+        since: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lifetime counters of one painter, zeroed for addresses that never painted
+    #[returns(PainterStatsResponse)]
+    PainterStats { address: String },
+    /// Painters ranked by one counter, highest first. Pass the last address
+    /// returned as `start_after` for the next page.
+    #[returns(TopPaintersResponse)]
+    TopPainters {
+        by: PainterRanking,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Paint revenue the tile has generated, one entry per denom paid
+    #[returns(TileEarningsResponse)]
+    TileEarnings { token_id: String },
+    /// Paint revenue received by an owner across all tiles, one entry per denom
+    #[returns(OwnerEarningsResponse)]
+    OwnerEarnings { owner: String },
+    /// Escrowed payouts the address can withdraw
+    #[returns(PendingBalanceResponse)]
+    PendingBalance { address: String },
+    /// CW20 tokens accepted for painting and their prices
+    #[returns(Cw20TokensResponse)]
+    Cw20Tokens {},
+    /// Unexpired painting grants on a tile
+    #[returns(PaintersResponse)]
+    Painters { token_id: String },
+    /// What taking over a live pixel costs right now, on top of the regular paint price.
+    /// Fails unless the collection enables outbids.
+    #[returns(TakeoverPriceResponse)]
+    TakeoverPrice { token_id: String, pixel_id: u32 },
+    #[returns(PaintCommitResponse)]
+    PaintCommit { token_id: String, committer: String },
+    /// Stored state of one pixel, annotation included
+    #[returns(PixelResponse)]
+    Pixel { token_id: String, pixel_id: u32 },
+}
+
+#[cw_serde]
+pub struct TileSvgResponse {
+    pub svg: String,
+}
+
+#[cw_serde]
+pub struct PixelResponse {
+    pub token_id: String,
+    pub pixel: PixelData,
+    /// Whether the paint has not expired yet
+    pub live: bool,
+    /// Color the tile shows for the pixel, as its SVG renders it
+    pub color: String,
+}
+
+#[cw_serde]
+pub struct PixelHistoryResponse {
+    pub entries: Vec<PixelHistoryEntry>,
+}
+
+#[cw_serde]
+pub struct TileHistoryResponse {
+    pub entries: Vec<PixelHistoryEntry>,
+}
+
+#[cw_serde]
+pub struct PainterStatsResponse {
+    pub address: Addr,
+    pub stats: PainterStats,
+    /// Pixels last painted by this address that have not expired yet
+    pub live_pixels: u32,
+}
+
+#[cw_serde]
+pub struct TopPaintersResponse {
+    pub painters: Vec<PainterStatsResponse>,
+}
+
+#[cw_serde]
+pub struct TileEarningsResponse {
+    pub token_id: String,
+    pub earnings: Vec<Earnings>,
+}
+
+#[cw_serde]
+pub struct OwnerEarningsResponse {
+    pub owner: Addr,
+    pub earnings: Vec<Earnings>,
+}
+
+#[cw_serde]
+pub struct PendingBalanceResponse {
+    pub address: Addr,
+    pub balances: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct Cw20TokenInfo {
+    pub token: Addr,
+    pub price_scaling: PriceScaling,
+}
+
+#[cw_serde]
+pub struct Cw20TokensResponse {
+    pub tokens: Vec<Cw20TokenInfo>,
+}
+
+#[cw_serde]
+pub struct PainterGrantInfo {
+    pub painter: Addr,
+    pub grant: PainterGrant,
+}
+
+#[cw_serde]
+pub struct PaintersResponse {
+    pub token_id: String,
+    pub painters: Vec<PainterGrantInfo>,
+}
+
+#[cw_serde]
+pub struct TakeoverPriceResponse {
+    pub token_id: String,
+    pub pixel_id: u32,
+    /// Current painter of a live pixel, `None` when it can be painted at the regular price
+    pub painter: Option<Addr>,
+    /// Denom or CW20 token the takeover has to be paid in
+    pub denom: Option<String>,
+    pub takeover: Takeover,
+}
+
+#[cw_serde]
+pub struct PaintCommitResponse {
+    pub token_id: String,
+    pub committer: Addr,
+    pub commit: Option<PaintCommit>,
+    /// Last block the commit can be revealed in
+    pub reveal_until: Option<u64>,
 }
@@ -0,0 +1,132 @@
+use cosmwasm_std::{Addr, Deps, Env, Order, StdError, StdResult, Storage, Uint128};
+
+use crate::{
+    contract::{
+        error::ContractError,
+        msg::TakeoverPriceResponse,
+        state::{CONFIG, PIXEL_PAYMENTS, TILE_METADATA},
+    },
+    core::{
+        outbid::{OutbidConfig, PixelPayment, Takeover},
+        tile::metadata::PixelData,
+    },
+};
+
+/// A live pixel taken over by a paint
+pub struct PixelTakeover {
+    pub pixel_id: u32,
+    pub displaced: Addr,
+    pub takeover: Takeover,
+}
+
+/// Prices the takeover of `pixel` if it is still live, refunds are paid in the
+/// denom the displaced painter used so the challenger has to pay in it too
+pub fn pixel_takeover(
+    storage: &dyn Storage,
+    outbid: &OutbidConfig,
+    token_id: &str,
+    pixel: &PixelData,
+    denom: &str,
+    now: u64,
+) -> Result<Option<PixelTakeover>, ContractError> {
+    if pixel.expiration_timestamp <= now {
+        return Ok(None);
+    }
+    let paid = match PIXEL_PAYMENTS.may_load(storage, (token_id, pixel.id))? {
+        Some(paid) => paid,
+        None => return Ok(None),
+    };
+    if paid.denom != denom {
+        return Err(ContractError::OutbidDenomMismatch {
+            pixel_id: pixel.id,
+            denom: paid.denom,
+        });
+    }
+    Ok(Some(PixelTakeover {
+        pixel_id: pixel.id,
+        displaced: pixel.last_updated_by.clone(),
+        takeover: outbid.takeover(
+            paid.amount,
+            pixel.last_updated_at,
+            pixel.expiration_timestamp,
+            now,
+        ),
+    }))
+}
+
+/// Free paints have nothing to buy out, so they clear the pixel's payment
+pub fn record_pixel_payment(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    pixel_id: u32,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        PIXEL_PAYMENTS.remove(storage, (token_id, pixel_id));
+        return Ok(());
+    }
+    PIXEL_PAYMENTS.save(
+        storage,
+        (token_id, pixel_id),
+        &PixelPayment {
+            denom: denom.to_string(),
+            amount,
+        },
+    )
+}
+
+pub fn clear_pixel_payments(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    let pixel_ids: Vec<u32> = PIXEL_PAYMENTS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for pixel_id in pixel_ids {
+        PIXEL_PAYMENTS.remove(storage, (token_id, pixel_id));
+    }
+    Ok(())
+}
+
+pub fn query_takeover_price(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    pixel_id: u32,
+) -> StdResult<TakeoverPriceResponse> {
+    let outbid = CONFIG
+        .load(deps.storage)?
+        .outbid
+        .ok_or_else(|| StdError::generic_err("Outbids are not enabled"))?;
+    let metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+    let pixel = metadata
+        .pixels
+        .get(pixel_id as usize)
+        .ok_or_else(|| StdError::generic_err(format!("Invalid pixel ID: {}", pixel_id)))?;
+    let now = env.block.time.seconds();
+
+    let live_payment = match PIXEL_PAYMENTS.may_load(deps.storage, (&token_id, pixel_id))? {
+        Some(paid) if pixel.expiration_timestamp > now => Some(paid),
+        _ => None,
+    };
+    Ok(match live_payment {
+        Some(paid) => TakeoverPriceResponse {
+            token_id,
+            pixel_id,
+            painter: Some(pixel.last_updated_by.clone()),
+            takeover: outbid.takeover(
+                paid.amount,
+                pixel.last_updated_at,
+                pixel.expiration_timestamp,
+                now,
+            ),
+            denom: Some(paid.denom),
+        },
+        None => TakeoverPriceResponse {
+            token_id,
+            pixel_id,
+            painter: None,
+            denom: None,
+            takeover: Takeover::default(),
+        },
+    })
+}
@@ -0,0 +1,171 @@
+use cosmwasm_std::{Addr, Deps, Empty, Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Bound;
+
+use crate::{
+    contract::{
+        msg::{PainterStatsResponse, TopPaintersResponse},
+        state::{painter_stats, PAINTER_LIVE_PIXELS, PAINTER_TILES},
+    },
+    core::{painter::PainterRanking, tile::metadata::PixelData},
+    defaults::constants::{DEFAULT_LEADERBOARD_LIMIT, MAX_LEADERBOARD_LIMIT},
+};
+
+/// Updates the painter's counters for one paint and moves live pixels over
+/// from whoever held them before. `previous` is the tile before the paint.
+pub fn record_painter_stats(
+    storage: &mut dyn Storage,
+    painter: &Addr,
+    token_id: &str,
+    previous: &[PixelData],
+    new_pixels: &[PixelData],
+    amount: Uint128,
+    current_time: u64,
+) -> StdResult<()> {
+    prune_expired(storage, painter, current_time)?;
+
+    for pixel in new_pixels {
+        let old = &previous[pixel.id as usize];
+        if old.expiration_timestamp > current_time {
+            let key = (&old.last_updated_by, old.expiration_timestamp, token_id);
+            let held = PAINTER_LIVE_PIXELS
+                .may_load(storage, key)?
+                .unwrap_or_default();
+            if held > 1 {
+                PAINTER_LIVE_PIXELS.save(storage, key, &(held - 1))?;
+            } else {
+                PAINTER_LIVE_PIXELS.remove(storage, key);
+            }
+        }
+        PAINTER_LIVE_PIXELS.update(
+            storage,
+            (painter, pixel.expiration_timestamp, token_id),
+            |held| -> StdResult<_> { Ok(held.unwrap_or_default() + 1) },
+        )?;
+    }
+
+    let first_paint_on_tile = !PAINTER_TILES.has(storage, (painter, token_id));
+    if first_paint_on_tile {
+        PAINTER_TILES.save(storage, (painter, token_id), &Empty {})?;
+    }
+
+    let stats = painter_stats();
+    let old_stats = stats.may_load(storage, painter)?;
+    let mut new_stats = old_stats.clone().unwrap_or_default();
+    new_stats.pixels_painted += new_pixels.len() as u64;
+    new_stats.total_spent += amount;
+    if first_paint_on_tile {
+        new_stats.tiles_touched += 1;
+    }
+    stats.replace(storage, painter, Some(&new_stats), old_stats.as_ref())
+}
+
+// Drops the painter's own expired entries, each is removed once so the cost
+// is spread over their paints
+fn prune_expired(storage: &mut dyn Storage, painter: &Addr, current_time: u64) -> StdResult<()> {
+    let max = Bound::exclusive((current_time + 1, ""));
+    let expired = PAINTER_LIVE_PIXELS
+        .sub_prefix(painter)
+        .keys(storage, None, Some(max), Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (expiration, token_id) in expired {
+        PAINTER_LIVE_PIXELS.remove(storage, (painter, expiration, &token_id));
+    }
+    Ok(())
+}
+
+fn live_pixels(deps: Deps, painter: &Addr, current_time: u64) -> StdResult<u32> {
+    let min = Bound::inclusive((current_time + 1, ""));
+    PAINTER_LIVE_PIXELS
+        .sub_prefix(painter)
+        .range(deps.storage, Some(min), None, Order::Ascending)
+        .try_fold(0u32, |live, item| -> StdResult<u32> { Ok(live + item?.1) })
+}
+
+fn painter_stats_response(
+    deps: Deps,
+    address: Addr,
+    current_time: u64,
+) -> StdResult<PainterStatsResponse> {
+    let stats = painter_stats()
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    Ok(PainterStatsResponse {
+        live_pixels: live_pixels(deps, &address, current_time)?,
+        address,
+        stats,
+    })
+}
+
+pub fn query_painter_stats(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<PainterStatsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    painter_stats_response(deps, address, env.block.time.seconds())
+}
+
+pub fn query_top_painters(
+    deps: Deps,
+    env: Env,
+    by: PainterRanking,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TopPaintersResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_LEADERBOARD_LIMIT)
+        .min(MAX_LEADERBOARD_LIMIT) as usize;
+    let stats = painter_stats();
+    let start = match start_after {
+        Some(address) => {
+            let address = deps.api.addr_validate(&address)?;
+            let start_stats = stats.load(deps.storage, &address)?;
+            Some((address, start_stats))
+        }
+        None => None,
+    };
+
+    // Highest first, ties broken by address so pages never overlap
+    let addresses = match by {
+        PainterRanking::PixelsPainted => stats
+            .idx
+            .pixels_painted
+            .keys(
+                deps.storage,
+                None,
+                start.map(|(address, s)| Bound::exclusive((s.pixels_painted, address))),
+                Order::Descending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?,
+        PainterRanking::TotalSpent => stats
+            .idx
+            .total_spent
+            .keys(
+                deps.storage,
+                None,
+                start.map(|(address, s)| Bound::exclusive((s.total_spent.u128(), address))),
+                Order::Descending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?,
+        PainterRanking::TilesTouched => stats
+            .idx
+            .tiles_touched
+            .keys(
+                deps.storage,
+                None,
+                start.map(|(address, s)| Bound::exclusive((s.tiles_touched, address))),
+                Order::Descending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?,
+    };
+
+    let current_time = env.block.time.seconds();
+    let painters = addresses
+        .into_iter()
+        .map(|address| painter_stats_response(deps, address, current_time))
+        .collect::<StdResult<_>>()?;
+    Ok(TopPaintersResponse { painters })
+}
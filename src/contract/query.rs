@@ -1,14 +1,78 @@
 use crate::core::tile::Tile;
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdResult};
+use cosmwasm_std::{from_json, to_json_binary, Binary, Deps, Env, Order, StdError, StdResult};
+use cw721::{AllNftInfoResponse, NftInfoResponse};
 use cw721_base::Extension;
 use serde::{Deserialize, Serialize};
 use sg721_base::{msg::QueryMsg as Sg721QueryMsg, Sg721Contract};
 
-use crate::contract::{msg::QueryMsg, state::PRICE_SCALING};
+use crate::{
+    contract::{
+        commit::query_paint_commit,
+        delegation::query_painters,
+        earnings::{query_owner_earnings, query_tile_earnings},
+        escrow::query_pending_balance,
+        history::{query_pixel_history, query_tile_history},
+        msg::{Cw20TokenInfo, Cw20TokensResponse, PixelResponse, QueryMsg, TileSvgResponse},
+        outbid::query_takeover_price,
+        painter::{query_painter_stats, query_top_painters},
+        state::{CONFIG, CW20_PRICE_SCALING, PRICE_SCALING, TILE_METADATA},
+    },
+    defaults::constants::{DEFAULT_SVG_SCALE, MAX_SVG_SCALE},
+};
 
 pub fn query_handler(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::PriceScaling {} => to_json_binary(&PRICE_SCALING.load(deps.storage)?),
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::TileSvg {
+            token_id,
+            scale,
+            grid_lines,
+        } => to_json_binary(&query_tile_svg(deps, env, token_id, scale, grid_lines)?),
+        QueryMsg::Pixel { token_id, pixel_id } => {
+            to_json_binary(&query_pixel(deps, env, token_id, pixel_id)?)
+        }
+        QueryMsg::PixelHistory {
+            token_id,
+            pixel_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_pixel_history(
+            deps,
+            token_id,
+            pixel_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::TileHistory {
+            token_id,
+            since,
+            limit,
+        } => to_json_binary(&query_tile_history(deps, token_id, since, limit)?),
+        QueryMsg::PainterStats { address } => {
+            to_json_binary(&query_painter_stats(deps, env, address)?)
+        }
+        QueryMsg::TopPainters {
+            by,
+            start_after,
+            limit,
+        } => to_json_binary(&query_top_painters(deps, env, by, start_after, limit)?),
+        QueryMsg::TileEarnings { token_id } => {
+            to_json_binary(&query_tile_earnings(deps, token_id)?)
+        }
+        QueryMsg::OwnerEarnings { owner } => to_json_binary(&query_owner_earnings(deps, owner)?),
+        QueryMsg::Cw20Tokens {} => to_json_binary(&query_cw20_tokens(deps)?),
+        QueryMsg::Painters { token_id } => to_json_binary(&query_painters(deps, env, token_id)?),
+        QueryMsg::PaintCommit {
+            token_id,
+            committer,
+        } => to_json_binary(&query_paint_commit(deps, token_id, committer)?),
+        QueryMsg::TakeoverPrice { token_id, pixel_id } => {
+            to_json_binary(&query_takeover_price(deps, env, token_id, pixel_id)?)
+        }
+        QueryMsg::PendingBalance { address } => {
+            to_json_binary(&query_pending_balance(deps, address)?)
+        }
         QueryMsg::OwnerOf {
             token_id,
             include_expired,
@@ -64,18 +128,31 @@ pub fn query_handler(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query_base::<Extension>(deps, env, base_msg)
         }
         QueryMsg::NftInfo { token_id } => {
-            let base_msg = Sg721QueryMsg::NftInfo { token_id };
-            query_base::<Tile>(deps, env, base_msg)
+            let base_msg = Sg721QueryMsg::NftInfo {
+                token_id: token_id.clone(),
+            };
+            let mut response: NftInfoResponse<Tile> =
+                from_json(query_base::<Tile>(deps, env.clone(), base_msg)?)?;
+            response.token_uri = Some(token_data_uri(deps, &env, &token_id, response.token_uri)?);
+            to_json_binary(&response)
         }
         QueryMsg::AllNftInfo {
             token_id,
             include_expired,
         } => {
             let base_msg = Sg721QueryMsg::AllNftInfo {
-                token_id,
+                token_id: token_id.clone(),
                 include_expired,
             };
-            query_base::<Tile>(deps, env, base_msg)
+            let mut response: AllNftInfoResponse<Tile> =
+                from_json(query_base::<Tile>(deps, env.clone(), base_msg)?)?;
+            response.info.token_uri = Some(token_data_uri(
+                deps,
+                &env,
+                &token_id,
+                response.info.token_uri,
+            )?);
+            to_json_binary(&response)
         }
         QueryMsg::Tokens {
             owner,
@@ -104,6 +181,110 @@ pub fn query_handler(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     }
 }
 
+// Replaces the static token URI with metadata generated from on-chain state,
+// keeping the original URI inside the metadata as a fallback
+fn token_data_uri(
+    deps: Deps,
+    env: &Env,
+    token_id: &str,
+    original_token_uri: Option<String>,
+) -> StdResult<String> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let contract_info = contract.contract_info.load(deps.storage)?;
+    let collection_info = contract.collection_info.load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let metadata = TILE_METADATA.load(deps.storage, token_id)?;
+    let tile = contract.tokens.load(deps.storage, token_id)?.extension;
+
+    Ok(metadata
+        .token_metadata(
+            &config,
+            format!("{} #{}", contract_info.name, token_id),
+            collection_info.description,
+            original_token_uri,
+            tile.render_time(env.block.time.seconds()),
+        )
+        .to_data_uri())
+}
+
+fn query_cw20_tokens(deps: Deps) -> StdResult<Cw20TokensResponse> {
+    let tokens = CW20_PRICE_SCALING
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(token, price_scaling)| Cw20TokenInfo {
+                token,
+                price_scaling,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(Cw20TokensResponse { tokens })
+}
+
+fn query_tile_svg(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    scale: Option<u32>,
+    grid_lines: Option<bool>,
+) -> StdResult<TileSvgResponse> {
+    let scale = scale.unwrap_or(DEFAULT_SVG_SCALE);
+    if scale == 0 || scale > MAX_SVG_SCALE {
+        return Err(StdError::generic_err(format!(
+            "Invalid SVG scale: {} (must be between 1 and {})",
+            scale, MAX_SVG_SCALE
+        )));
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+    let tile = Sg721Contract::<Tile>::default()
+        .tokens
+        .load(deps.storage, &token_id)?
+        .extension;
+    Ok(TileSvgResponse {
+        svg: metadata.to_svg(
+            &config,
+            tile.render_time(env.block.time.seconds()),
+            scale,
+            grid_lines.unwrap_or(false),
+        ),
+    })
+}
+
+fn query_pixel(deps: Deps, env: Env, token_id: String, pixel_id: u32) -> StdResult<PixelResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+    let tile = Sg721Contract::<Tile>::default()
+        .tokens
+        .load(deps.storage, &token_id)?
+        .extension;
+    let now = env.block.time.seconds();
+    let pixel = metadata
+        .pixels
+        .get(pixel_id as usize)
+        .cloned()
+        .ok_or_else(|| {
+            StdError::generic_err(format!(
+                "Invalid pixel ID: {} (tile has {} pixels)",
+                pixel_id,
+                metadata.pixels.len()
+            ))
+        })?;
+    let color = metadata
+        .pixel_color(
+            pixel_id as usize,
+            &config.default_color,
+            tile.render_time(now),
+        )
+        .to_string();
+    Ok(PixelResponse {
+        token_id,
+        live: pixel.expiration_timestamp > now,
+        pixel,
+        color,
+    })
+}
+
 fn query_base<T>(deps: Deps, env: Env, msg: Sg721QueryMsg) -> StdResult<Binary>
 where
     T: Serialize + for<'de> Deserialize<'de> + Clone,
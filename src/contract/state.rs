@@ -1,4 +1,87 @@
-use crate::core::pricing::PriceScaling;
-use cw_storage_plus::Item;
+use crate::core::{
+    config::Config,
+    earnings::Earnings,
+    outbid::PixelPayment,
+    painter::PainterStats,
+    pricing::PriceScaling,
+    tile::{
+        commit::{PaintCommit, PixelReservation},
+        grant::PainterGrant,
+        history::PixelHistoryEntry,
+        metadata::TileMetadata,
+    },
+};
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
+pub const CONFIG: Item<Config> = Item::new("config");
 pub const PRICE_SCALING: Item<PriceScaling> = Item::new("price_scaling");
+/// Whitelisted CW20 tokens and the prices painting with them costs
+pub const CW20_PRICE_SCALING: Map<&Addr, PriceScaling> = Map::new("cw20_price_scaling");
+/// Full pixel state per token. Transfers, burns and queries need the live
+/// pixels without a caller supplying them, at roughly 30 gas per stored byte
+pub const TILE_METADATA: Map<&str, TileMetadata> = Map::new("tile_metadata");
+/// What the current paint of each (token, pixel) cost, only kept while outbids are enabled
+pub const PIXEL_PAYMENTS: Map<(&str, u32), PixelPayment> = Map::new("pixel_payments");
+/// Hidden paints waiting to be revealed per (token, committer)
+pub const PAINT_COMMITS: Map<(&str, &Addr), PaintCommit> = Map::new("paint_commits");
+/// Pixels held by paint commits per (token, pixel), stale once the window closes
+pub const PIXEL_RESERVATIONS: Map<(&str, u32), PixelReservation> = Map::new("pixel_reservations");
+/// Painting rights per (token, painter), cleared when the tile changes hands
+pub const PAINTER_GRANTS: Map<(&str, &Addr), PainterGrant> = Map::new("painter_grants");
+/// Paint revenue per (token, denom), kept after transfers and burns
+pub const TILE_EARNINGS: Map<(&str, &str), Earnings> = Map::new("tile_earnings");
+/// Paint revenue per (owner at paint time, denom)
+pub const OWNER_EARNINGS: Map<(&Addr, &str), Earnings> = Map::new("owner_earnings");
+/// Escrowed payouts per (recipient, denom) awaiting withdrawal
+pub const PENDING_BALANCES: Map<(&Addr, &str), Uint128> = Map::new("pending_balances");
+
+/// Ring buffer of past paints keyed by (token, pixel, slot), only written when
+/// the config enables history
+pub const PIXEL_HISTORY: Map<(&str, u32, u32), PixelHistoryEntry> = Map::new("pixel_history");
+/// Number of paints recorded per (token, pixel), the next entry's `seq`
+pub const PIXEL_PAINT_COUNT: Map<(&str, u32), u64> = Map::new("pixel_paint_count");
+/// Time ordered index over the ring buffers, keyed by (token, painted_at, (pixel, seq))
+/// so repeated paints of a pixel within one block each keep an entry
+pub const TILE_HISTORY_INDEX: Map<(&str, u64, (u32, u64)), Empty> = Map::new("tile_history_index");
+
+pub struct PainterStatsIndexes<'a> {
+    pub pixels_painted: MultiIndex<'a, u64, PainterStats, Addr>,
+    pub total_spent: MultiIndex<'a, u128, PainterStats, Addr>,
+    pub tiles_touched: MultiIndex<'a, u32, PainterStats, Addr>,
+}
+
+impl<'a> IndexList<PainterStats> for PainterStatsIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PainterStats>> + '_> {
+        let v: Vec<&dyn Index<PainterStats>> =
+            vec![&self.pixels_painted, &self.total_spent, &self.tiles_touched];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Per painter counters, indexed by each counter for the leaderboards
+pub fn painter_stats<'a>() -> IndexedMap<'a, &'a Addr, PainterStats, PainterStatsIndexes<'a>> {
+    let indexes = PainterStatsIndexes {
+        pixels_painted: MultiIndex::new(
+            |_, stats| stats.pixels_painted,
+            "painter_stats",
+            "painter_stats__pixels_painted",
+        ),
+        total_spent: MultiIndex::new(
+            |_, stats| stats.total_spent.u128(),
+            "painter_stats",
+            "painter_stats__total_spent",
+        ),
+        tiles_touched: MultiIndex::new(
+            |_, stats| stats.tiles_touched,
+            "painter_stats",
+            "painter_stats__tiles_touched",
+        ),
+    };
+    IndexedMap::new("painter_stats", indexes)
+}
+
+/// Tiles each painter has painted at least once
+pub const PAINTER_TILES: Map<(&Addr, &str), Empty> = Map::new("painter_tiles");
+/// Pixels a painter currently holds, counted per (painter, expiration, token)
+pub const PAINTER_LIVE_PIXELS: Map<(&Addr, u64, &str), u32> = Map::new("painter_live_pixels");
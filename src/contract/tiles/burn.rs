@@ -0,0 +1,77 @@
+use cosmwasm_std::{BankMsg, DepsMut, Env, MessageInfo, Response};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{
+        commit::clear_paint_commits, delegation::clear_painter_grants, error::ContractError,
+        history::clear_pixel_history, msg::Sg721ExecuteMsg, outbid::clear_pixel_payments,
+        state::TILE_METADATA,
+    },
+    core::tile::Tile,
+    events::{EventData, PaintCommitExpireEventData, TileBurnEventData},
+};
+
+pub fn burn_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let token = contract.tokens.load(deps.storage, &token_id)?;
+    let metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+
+    // Painters have prepaid for their pixels, so the tile must outlive them
+    let live_pixels = metadata.live_pixels(env.block.time.seconds());
+    if live_pixels > 0 {
+        return Err(ContractError::TileHasLivePixels {
+            token_id,
+            live_pixels,
+        });
+    }
+
+    let burn_event = TileBurnEventData {
+        token_id: token_id.clone(),
+        owner: token.owner,
+        tile_hash: token.extension.tile_hash,
+        live_pixels,
+    }
+    .into_event();
+
+    TILE_METADATA.remove(deps.storage, &token_id);
+    clear_painter_grants(deps.storage, &token_id)?;
+    clear_pixel_payments(deps.storage, &token_id)?;
+    clear_pixel_history(deps.storage, &token_id)?;
+
+    // Open commits can no longer be revealed, their escrow goes back to the
+    // committers and the reserved pixels are freed for a re-minted tile
+    let mut refunds = vec![];
+    let mut refund_events = vec![];
+    for (committer, commit) in clear_paint_commits(deps.storage, &token_id)? {
+        refund_events.push(
+            PaintCommitExpireEventData {
+                token_id: token_id.clone(),
+                committer: committer.clone(),
+                recipient: committer.clone(),
+                denom: commit.funds.denom.clone(),
+                amount: commit.funds.amount.u128(),
+                forfeited: false,
+            }
+            .into_event(),
+        );
+        refunds.push(BankMsg::Send {
+            to_address: committer.to_string(),
+            amount: vec![commit.funds],
+        });
+    }
+
+    // Forward to base contract, which handles approvals and ownership checks
+    let base_msg = Sg721ExecuteMsg::Burn { token_id };
+    let response = contract.execute(deps, env, info, base_msg)?;
+
+    Ok(response
+        .add_event(burn_event)
+        .add_messages(refunds)
+        .add_events(refund_events))
+}
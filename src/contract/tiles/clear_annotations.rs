@@ -0,0 +1,72 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+use std::collections::HashSet;
+
+use crate::{
+    contract::{
+        error::ContractError,
+        history::clear_history_annotations,
+        state::{CONFIG, TILE_METADATA},
+    },
+    core::tile::Tile,
+    events::{AnnotationClearEventData, EventData, MetadataUpdateEventData},
+};
+
+pub fn clear_annotations(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    pixel_ids: Vec<u32>,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if !config.is_moderator(&info.sender) {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender.to_string(),
+        });
+    }
+
+    let mut seen_ids = HashSet::new();
+    for id in &pixel_ids {
+        if !seen_ids.insert(*id) {
+            return Err(ContractError::DuplicatePixelId { id: *id });
+        }
+        if *id >= config.pixels_per_tile() {
+            return Err(ContractError::InvalidPixelId { id: *id });
+        }
+    }
+
+    // Moderation applies to frozen tiles too
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let mut token = contract.tokens.load(deps.storage, &token_id)?;
+    let mut metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+    metadata.clear_annotations(&pixel_ids);
+    let tile_hash = metadata.hash();
+    TILE_METADATA.save(deps.storage, &token_id, &metadata)?;
+    token.extension.tile_hash = tile_hash.clone();
+    contract.tokens.save(deps.storage, &token_id, &token)?;
+
+    if config.history_enabled() {
+        for id in &pixel_ids {
+            clear_history_annotations(deps.storage, &token_id, *id)?;
+        }
+    }
+
+    let clear_event = AnnotationClearEventData {
+        token_id: token_id.clone(),
+        moderator: info.sender,
+        pixel_ids,
+        tile_hash: tile_hash.clone(),
+    }
+    .into_event();
+    let metadata_event = MetadataUpdateEventData {
+        token_id,
+        resulting_hash: tile_hash,
+    }
+    .into_event();
+
+    Ok(Response::new()
+        .add_event(clear_event)
+        .add_event(metadata_event))
+}
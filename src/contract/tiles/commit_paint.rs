@@ -0,0 +1,281 @@
+use cosmwasm_std::{
+    Addr, BankMsg, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage,
+};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+use std::collections::HashSet;
+
+use crate::{
+    contract::{
+        delegation::may_paint,
+        error::ContractError,
+        escrow::credit_pending,
+        state::{CONFIG, PAINT_COMMITS, PIXEL_RESERVATIONS, TILE_METADATA},
+        tiles::set_pixel_color::{paint, PaintPayment},
+    },
+    core::{
+        config::{PayoutMode, UnrevealedCommits},
+        tile::{
+            commit::{is_commitment, paint_commitment, PaintCommit, PixelReservation},
+            metadata::PixelUpdate,
+            Tile,
+        },
+    },
+    events::{EventData, PaintCommitEventData, PaintCommitExpireEventData},
+};
+
+pub fn commit_paint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    pixel_ids: Vec<u32>,
+    commitment: String,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    // Fail early on tiles that could never take the paint
+    let token = contract.tokens.load(deps.storage, &token_id)?;
+    let policy = &token.extension.policy;
+    if policy.frozen {
+        return Err(ContractError::TileFrozen { token_id });
+    }
+    let pixels = pixel_ids.len() as u32;
+    if !may_paint(
+        deps.storage,
+        &env.block,
+        &token_id,
+        policy,
+        &token.owner,
+        &info.sender,
+        pixels,
+    )? {
+        return Err(ContractError::PaintingNotAllowed {
+            token_id,
+            painter: info.sender.to_string(),
+        });
+    }
+    if pixel_ids.is_empty() {
+        return Err(ContractError::InvalidPixelUpdate {
+            reason: "a paint commit has to reserve at least one pixel".to_string(),
+        });
+    }
+    let mut seen_ids = HashSet::new();
+    for &id in &pixel_ids {
+        if id >= config.pixels_per_tile() {
+            return Err(ContractError::InvalidPixelId { id });
+        }
+        if !seen_ids.insert(id) {
+            return Err(ContractError::DuplicatePixelId { id });
+        }
+    }
+
+    if !is_commitment(&commitment) {
+        return Err(ContractError::InvalidCommitment {});
+    }
+    let funds = match info.funds.as_slice() {
+        [funds] if !funds.amount.is_zero() => funds.clone(),
+        _ => return Err(ContractError::InsufficientFunds {}),
+    };
+    if !config.accepts_denom(&funds.denom) {
+        return Err(ContractError::UnacceptedDenom { denom: funds.denom });
+    }
+    if PAINT_COMMITS.has(deps.storage, (&token_id, &info.sender)) {
+        return Err(ContractError::CommitPending {
+            token_id,
+            committer: info.sender.to_string(),
+        });
+    }
+
+    let commit = PaintCommit {
+        commitment: commitment.clone(),
+        funds: funds.clone(),
+        height: env.block.height,
+        pixel_ids,
+        owner: token.owner.clone(),
+        policy: token.extension.policy.clone(),
+    };
+    let reservation = PixelReservation {
+        committer: info.sender.clone(),
+        until: commit.reveal_until(config.reveal_window_blocks),
+    };
+    for &id in &commit.pixel_ids {
+        if let Some(held) = PIXEL_RESERVATIONS.may_load(deps.storage, (&token_id, id))? {
+            if held.blocks(&info.sender, env.block.height) {
+                return Err(ContractError::PixelReserved {
+                    id,
+                    until: held.until,
+                });
+            }
+        }
+        PIXEL_RESERVATIONS.save(deps.storage, (&token_id, id), &reservation)?;
+    }
+    PAINT_COMMITS.save(deps.storage, (&token_id, &info.sender), &commit)?;
+
+    let event = PaintCommitEventData {
+        token_id,
+        committer: info.sender,
+        commitment,
+        pixel_ids: commit.pixel_ids,
+        denom: funds.denom,
+        amount: funds.amount.u128(),
+        reveal_until: reservation.until,
+    }
+    .into_event();
+
+    Ok(Response::new().add_event(event))
+}
+
+pub fn reveal_paint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    updates: Vec<PixelUpdate>,
+    salt: String,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let commit = load_commit(deps.as_ref(), &token_id, &info)?;
+
+    let window = config.reveal_window_blocks;
+    if !commit.can_reveal(env.block.height, window) {
+        return Err(ContractError::OutsideRevealWindow {
+            token_id,
+            from: commit.height + 1,
+            until: commit.reveal_until(window),
+        });
+    }
+    if paint_commitment(&info.sender, &token_id, &updates, &salt) != commit.commitment {
+        return Err(ContractError::CommitmentMismatch {});
+    }
+    if let Some(update) = updates
+        .iter()
+        .find(|update| !commit.pixel_ids.contains(&update.id))
+    {
+        return Err(ContractError::PixelNotCommitted { id: update.id });
+    }
+    PAINT_COMMITS.remove(deps.storage, (&token_id, &info.sender));
+    release_pixels(deps.storage, &token_id, &info.sender, &commit.pixel_ids)?;
+
+    // The tile may have changed since the commit, so paint on what is stored now
+    let current_metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+    paint(
+        deps,
+        env,
+        info.sender,
+        token_id,
+        current_metadata,
+        updates,
+        PaintPayment::Committed(commit.funds),
+    )
+}
+
+pub fn expire_commit(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    token_id: String,
+    committer: String,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let committer = deps.api.addr_validate(&committer)?;
+    let commit = PAINT_COMMITS
+        .may_load(deps.storage, (&token_id, &committer))?
+        .ok_or_else(|| ContractError::NoPaintCommit {
+            token_id: token_id.clone(),
+            committer: committer.to_string(),
+        })?;
+    if !commit.is_expired(env.block.height, config.reveal_window_blocks) {
+        return Err(ContractError::CommitNotExpired {
+            token_id,
+            until: commit.reveal_until(config.reveal_window_blocks),
+        });
+    }
+    PAINT_COMMITS.remove(deps.storage, (&token_id, &committer));
+    release_pixels(deps.storage, &token_id, &committer, &commit.pixel_ids)?;
+
+    // Forfeits only go to an owner who kept the tile open to the committer.
+    // Commits on burned, transferred or re-ruled tiles are refunded.
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let owner = match contract.tokens.may_load(deps.storage, &token_id)? {
+        Some(token)
+            if token.owner == commit.owner
+                && token.extension.policy == commit.policy
+                && may_paint(
+                    deps.storage,
+                    &env.block,
+                    &token_id,
+                    &token.extension.policy,
+                    &token.owner,
+                    &committer,
+                    commit.pixel_ids.len() as u32,
+                )? =>
+        {
+            Some(token.owner)
+        }
+        _ => None,
+    };
+    let (recipient, forfeited) = match (&config.unrevealed_commits, owner) {
+        (UnrevealedCommits::Forfeit, Some(owner)) => (owner, true),
+        _ => (committer.clone(), false),
+    };
+
+    // Forfeits are owner payouts, so they follow the payout mode like paints do
+    let messages: Vec<CosmosMsg<StargazeMsgWrapper>> = match config.payout_mode {
+        PayoutMode::Escrow if forfeited => {
+            credit_pending(
+                deps.storage,
+                &recipient,
+                &commit.funds.denom,
+                commit.funds.amount,
+            )?;
+            vec![]
+        }
+        _ => vec![BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![commit.funds.clone()],
+        }
+        .into()],
+    };
+
+    let event = PaintCommitExpireEventData {
+        token_id,
+        committer,
+        recipient,
+        denom: commit.funds.denom,
+        amount: commit.funds.amount.u128(),
+        forfeited,
+    }
+    .into_event();
+
+    Ok(Response::new().add_messages(messages).add_event(event))
+}
+
+fn load_commit(
+    deps: Deps,
+    token_id: &str,
+    info: &MessageInfo,
+) -> Result<PaintCommit, ContractError> {
+    PAINT_COMMITS
+        .may_load(deps.storage, (token_id, &info.sender))?
+        .ok_or_else(|| ContractError::NoPaintCommit {
+            token_id: token_id.to_string(),
+            committer: info.sender.to_string(),
+        })
+}
+
+/// Frees the pixels a commit reserved, unless a later commit has taken them over
+fn release_pixels(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    committer: &Addr,
+    pixel_ids: &[u32],
+) -> StdResult<()> {
+    for &id in pixel_ids {
+        let held = PIXEL_RESERVATIONS.may_load(storage, (token_id, id))?;
+        if held.is_some_and(|held| held.committer == *committer) {
+            PIXEL_RESERVATIONS.remove(storage, (token_id, id));
+        }
+    }
+    Ok(())
+}
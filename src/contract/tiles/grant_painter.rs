@@ -0,0 +1,98 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Order, Response};
+use cw721::Expiration;
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{error::ContractError, state::PAINTER_GRANTS},
+    core::tile::{grant::PainterGrant, Tile},
+    defaults::constants::MAX_PAINTER_GRANTS,
+    events::{EventData, PainterGrantEventData},
+};
+
+pub fn grant_painter(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    painter: String,
+    expires: Option<Expiration>,
+    max_pixels: Option<u32>,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let owner = load_owner(&deps, &info, &token_id)?;
+    let painter = deps.api.addr_validate(&painter)?;
+    let grant = PainterGrant::new(expires, max_pixels);
+
+    let invalid = |reason: &str| ContractError::InvalidPainterGrant {
+        reason: reason.to_string(),
+    };
+    if painter == owner {
+        return Err(invalid("owners can already paint their tiles"));
+    }
+    if grant.is_expired(&env.block) {
+        return Err(invalid("expiration is in the past"));
+    }
+    if max_pixels == Some(0) {
+        return Err(invalid("max_pixels must be greater than zero"));
+    }
+    if !PAINTER_GRANTS.has(deps.storage, (&token_id, &painter)) {
+        let grants = PAINTER_GRANTS
+            .prefix(&token_id)
+            .keys_raw(deps.storage, None, None, Order::Ascending)
+            .count();
+        if grants >= MAX_PAINTER_GRANTS {
+            return Err(invalid(&format!(
+                "tiles cannot have more than {} painters",
+                MAX_PAINTER_GRANTS
+            )));
+        }
+    }
+    PAINTER_GRANTS.save(deps.storage, (&token_id, &painter), &grant)?;
+
+    let event = PainterGrantEventData {
+        token_id,
+        painter,
+        grant: Some(grant),
+    }
+    .into_event();
+
+    Ok(Response::new().add_event(event))
+}
+
+pub fn revoke_painter(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    painter: String,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    load_owner(&deps, &info, &token_id)?;
+    let painter = deps.api.addr_validate(&painter)?;
+    if !PAINTER_GRANTS.has(deps.storage, (&token_id, &painter)) {
+        return Err(ContractError::InvalidPainterGrant {
+            reason: format!("{} has no grant on tile {}", painter, token_id),
+        });
+    }
+    PAINTER_GRANTS.remove(deps.storage, (&token_id, &painter));
+
+    let event = PainterGrantEventData {
+        token_id,
+        painter,
+        grant: None,
+    }
+    .into_event();
+
+    Ok(Response::new().add_event(event))
+}
+
+// Grants are the owner's call alone, cw721 approvals do not extend to them
+fn load_owner(deps: &DepsMut, info: &MessageInfo, token_id: &str) -> Result<Addr, ContractError> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let owner = contract.tokens.load(deps.storage, token_id)?.owner;
+    if info.sender != owner {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender.to_string(),
+        });
+    }
+    Ok(owner)
+}
@@ -3,7 +3,11 @@ use sg721_base::Sg721Contract;
 use sg_std::StargazeMsgWrapper;
 
 use crate::{
-    contract::{error::ContractError, msg::Sg721ExecuteMsg},
+    contract::{
+        error::ContractError,
+        msg::Sg721ExecuteMsg,
+        state::{CONFIG, TILE_METADATA},
+    },
     core::tile::{metadata::TileMetadata, Tile},
     events::{EventData, MintMetadataEventData},
 };
@@ -19,12 +23,13 @@ pub fn mint_handler(
     let contract: Sg721Contract<Tile> = Sg721Contract::default();
 
     // Generate initial metadata
-    let metadata = TileMetadata::default();
+    let config = CONFIG.load(deps.storage)?;
+    let metadata = TileMetadata::new(&config);
 
     // Generate our own extension
-    let extension = Tile {
-        tile_hash: metadata.hash(),
-    };
+    let extension = Tile::new(metadata.hash());
+
+    TILE_METADATA.save(deps.storage, &token_id, &metadata)?;
 
     // Create mint message with our Tile extension
     let mint_msg = Sg721ExecuteMsg::Mint {
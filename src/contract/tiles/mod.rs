@@ -1,3 +1,15 @@
+pub mod burn;
+pub mod clear_annotations;
+pub mod commit_paint;
+pub mod grant_painter;
 pub mod mint;
+pub mod paint_image;
+pub mod receive;
+pub mod set_base_layer;
+pub mod set_cw20_price_scaling;
 pub mod set_pixel_color;
+pub mod set_tile_policy;
+pub mod transfer;
+pub mod update_config;
 pub mod update_price_scaling;
+pub mod withdraw;
@@ -0,0 +1,48 @@
+use cosmwasm_std::{Binary, DepsMut, Env, MessageInfo, Response};
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{
+        error::ContractError,
+        state::{CONFIG, TILE_METADATA},
+        tiles::set_pixel_color::{paint, PaintPayment},
+    },
+    core::tile::image::ImageFormat,
+};
+
+/// Paints a whole bitmap over the tile, only the pixels it changes are painted
+/// and paid for
+pub fn paint_image(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    format: ImageFormat,
+    data: Binary,
+    duration: u64,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let colors = format.decode(data.as_slice(), config.pixels_per_tile())?;
+
+    // Diff against what is stored rather than what the sender saw
+    let current_metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+    let updates = current_metadata.image_updates(
+        &colors,
+        &config.default_color,
+        env.block.time.seconds(),
+        duration,
+    );
+    if updates.is_empty() {
+        return Err(ContractError::ImageUnchanged { token_id });
+    }
+
+    paint(
+        deps,
+        env,
+        info.sender,
+        token_id,
+        current_metadata,
+        updates,
+        PaintPayment::Native(info.funds),
+    )
+}
@@ -0,0 +1,39 @@
+use cosmwasm_std::{from_json, DepsMut, Env, MessageInfo, Response};
+use cw20::Cw20ReceiveMsg;
+use sg_std::StargazeMsgWrapper;
+
+use crate::contract::{
+    error::ContractError,
+    msg::Cw20HookMsg,
+    tiles::set_pixel_color::{paint, PaintPayment},
+};
+
+/// Entry point for CW20 `Send`, the calling contract is the token being paid
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receive_msg: Cw20ReceiveMsg,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let painter = deps.api.addr_validate(&receive_msg.sender)?;
+    let payment = PaintPayment::Cw20 {
+        token: info.sender,
+        amount: receive_msg.amount,
+    };
+
+    match from_json(&receive_msg.msg)? {
+        Cw20HookMsg::SetPixelColor {
+            token_id,
+            current_metadata,
+            updates,
+        } => paint(
+            deps,
+            env,
+            painter,
+            token_id,
+            current_metadata,
+            updates,
+            payment,
+        ),
+    }
+}
@@ -0,0 +1,67 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+use std::collections::HashSet;
+
+use crate::{
+    contract::{
+        error::ContractError,
+        state::{CONFIG, TILE_METADATA},
+    },
+    core::tile::{layer::BasePixel, Tile},
+    events::{BaseLayerUpdateEventData, EventData, MetadataUpdateEventData},
+};
+
+pub fn set_base_layer(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+    pixels: Vec<BasePixel>,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let mut token = contract.tokens.load(deps.storage, &token_id)?;
+
+    // Only the owner, approvals do not extend to the base layer
+    if info.sender != token.owner {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender.to_string(),
+        });
+    }
+    if token.extension.policy.frozen {
+        return Err(ContractError::TileFrozen { token_id });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut seen_ids = HashSet::new();
+    for pixel in &pixels {
+        if !seen_ids.insert(pixel.id) {
+            return Err(ContractError::DuplicatePixelId { id: pixel.id });
+        }
+        pixel.validate(&config)?;
+    }
+
+    let mut metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+    metadata.apply_base_pixels(&pixels);
+    let tile_hash = metadata.hash();
+    TILE_METADATA.save(deps.storage, &token_id, &metadata)?;
+    token.extension.tile_hash = tile_hash.clone();
+    contract.tokens.save(deps.storage, &token_id, &token)?;
+
+    let layer_event = BaseLayerUpdateEventData {
+        token_id: token_id.clone(),
+        owner: token.owner,
+        pixels,
+        tile_hash: tile_hash.clone(),
+    }
+    .into_event();
+    let metadata_event = MetadataUpdateEventData {
+        token_id,
+        resulting_hash: tile_hash,
+    }
+    .into_event();
+
+    Ok(Response::new()
+        .add_event(layer_event)
+        .add_event(metadata_event))
+}
@@ -0,0 +1,51 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{error::ContractError, state::CW20_PRICE_SCALING},
+    core::{pricing::PriceScaling, tile::Tile},
+    events::{Cw20PriceScalingUpdateEventData, EventData},
+};
+
+pub fn set_cw20_price_scaling(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token: String,
+    price_scaling: Option<PriceScaling>,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    // Same authority as the native price scaling
+    let contract = Sg721Contract::<Tile>::default();
+    let royalty_info = contract
+        .collection_info
+        .load(deps.storage)?
+        .royalty_info
+        .ok_or(ContractError::MissingRoyaltyInfo {})?;
+    if info.sender != royalty_info.payment_address {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender.to_string(),
+        });
+    }
+
+    let token = deps.api.addr_validate(&token)?;
+    match &price_scaling {
+        Some(price_scaling) => {
+            price_scaling
+                .validate()
+                .map_err(|e| ContractError::InvalidPixelUpdate {
+                    reason: e.to_string(),
+                })?;
+            CW20_PRICE_SCALING.save(deps.storage, &token, price_scaling)?;
+        }
+        None => CW20_PRICE_SCALING.remove(deps.storage, &token),
+    }
+
+    let event = Cw20PriceScalingUpdateEventData {
+        token,
+        price_scaling,
+    }
+    .into_event();
+
+    Ok(Response::new().add_event(event))
+}
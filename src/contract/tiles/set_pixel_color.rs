@@ -1,32 +1,139 @@
-use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
 use cw721::OwnerOfResponse;
+use cw_utils::PaymentError;
 use sg721_base::Sg721Contract;
 use sg_std::StargazeMsgWrapper;
 use std::collections::HashSet;
 
 use crate::{
-    contract::{error::ContractError, msg::QueryMsg, state::PRICE_SCALING},
-    core::tile::{
-        metadata::{PixelData, PixelUpdate, TileMetadata},
-        Tile,
+    contract::{
+        earnings::record_earnings,
+        error::ContractError,
+        escrow::credit_pending,
+        history::record_pixel_history,
+        msg::QueryMsg,
+        outbid::{pixel_takeover, record_pixel_payment, PixelTakeover},
+        painter::record_painter_stats,
+        state::{
+            CONFIG, CW20_PRICE_SCALING, PAINTER_GRANTS, PIXEL_RESERVATIONS, PRICE_SCALING,
+            TILE_METADATA,
+        },
+    },
+    core::{
+        config::PayoutMode,
+        revenue::{PaymentDistribution, Payout},
+        tile::{
+            metadata::{PixelData, PixelUpdate, TileMetadata},
+            Tile,
+        },
     },
     events::{
-        EventData, MetadataUpdateEventData, PaymentDistributionEventData, PixelUpdateEventData,
+        EventData, MetadataUpdateEventData, PaymentDistributionEventData, PixelOutbidEventData,
+        PixelUpdateEventData,
     },
 };
 
+/// What a paint is paid with
+pub enum PaintPayment {
+    /// Funds attached to the message
+    Native(Vec<Coin>),
+    /// Tokens received through the CW20 `Receive` hook
+    Cw20 { token: Addr, amount: Uint128 },
+    /// Funds escrowed by `CommitPaint`, may exceed the price
+    Committed(Coin),
+}
+
+impl PaintPayment {
+    /// Key used for earnings and pending balances, the token address for CW20
+    fn denom(&self) -> String {
+        match self {
+            PaintPayment::Native(funds) => funds
+                .first()
+                .map(|coin| coin.denom.clone())
+                .unwrap_or_default(),
+            PaintPayment::Cw20 { token, .. } => token.to_string(),
+            PaintPayment::Committed(funds) => funds.denom.clone(),
+        }
+    }
+}
+
 pub fn set_pixel_color(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     token_id: String,
+    current_metadata: TileMetadata,
+    updates: Vec<PixelUpdate>,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    paint(
+        deps,
+        env,
+        info.sender,
+        token_id,
+        current_metadata,
+        updates,
+        PaintPayment::Native(info.funds),
+    )
+}
+
+pub fn paint(
+    deps: DepsMut,
+    env: Env,
+    painter: Addr,
+    token_id: String,
     mut current_metadata: TileMetadata,
     updates: Vec<PixelUpdate>,
+    payment: PaintPayment,
 ) -> Result<Response<StargazeMsgWrapper>, ContractError> {
     let contract: Sg721Contract<Tile> = Sg721Contract::default();
 
-    // Verify current metadata hash matches stored hash
+    // Enforce the owner's policy before looking at the update itself
     let mut token = contract.tokens.load(deps.storage, &token_id)?;
+    let policy = &token.extension.policy;
+    if policy.frozen {
+        return Err(ContractError::TileFrozen { token_id });
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let allowed = policy.allows(&painter, &token.owner);
+    let is_owner = painter == token.owner;
+    // Granted painters paint as the owner would, within their grant. Grants are
+    // only consulted where the policy shuts the painter out or painting is free.
+    let mut granted = false;
+    if !is_owner && (!allowed || config.free_owner_painting) {
+        let grant = PAINTER_GRANTS
+            .may_load(deps.storage, (&token_id, &painter))?
+            .filter(|grant| !grant.is_expired(&env.block));
+        let pixels = updates.len() as u32;
+        match grant {
+            Some(mut grant) if grant.covers(pixels) => {
+                grant.pixels_painted += pixels;
+                PAINTER_GRANTS.save(deps.storage, (&token_id, &painter), &grant)?;
+                granted = true;
+            }
+            // Anyone may paint the tile, just not for free
+            _ if allowed => {}
+            Some(_) => {
+                return Err(ContractError::PainterGrantExceeded {
+                    token_id,
+                    painter: painter.to_string(),
+                    pixels,
+                })
+            }
+            None => {
+                return Err(ContractError::PaintingNotAllowed {
+                    token_id,
+                    painter: painter.to_string(),
+                })
+            }
+        }
+    }
+    let free = config.free_owner_painting && (is_owner || granted);
+
+    // Verify current metadata hash matches stored hash
     if token.extension.tile_hash != current_metadata.hash() {
         return Err(ContractError::MetadataHashMismatch {});
     }
@@ -40,10 +147,24 @@ pub fn set_pixel_color(
         .querier
         .query_wasm_smart(env.contract.address.clone(), &owner_query)?;
 
-    let price_scaling = PRICE_SCALING.load(deps.storage)?;
+    // CW20 tokens are priced separately from native denoms
+    let price_scaling = match &payment {
+        PaintPayment::Native(_) | PaintPayment::Committed(_) => PRICE_SCALING.load(deps.storage)?,
+        PaintPayment::Cw20 { token, .. } => CW20_PRICE_SCALING
+            .may_load(deps.storage, token)?
+            .ok_or_else(|| ContractError::UnacceptedCw20 {
+                token: token.to_string(),
+            })?,
+    };
     let current_time = env.block.time.seconds();
     let mut seen_ids = HashSet::new();
     let mut total_price = Uint128::zero();
+    let mut pixel_prices = Vec::with_capacity(updates.len());
+    let multiplier = if free {
+        Decimal::zero()
+    } else {
+        token.extension.policy.price_multiplier
+    };
 
     // Single pass: validate duplicates, validate updates, calculate price
     for update in &updates {
@@ -53,18 +174,84 @@ pub fn set_pixel_color(
         }
 
         // First validate the update integrity
-        update.validate_integrity()?;
+        update.validate_integrity(&config)?;
 
         // Then validate if it can be applied to the tile
         update.validate_for_tile(&current_metadata.pixels[update.id as usize], current_time)?;
 
-        // Add to total price
-        total_price += price_scaling.calculate_price(update.expiration_duration);
+        // Pixels held by someone else's paint commit wait for its reveal
+        if let Some(held) = PIXEL_RESERVATIONS.may_load(deps.storage, (&token_id, update.id))? {
+            if held.blocks(&painter, env.block.height) {
+                return Err(ContractError::PixelReserved {
+                    id: update.id,
+                    until: held.until,
+                });
+            }
+        }
+
+        // Add to total price, rounded per pixel so it matches what outbids refund
+        let price = price_scaling.calculate_price(update.expiration_duration);
+        let price = (price
+            + config.keyframe_surcharge(price, update.keyframes.len())
+            + price_scaling.annotation_price(update.annotation_bytes()))
+            * multiplier;
+        pixel_prices.push(price);
+        total_price += price;
+    }
+    let denom = payment.denom();
+
+    // Live pixels of other painters have to be bought out when outbids are enabled
+    let mut takeovers: Vec<PixelTakeover> = vec![];
+    if let Some(outbid) = &config.outbid {
+        for update in &updates {
+            let pixel = &current_metadata.pixels[update.id as usize];
+            if pixel.last_updated_by == painter {
+                continue;
+            }
+            if let Some(takeover) =
+                pixel_takeover(deps.storage, outbid, &token_id, pixel, &denom, current_time)?
+            {
+                takeovers.push(takeover);
+            }
+        }
     }
+    let takeover_price: Uint128 = takeovers.iter().map(|t| t.takeover.price()).sum();
+    let required = total_price + takeover_price;
 
     // Verify sent funds match total price
-    if info.funds.is_empty() || info.funds[0].amount != total_price {
-        return Err(ContractError::InsufficientFunds {});
+    match &payment {
+        PaintPayment::Native(funds) if required.is_zero() => {
+            if !funds.is_empty() {
+                return Err(PaymentError::NonPayable {}.into());
+            }
+        }
+        PaintPayment::Native(funds) => {
+            // Extra coins would be kept without being paid out or refunded
+            let coin = match funds.as_slice() {
+                [coin] => coin,
+                [] => return Err(ContractError::InsufficientFunds {}),
+                _ => return Err(PaymentError::MultipleDenoms {}.into()),
+            };
+            if !config.accepts_denom(&coin.denom) {
+                return Err(ContractError::UnacceptedDenom {
+                    denom: coin.denom.clone(),
+                });
+            }
+            if coin.amount != required {
+                return Err(ContractError::InsufficientFunds {});
+            }
+        }
+        PaintPayment::Cw20 { amount, .. } => {
+            if *amount != required {
+                return Err(ContractError::InsufficientFunds {});
+            }
+        }
+        // Prices can move between commit and reveal, so escrowed funds only have to cover them
+        PaintPayment::Committed(funds) => {
+            if funds.amount < required {
+                return Err(ContractError::InsufficientFunds {});
+            }
+        }
     }
 
     // Get royalty info from collection info
@@ -74,28 +261,65 @@ pub fn set_pixel_color(
         .ok_or(ContractError::MissingRoyaltyInfo {})?;
 
     // Calculate payment distribution
-    let royalty_amount = total_price * royalty_info.share;
-    let owner_amount = total_price - royalty_amount;
-
-    // Create bank messages for payment distribution
-    let bank_msgs: Vec<CosmosMsg<StargazeMsgWrapper>> = vec![
-        cosmwasm_std::BankMsg::Send {
-            to_address: royalty_info.payment_address.to_string(),
-            amount: vec![cosmwasm_std::Coin {
-                denom: info.funds[0].denom.clone(),
-                amount: royalty_amount,
-            }],
-        }
-        .into(),
-        cosmwasm_std::BankMsg::Send {
-            to_address: owner.owner,
-            amount: vec![cosmwasm_std::Coin {
-                denom: info.funds[0].denom.clone(),
-                amount: owner_amount,
-            }],
+    let owner_addr = deps.api.addr_validate(&owner.owner)?;
+    // The premium not refunded is split like the paint itself
+    let retained: Uint128 = takeovers.iter().map(|t| t.takeover.retained()).sum();
+    let distribution = PaymentDistribution::new(
+        total_price + retained,
+        royalty_info.share,
+        royalty_info.payment_address,
+        owner_addr,
+        &config.revenue_splits,
+    );
+    let refunds = takeovers.iter().map(|t| Payout {
+        recipient: t.displaced.clone(),
+        amount: t.takeover.displaced_refund,
+    });
+    // Bank sends and cw20-base both refuse zero amounts
+    let payouts: Vec<Payout> = distribution
+        .payouts()
+        .into_iter()
+        .chain(refunds)
+        .filter(|payout| !payout.amount.is_zero())
+        .collect();
+
+    // Pay out directly, or credit pending balances in escrow mode. CW20
+    // transfers cannot be rejected by the recipient, so they are never escrowed.
+    let payout_msgs: Vec<CosmosMsg<StargazeMsgWrapper>> = match (&payment, &config.payout_mode) {
+        (PaintPayment::Native(_) | PaintPayment::Committed(_), PayoutMode::Direct) => payouts
+            .iter()
+            .map(|payout| {
+                BankMsg::Send {
+                    to_address: payout.recipient.to_string(),
+                    amount: vec![Coin {
+                        denom: denom.clone(),
+                        amount: payout.amount,
+                    }],
+                }
+                .into()
+            })
+            .collect(),
+        (PaintPayment::Native(_) | PaintPayment::Committed(_), PayoutMode::Escrow) => {
+            for payout in &payouts {
+                credit_pending(deps.storage, &payout.recipient, &denom, payout.amount)?;
+            }
+            vec![]
         }
-        .into(),
-    ];
+        (PaintPayment::Cw20 { token, .. }, _) => payouts
+            .iter()
+            .map(|payout| -> Result<_, ContractError> {
+                Ok(WasmMsg::Execute {
+                    contract_addr: token.to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: payout.recipient.to_string(),
+                        amount: payout.amount,
+                    })?,
+                    funds: vec![],
+                }
+                .into())
+            })
+            .collect::<Result<_, _>>()?,
+    };
 
     // Create events for each pixel update
     let mut new_pixels = Vec::with_capacity(updates.len());
@@ -104,13 +328,40 @@ pub fn set_pixel_color(
             id: update.id,
             color: update.color.clone(),
             expiration_timestamp: current_time + update.expiration_duration,
-            last_updated_by: info.sender.clone(),
+            last_updated_by: painter.clone(),
             last_updated_at: current_time,
+            keyframes: update.keyframes.clone(),
+            annotation: update.annotation.clone(),
         });
     }
 
+    if config.history_enabled() {
+        record_pixel_history(
+            deps.storage,
+            &token_id,
+            &new_pixels,
+            config.pixel_history_depth,
+        )?;
+    }
+
+    record_painter_stats(
+        deps.storage,
+        &painter,
+        &token_id,
+        &current_metadata.pixels,
+        &new_pixels,
+        required,
+        current_time,
+    )?;
+
+    if config.outbid.is_some() {
+        for (update, price) in updates.iter().zip(pixel_prices) {
+            record_pixel_payment(deps.storage, &token_id, update.id, &denom, price)?;
+        }
+    }
+
     // Apply all updates at once
-    current_metadata.apply_updates(updates, &info.sender, current_time);
+    current_metadata.apply_updates(updates, &painter, current_time);
 
     // Create pixel update event
     let pixel_event = PixelUpdateEventData {
@@ -127,24 +378,57 @@ pub fn set_pixel_color(
     }
     .into_event();
 
+    if !required.is_zero() {
+        record_earnings(deps.storage, &token_id, &denom, &distribution)?;
+    }
+
     // Create payment distribution event
     let payment_event = PaymentDistributionEventData {
         token_id: token_id.clone(),
-        sender: info.sender.clone(),
-        royalty_amount: royalty_amount.u128(),
-        owner_amount: owner_amount.u128(),
+        sender: painter.clone(),
+        royalty_amount: distribution.royalty.amount.u128(),
+        owner_amount: distribution.owner.amount.u128(),
+        payouts: distribution.payouts(),
     }
     .into_event();
 
     // Update token extension with new metadata hash
     token.extension.tile_hash = current_metadata.hash();
     contract.tokens.save(deps.storage, &token_id, &token)?;
+    TILE_METADATA.save(deps.storage, &token_id, &current_metadata)?;
+
+    let outbid_events = takeovers.into_iter().map(|t| {
+        PixelOutbidEventData {
+            token_id: token_id.clone(),
+            pixel_id: t.pixel_id,
+            challenger: painter.clone(),
+            displaced: t.displaced,
+            denom: denom.clone(),
+            remaining_value: t.takeover.remaining_value.u128(),
+            premium: t.takeover.premium.u128(),
+            refund: t.takeover.displaced_refund.u128(),
+        }
+        .into_event()
+    });
 
-    let response = Response::new()
-        .add_messages(bank_msgs)
+    let mut response = Response::new()
+        .add_messages(payout_msgs)
         .add_event(pixel_event)
         .add_event(metadata_event)
-        .add_event(payment_event);
+        .add_event(payment_event)
+        .add_events(outbid_events);
+    if let PaintPayment::Committed(funds) = &payment {
+        let change = funds.amount - required;
+        if !change.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: painter.to_string(),
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount: change,
+                }],
+            });
+        }
+    }
 
     Ok(response)
 }
@@ -0,0 +1,61 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{error::ContractError, state::CONFIG},
+    core::tile::{
+        policy::{PaintAccess, TilePolicy},
+        Tile,
+    },
+    events::{EventData, TilePolicyUpdateEventData},
+};
+
+pub fn set_tile_policy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    policy: TilePolicy,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let mut token = contract.tokens.load(deps.storage, &token_id)?;
+
+    // Only the owner, approvals do not extend to the policy
+    if info.sender != token.owner {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender.to_string(),
+        });
+    }
+    if token.extension.policy.frozen {
+        return Err(ContractError::TileFrozen { token_id });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    policy
+        .validate(&config)
+        .map_err(|e| ContractError::InvalidTilePolicy {
+            reason: e.to_string(),
+        })?;
+    if let PaintAccess::Allowlist(allowed) = &policy.access {
+        for address in allowed {
+            deps.api.addr_validate(address.as_str())?;
+        }
+    }
+
+    if policy.frozen {
+        token.extension.frozen_at = Some(env.block.time.seconds());
+    }
+    token.extension.policy = policy.clone();
+    contract.tokens.save(deps.storage, &token_id, &token)?;
+
+    let event = TilePolicyUpdateEventData {
+        token_id,
+        owner: token.owner,
+        policy,
+        frozen_at: token.extension.frozen_at,
+    }
+    .into_event();
+
+    Ok(Response::new().add_event(event))
+}
@@ -0,0 +1,78 @@
+use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{
+        delegation::clear_painter_grants, error::ContractError, msg::Sg721ExecuteMsg,
+        state::TILE_METADATA,
+    },
+    core::tile::Tile,
+    events::{EventData, TileTransferEventData},
+};
+
+pub fn transfer_handler(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    token_id: String,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let previous_owner = contract.tokens.load(deps.storage, &token_id)?.owner;
+
+    // Forward to base contract, which handles approvals and ownership checks
+    let base_msg = Sg721ExecuteMsg::TransferNft {
+        recipient,
+        token_id: token_id.clone(),
+    };
+    let response = contract.execute(deps.branch(), env.clone(), info, base_msg)?;
+    clear_painter_grants(deps.storage, &token_id)?;
+
+    let transfer_event = transfer_event(deps.as_ref(), &env, token_id, previous_owner)?;
+    Ok(response.add_event(transfer_event))
+}
+
+pub fn send_handler(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract_addr: String,
+    token_id: String,
+    msg: Binary,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let previous_owner = contract.tokens.load(deps.storage, &token_id)?.owner;
+
+    // Forward to base contract, which handles approvals and ownership checks
+    let base_msg = Sg721ExecuteMsg::SendNft {
+        contract: contract_addr,
+        token_id: token_id.clone(),
+        msg,
+    };
+    let response = contract.execute(deps.branch(), env.clone(), info, base_msg)?;
+    clear_painter_grants(deps.storage, &token_id)?;
+
+    let transfer_event = transfer_event(deps.as_ref(), &env, token_id, previous_owner)?;
+    Ok(response.add_event(transfer_event))
+}
+
+fn transfer_event(
+    deps: Deps,
+    env: &Env,
+    token_id: String,
+    previous_owner: Addr,
+) -> Result<Event, ContractError> {
+    let contract: Sg721Contract<Tile> = Sg721Contract::default();
+    let token = contract.tokens.load(deps.storage, &token_id)?;
+    let metadata = TILE_METADATA.load(deps.storage, &token_id)?;
+
+    Ok(TileTransferEventData {
+        token_id,
+        previous_owner,
+        new_owner: token.owner,
+        tile_hash: token.extension.tile_hash,
+        live_pixels: metadata.live_pixels(env.block.time.seconds()),
+    }
+    .into_event())
+}
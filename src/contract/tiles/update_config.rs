@@ -0,0 +1,56 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use sg721_base::Sg721Contract;
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{error::ContractError, state::CONFIG},
+    core::{config::Config, tile::Tile},
+    events::{ConfigUpdateEventData, EventData},
+};
+
+pub fn update_config(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_config: Config,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    let contract = Sg721Contract::<Tile>::default();
+    let collection_info = contract.collection_info.load(deps.storage)?;
+
+    // Only royalty payment address can update the config
+    match collection_info.royalty_info {
+        Some(royalty_info) if info.sender == royalty_info.payment_address => (),
+        Some(_) => {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.to_string(),
+            })
+        }
+        None => return Err(ContractError::MissingRoyaltyInfo {}),
+    }
+
+    new_config
+        .validate()
+        .map_err(|e| ContractError::InvalidConfig {
+            reason: e.to_string(),
+        })?;
+    for split in &new_config.revenue_splits {
+        deps.api.addr_validate(split.recipient.as_str())?;
+    }
+    for moderator in &new_config.moderators {
+        deps.api.addr_validate(moderator.as_str())?;
+    }
+
+    // Minted tiles hold one pixel per position of the current dimensions
+    let config = CONFIG.load(deps.storage)?;
+    let resized =
+        new_config.tile_width != config.tile_width || new_config.tile_height != config.tile_height;
+    if resized && contract.parent.token_count(deps.storage)? > 0 {
+        return Err(ContractError::TileDimensionsLocked {});
+    }
+
+    CONFIG.save(deps.storage, &new_config)?;
+
+    let event = ConfigUpdateEventData { config: new_config }.into_event();
+
+    Ok(Response::new().add_event(event))
+}
@@ -48,6 +48,7 @@ pub fn update_price_scaling(
         hour_12_price: new_scaling.hour_12_price.u128(),
         hour_24_price: new_scaling.hour_24_price.u128(),
         quadratic_base: new_scaling.quadratic_base.u128(),
+        annotation_byte_price: new_scaling.annotation_byte_price.u128(),
     }
     .into_event();
 
@@ -0,0 +1,57 @@
+use cosmwasm_std::{BankMsg, Coin, DepsMut, Env, MessageInfo, Response, Uint128};
+use cw_utils::nonpayable;
+use sg_std::StargazeMsgWrapper;
+
+use crate::{
+    contract::{error::ContractError, state::PENDING_BALANCES},
+    events::{EventData, WithdrawEventData},
+};
+
+pub fn withdraw(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Option<Uint128>,
+) -> Result<Response<StargazeMsgWrapper>, ContractError> {
+    // Funds sent along would be stuck in the contract
+    nonpayable(&info)?;
+
+    let key = (&info.sender, denom.as_str());
+    let available = PENDING_BALANCES
+        .may_load(deps.storage, key)?
+        .unwrap_or_default();
+    let amount = amount.unwrap_or(available);
+
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw { denom });
+    }
+    if amount > available {
+        return Err(ContractError::InsufficientPendingBalance {
+            denom,
+            requested: amount,
+            available,
+        });
+    }
+
+    let remaining = available - amount;
+    if remaining.is_zero() {
+        PENDING_BALANCES.remove(deps.storage, key);
+    } else {
+        PENDING_BALANCES.save(deps.storage, key, &remaining)?;
+    }
+
+    let event = WithdrawEventData {
+        address: info.sender.clone(),
+        denom: denom.clone(),
+        amount: amount.u128(),
+    }
+    .into_event();
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin { denom, amount }],
+        })
+        .add_event(event))
+}
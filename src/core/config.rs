@@ -0,0 +1,288 @@
+use crate::{
+    core::{outbid::OutbidConfig, revenue::RevenueSplit},
+    defaults::constants::{
+        BASIS_POINTS, DEFAULT_COLOR, DEFAULT_KEYFRAME_SURCHARGE_BPS,
+        DEFAULT_MAX_PRICE_MULTIPLIER_PERCENT, DEFAULT_MIN_PRICE_MULTIPLIER_PERCENT,
+        DEFAULT_PIXEL_HISTORY_DEPTH, DEFAULT_REVEAL_WINDOW_BLOCKS, DEFAULT_TILE_HEIGHT,
+        DEFAULT_TILE_WIDTH, MAX_KEYFRAME_SURCHARGE_BPS, MAX_MODERATORS, MAX_PIXELS_PER_TILE,
+        MAX_PIXEL_HISTORY_DEPTH, MAX_PRICE_MULTIPLIER_PERCENT, MAX_REVEAL_WINDOW_BLOCKS,
+        MAX_REVENUE_SPLITS, MAX_TILE_DIMENSION, PIXEL_MAX_EXPIRATION, PIXEL_MIN_EXPIRATION,
+    },
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use sg_std::NATIVE_DENOM;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ConfigError {
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
+}
+
+/// How paint payments reach tile owners and the royalty address
+#[cw_serde]
+#[derive(Default)]
+pub enum PayoutMode {
+    /// Bank transfers are sent with every paint
+    #[default]
+    Direct,
+    /// Payouts are credited to pending balances and claimed with `Withdraw`
+    Escrow,
+}
+
+/// What happens to the payment of a paint commit that was never revealed
+#[cw_serde]
+#[derive(Default)]
+pub enum UnrevealedCommits {
+    /// Returned to the committer
+    #[default]
+    Refund,
+    /// Paid to the tile owner
+    Forfeit,
+}
+
+/// Canvas and painting parameters, set at instantiate and replaced with `UpdateConfig`
+#[cw_serde]
+pub struct Config {
+    /// Native denom paints are paid in. A single one, since the price scaling
+    /// does not know what a unit of any other denom is worth.
+    pub denom: String,
+    pub pixel_min_expiration: u64,
+    pub pixel_max_expiration: u64,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub default_color: String,
+    /// Past paints kept per pixel, zero disables history
+    #[serde(default)]
+    pub pixel_history_depth: u32,
+    #[serde(default)]
+    pub payout_mode: PayoutMode,
+    /// Recipients paid alongside the owner and royalty address on every paint
+    #[serde(default)]
+    pub revenue_splits: Vec<RevenueSplit>,
+    /// Range tile owners may scale their tile's paint price within
+    #[serde(default = "default_min_price_multiplier")]
+    pub min_price_multiplier: Decimal,
+    #[serde(default = "default_max_price_multiplier")]
+    pub max_price_multiplier: Decimal,
+    /// Makes live pixels cost a prorated buyout plus premium to paint over.
+    /// Without it they can be painted over at the regular price.
+    #[serde(default)]
+    pub outbid: Option<OutbidConfig>,
+    /// Blocks after a `CommitPaint` in which it can be revealed
+    #[serde(default = "default_reveal_window_blocks")]
+    pub reveal_window_blocks: u64,
+    #[serde(default)]
+    pub unrevealed_commits: UnrevealedCommits,
+    /// Added to a pixel's price for each keyframe it schedules, out of 10,000
+    #[serde(default = "default_keyframe_surcharge_bps")]
+    pub keyframe_surcharge_bps: u16,
+    /// Addresses allowed to clear pixel annotations on any tile
+    #[serde(default)]
+    pub moderators: Vec<Addr>,
+    /// Lets tile owners, and painters they granted, paint their tile for free
+    #[serde(default)]
+    pub free_owner_painting: bool,
+}
+
+fn default_min_price_multiplier() -> Decimal {
+    Decimal::percent(DEFAULT_MIN_PRICE_MULTIPLIER_PERCENT)
+}
+
+fn default_max_price_multiplier() -> Decimal {
+    Decimal::percent(DEFAULT_MAX_PRICE_MULTIPLIER_PERCENT)
+}
+
+fn default_reveal_window_blocks() -> u64 {
+    DEFAULT_REVEAL_WINDOW_BLOCKS
+}
+
+fn default_keyframe_surcharge_bps() -> u16 {
+    DEFAULT_KEYFRAME_SURCHARGE_BPS
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            denom: NATIVE_DENOM.to_string(),
+            pixel_min_expiration: PIXEL_MIN_EXPIRATION,
+            pixel_max_expiration: PIXEL_MAX_EXPIRATION,
+            tile_width: DEFAULT_TILE_WIDTH,
+            tile_height: DEFAULT_TILE_HEIGHT,
+            default_color: DEFAULT_COLOR.to_string(),
+            pixel_history_depth: DEFAULT_PIXEL_HISTORY_DEPTH,
+            payout_mode: PayoutMode::default(),
+            revenue_splits: vec![],
+            min_price_multiplier: default_min_price_multiplier(),
+            max_price_multiplier: default_max_price_multiplier(),
+            outbid: None,
+            reveal_window_blocks: default_reveal_window_blocks(),
+            unrevealed_commits: UnrevealedCommits::default(),
+            keyframe_surcharge_bps: default_keyframe_surcharge_bps(),
+            moderators: vec![],
+            free_owner_painting: false,
+        }
+    }
+}
+
+impl Config {
+    pub fn pixels_per_tile(&self) -> u32 {
+        self.tile_width * self.tile_height
+    }
+
+    /// Column and row of a pixel, pixels are stored row by row from the top left
+    pub fn pixel_position(&self, id: u32) -> (u32, u32) {
+        (id % self.tile_width, id / self.tile_width)
+    }
+
+    pub fn history_enabled(&self) -> bool {
+        self.pixel_history_depth > 0
+    }
+
+    pub fn accepts_denom(&self, denom: &str) -> bool {
+        self.denom == denom
+    }
+
+    /// Extra cost of scheduling `keyframes` on a pixel that costs `price`
+    pub fn keyframe_surcharge(&self, price: Uint128, keyframes: usize) -> Uint128 {
+        price.multiply_ratio(
+            self.keyframe_surcharge_bps as u128 * keyframes as u128,
+            BASIS_POINTS,
+        )
+    }
+
+    pub fn is_moderator(&self, address: &Addr) -> bool {
+        self.moderators.contains(address)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.denom.is_empty() {
+            return Err(ConfigError::InvalidConfig(
+                "denom cannot be empty".to_string(),
+            ));
+        }
+        if self.pixel_min_expiration == 0 {
+            return Err(ConfigError::InvalidConfig(
+                "pixel_min_expiration must be greater than zero".to_string(),
+            ));
+        }
+        if self.pixel_min_expiration > self.pixel_max_expiration {
+            return Err(ConfigError::InvalidConfig(
+                "pixel_min_expiration must be less than or equal to pixel_max_expiration"
+                    .to_string(),
+            ));
+        }
+        for (name, dimension) in [
+            ("tile_width", self.tile_width),
+            ("tile_height", self.tile_height),
+        ] {
+            if dimension == 0 || dimension > MAX_TILE_DIMENSION {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "{} must be between 1 and {}",
+                    name, MAX_TILE_DIMENSION
+                )));
+            }
+        }
+        if self.pixels_per_tile() > MAX_PIXELS_PER_TILE {
+            return Err(ConfigError::InvalidConfig(format!(
+                "tiles cannot have more than {} pixels",
+                MAX_PIXELS_PER_TILE
+            )));
+        }
+        if self.pixel_history_depth > MAX_PIXEL_HISTORY_DEPTH {
+            return Err(ConfigError::InvalidConfig(format!(
+                "pixel_history_depth cannot exceed {}",
+                MAX_PIXEL_HISTORY_DEPTH
+            )));
+        }
+        self.validate_revenue_splits()?;
+        if self.min_price_multiplier.is_zero()
+            || self.min_price_multiplier > self.max_price_multiplier
+            || self.max_price_multiplier > Decimal::percent(MAX_PRICE_MULTIPLIER_PERCENT)
+        {
+            return Err(ConfigError::InvalidConfig(format!(
+                "price multipliers must satisfy 0 < min <= max <= {}",
+                Decimal::percent(MAX_PRICE_MULTIPLIER_PERCENT)
+            )));
+        }
+        if let Some(outbid) = &self.outbid {
+            outbid
+                .validate()
+                .map_err(|e| ConfigError::InvalidConfig(e.to_string()))?;
+        }
+        if self.reveal_window_blocks == 0 || self.reveal_window_blocks > MAX_REVEAL_WINDOW_BLOCKS {
+            return Err(ConfigError::InvalidConfig(format!(
+                "reveal_window_blocks must be between 1 and {}",
+                MAX_REVEAL_WINDOW_BLOCKS
+            )));
+        }
+        if self.keyframe_surcharge_bps > MAX_KEYFRAME_SURCHARGE_BPS {
+            return Err(ConfigError::InvalidConfig(format!(
+                "keyframe_surcharge_bps cannot exceed {}",
+                MAX_KEYFRAME_SURCHARGE_BPS
+            )));
+        }
+        if self.moderators.len() > MAX_MODERATORS {
+            return Err(ConfigError::InvalidConfig(format!(
+                "cannot have more than {} moderators",
+                MAX_MODERATORS
+            )));
+        }
+        for (index, moderator) in self.moderators.iter().enumerate() {
+            if self.moderators[..index].contains(moderator) {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "duplicate moderator: {}",
+                    moderator
+                )));
+            }
+        }
+        if !is_hex_color(&self.default_color) {
+            return Err(ConfigError::InvalidConfig(format!(
+                "invalid default_color format: {}",
+                self.default_color
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_revenue_splits(&self) -> Result<(), ConfigError> {
+        if self.revenue_splits.len() > MAX_REVENUE_SPLITS {
+            return Err(ConfigError::InvalidConfig(format!(
+                "cannot have more than {} revenue splits",
+                MAX_REVENUE_SPLITS
+            )));
+        }
+        let mut total_basis_points = 0u32;
+        for (index, split) in self.revenue_splits.iter().enumerate() {
+            if split.basis_points == 0 {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "revenue split for {} must be greater than zero",
+                    split.recipient
+                )));
+            }
+            if self.revenue_splits[..index]
+                .iter()
+                .any(|other| other.recipient == split.recipient)
+            {
+                return Err(ConfigError::InvalidConfig(format!(
+                    "duplicate revenue split recipient: {}",
+                    split.recipient
+                )));
+            }
+            total_basis_points += u32::from(split.basis_points);
+        }
+        if total_basis_points > u32::from(BASIS_POINTS) {
+            return Err(ConfigError::InvalidConfig(format!(
+                "revenue splits cannot exceed {} basis points",
+                BASIS_POINTS
+            )));
+        }
+        Ok(())
+    }
+}
+
+// Colors are stored as #RRGGBB
+pub fn is_hex_color(color: &str) -> bool {
+    color.starts_with('#') && color.len() == 7 && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
@@ -0,0 +1,34 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+use crate::core::revenue::PaymentDistribution;
+
+/// Cumulative paint revenue in one denom, split the same way payments are
+#[cw_serde]
+pub struct Earnings {
+    pub denom: String,
+    /// Paid to whoever owned the tile at the time of each paint
+    pub owner_amount: Uint128,
+    /// Paid to the collection royalty address
+    pub royalty_amount: Uint128,
+    /// Paid to the configured revenue split recipients
+    #[serde(default)]
+    pub split_amount: Uint128,
+}
+
+impl Earnings {
+    pub fn new(denom: &str) -> Self {
+        Self {
+            denom: denom.to_string(),
+            owner_amount: Uint128::zero(),
+            royalty_amount: Uint128::zero(),
+            split_amount: Uint128::zero(),
+        }
+    }
+
+    pub fn add(&mut self, distribution: &PaymentDistribution) {
+        self.owner_amount += distribution.owner.amount;
+        self.royalty_amount += distribution.royalty.amount;
+        self.split_amount += distribution.split_amount();
+    }
+}
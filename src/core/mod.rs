@@ -1,7 +1,15 @@
 // Core domain modules
+pub mod config;
+pub mod earnings;
+pub mod outbid;
+pub mod painter;
 pub mod pricing;
+pub mod replay;
+pub mod revenue;
 pub mod tile;
 
 // Re-export commonly used types
+pub use config::Config;
 pub use pricing::PriceScaling;
+pub use replay::{ReplayError, TileStateReplayer};
 pub use tile::Tile;
@@ -0,0 +1,84 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+use thiserror::Error;
+
+use crate::defaults::constants::{BASIS_POINTS, MAX_OUTBID_PREMIUM_BPS};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum OutbidError {
+    #[error("Invalid outbid config: {0}")]
+    InvalidOutbidConfig(String),
+}
+
+/// Terms for taking over pixels that have not expired yet
+#[cw_serde]
+pub struct OutbidConfig {
+    /// Charged on top of the remaining value, out of 10,000
+    pub premium_bps: u16,
+    /// Part of the premium refunded to the displaced painter, out of 10,000
+    pub displaced_share_bps: u16,
+}
+
+impl OutbidConfig {
+    pub fn validate(&self) -> Result<(), OutbidError> {
+        if self.premium_bps > MAX_OUTBID_PREMIUM_BPS {
+            return Err(OutbidError::InvalidOutbidConfig(format!(
+                "premium_bps cannot exceed {}",
+                MAX_OUTBID_PREMIUM_BPS
+            )));
+        }
+        if self.displaced_share_bps > BASIS_POINTS {
+            return Err(OutbidError::InvalidOutbidConfig(format!(
+                "displaced_share_bps cannot exceed {}",
+                BASIS_POINTS
+            )));
+        }
+        Ok(())
+    }
+
+    /// Cost of taking a pixel painted at `painted_at` that expires at `expires_at`.
+    /// The remaining value is what was paid, prorated over the time left.
+    pub fn takeover(&self, paid: Uint128, painted_at: u64, expires_at: u64, now: u64) -> Takeover {
+        let remaining_value = if now >= expires_at || expires_at <= painted_at {
+            Uint128::zero()
+        } else {
+            paid.multiply_ratio(expires_at - now, expires_at - painted_at)
+        };
+        let premium = remaining_value.multiply_ratio(self.premium_bps, BASIS_POINTS);
+        Takeover {
+            remaining_value,
+            premium,
+            displaced_refund: remaining_value
+                + premium.multiply_ratio(self.displaced_share_bps, BASIS_POINTS),
+        }
+    }
+}
+
+/// What a single pixel paint cost, kept while outbids are enabled
+#[cw_serde]
+pub struct PixelPayment {
+    /// Native denom or CW20 token address
+    pub denom: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct Takeover {
+    pub remaining_value: Uint128,
+    pub premium: Uint128,
+    /// Unused time plus the displaced painter's share of the premium
+    pub displaced_refund: Uint128,
+}
+
+impl Takeover {
+    /// Owed by the challenger on top of the regular paint price
+    pub fn price(&self) -> Uint128 {
+        self.remaining_value + self.premium
+    }
+
+    /// The premium left after the refund, distributed like a regular paint
+    pub fn retained(&self) -> Uint128 {
+        self.price() - self.displaced_refund
+    }
+}
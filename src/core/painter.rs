@@ -0,0 +1,20 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+/// Lifetime counters for one painting address
+#[cw_serde]
+#[derive(Default)]
+pub struct PainterStats {
+    pub pixels_painted: u64,
+    /// Sum of paid amounts, counted in price units whichever denom or CW20 token was used
+    pub total_spent: Uint128,
+    pub tiles_touched: u32,
+}
+
+/// Counter a leaderboard is ranked by
+#[cw_serde]
+pub enum PainterRanking {
+    PixelsPainted,
+    TotalSpent,
+    TilesTouched,
+}
@@ -22,6 +22,9 @@ pub struct PriceScaling {
     pub hour_12_price: Uint128,
     pub hour_24_price: Uint128,
     pub quadratic_base: Uint128,
+    /// Charged per byte of a pixel annotation, zero makes annotations free
+    #[serde(default)]
+    pub annotation_byte_price: Uint128,
 }
 
 impl Default for PriceScaling {
@@ -31,6 +34,7 @@ impl Default for PriceScaling {
             hour_12_price: Uint128::from(DEFAULT_PRICE_12_HOURS),
             hour_24_price: Uint128::from(DEFAULT_PRICE_24_HOURS),
             quadratic_base: Uint128::from(DEFAULT_PRICE_QUADRATIC_BASE),
+            annotation_byte_price: Uint128::zero(),
         }
     }
 }
@@ -97,6 +101,10 @@ impl PriceScaling {
         }
     }
 
+    pub fn annotation_price(&self, bytes: usize) -> Uint128 {
+        self.annotation_byte_price * Uint128::from(bytes as u128)
+    }
+
     pub fn calculate_total_price<'a>(&self, durations: impl Iterator<Item = &'a u64>) -> Uint128 {
         durations
             .map(|duration| self.calculate_price(*duration))
@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Addr, Event, Uint128};
+use thiserror::Error;
+
+use crate::{
+    core::{
+        config::Config,
+        pricing::PriceScaling,
+        tile::metadata::{PixelData, TileMetadata},
+    },
+    events::{
+        AnnotationClearEventData, BaseLayerUpdateEventData, ConfigUpdateEventData, EventData,
+        EventType, InstantiatePriceScalingEventData, MetadataUpdateEventData,
+        MintMetadataEventData, PixelUpdateEventData, PriceScalingUpdateEventData,
+        TileBurnEventData, TilePolicyUpdateEventData, TileTransferEventData,
+    },
+};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ReplayError {
+    #[error("Tile hash mismatch for token {token_id}: event reports {expected}, replayed state hashes to {actual}")]
+    HashMismatch {
+        token_id: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Unknown token {token_id}: event received before mint")]
+    UnknownToken { token_id: String },
+
+    #[error("Duplicate mint for token {token_id}")]
+    DuplicateMint { token_id: String },
+
+    #[error("Invalid pixel ID {id} for token {token_id}")]
+    InvalidPixelId { token_id: String, id: u32 },
+
+    #[error("Malformed {event_type} event")]
+    MalformedEvent { event_type: String },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayedTile {
+    pub owner: Addr,
+    pub metadata: TileMetadata,
+    pub tile_hash: String,
+    /// Set once the tile is frozen, it keeps rendering as it looked then
+    pub frozen_at: Option<u64>,
+}
+
+/// Rebuilds every tile's metadata from the ordered stream of events emitted by the
+/// tiles contract, verifying each reported `tile_hash` against the replayed state.
+#[derive(Clone, Debug, Default)]
+pub struct TileStateReplayer {
+    contract_address: Option<String>,
+    config: Option<Config>,
+    price_scaling: Option<PriceScaling>,
+    tiles: BTreeMap<String, ReplayedTile>,
+}
+
+impl TileStateReplayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only consider events emitted by the given contract address
+    pub fn for_contract(contract_address: impl Into<String>) -> Self {
+        Self {
+            contract_address: Some(contract_address.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn config(&self) -> Option<&Config> {
+        self.config.as_ref()
+    }
+
+    pub fn price_scaling(&self) -> Option<&PriceScaling> {
+        self.price_scaling.as_ref()
+    }
+
+    pub fn tile(&self, token_id: &str) -> Option<&ReplayedTile> {
+        self.tiles.get(token_id)
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = (&String, &ReplayedTile)> {
+        self.tiles.iter()
+    }
+
+    pub fn into_tiles(self) -> BTreeMap<String, ReplayedTile> {
+        self.tiles
+    }
+
+    pub fn replay<'a>(
+        &mut self,
+        events: impl IntoIterator<Item = &'a Event>,
+    ) -> Result<(), ReplayError> {
+        for event in events {
+            self.apply(event)?;
+        }
+        Ok(())
+    }
+
+    pub fn apply(&mut self, event: &Event) -> Result<(), ReplayError> {
+        if !self.is_from_contract(event) {
+            return Ok(());
+        }
+
+        if event.ty == EventType::MintMetadataEvent.as_wasm_str() {
+            self.apply_mint(parse::<MintMetadataEventData>(event)?)
+        } else if event.ty == EventType::PixelUpdateEvent.as_wasm_str() {
+            self.apply_pixel_update(parse::<PixelUpdateEventData>(event)?)
+        } else if event.ty == EventType::BaseLayerUpdateEvent.as_wasm_str() {
+            self.apply_base_layer_update(parse::<BaseLayerUpdateEventData>(event)?)
+        } else if event.ty == EventType::AnnotationClearEvent.as_wasm_str() {
+            self.apply_annotation_clear(parse::<AnnotationClearEventData>(event)?)
+        } else if event.ty == EventType::MetadataUpdateEvent.as_wasm_str() {
+            let data = parse::<MetadataUpdateEventData>(event)?;
+            let tile = self.tile_mut(&data.token_id)?;
+            verify_hash(&data.token_id, &data.resulting_hash, &tile.tile_hash)
+        } else if event.ty == EventType::TileTransferEvent.as_wasm_str() {
+            let data = parse::<TileTransferEventData>(event)?;
+            let tile = self.tile_mut(&data.token_id)?;
+            verify_hash(&data.token_id, &data.tile_hash, &tile.tile_hash)?;
+            tile.owner = data.new_owner;
+            Ok(())
+        } else if event.ty == EventType::TileBurnEvent.as_wasm_str() {
+            let data = parse::<TileBurnEventData>(event)?;
+            let tile = self.tile_mut(&data.token_id)?;
+            verify_hash(&data.token_id, &data.tile_hash, &tile.tile_hash)?;
+            self.tiles.remove(&data.token_id);
+            Ok(())
+        } else if event.ty == EventType::TilePolicyUpdateEvent.as_wasm_str() {
+            let data = parse::<TilePolicyUpdateEventData>(event)?;
+            self.tile_mut(&data.token_id)?.frozen_at = data.frozen_at;
+            Ok(())
+        } else if event.ty == EventType::PriceScalingUpdateEvent.as_wasm_str() {
+            let data = parse::<PriceScalingUpdateEventData>(event)?;
+            self.price_scaling = Some(PriceScaling {
+                hour_1_price: Uint128::from(data.hour_1_price),
+                hour_12_price: Uint128::from(data.hour_12_price),
+                hour_24_price: Uint128::from(data.hour_24_price),
+                quadratic_base: Uint128::from(data.quadratic_base),
+                annotation_byte_price: Uint128::from(data.annotation_byte_price),
+            });
+            Ok(())
+        } else if event.ty == EventType::InstantiatePriceScalingEvent.as_wasm_str() {
+            let data = parse::<InstantiatePriceScalingEventData>(event)?;
+            let malformed = |_| ReplayError::MalformedEvent {
+                event_type: event.ty.clone(),
+            };
+            self.price_scaling =
+                Some(serde_json::from_str(&data.price_scaling).map_err(malformed)?);
+            self.config = Some(serde_json::from_str(&data.config).map_err(malformed)?);
+            Ok(())
+        } else if event.ty == EventType::ConfigUpdateEvent.as_wasm_str() {
+            self.config = Some(parse::<ConfigUpdateEventData>(event)?.config);
+            Ok(())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn is_from_contract(&self, event: &Event) -> bool {
+        match &self.contract_address {
+            Some(address) => event
+                .attributes
+                .iter()
+                .any(|a| a.key == "_contract_address" && &a.value == address),
+            None => true,
+        }
+    }
+
+    fn apply_mint(&mut self, data: MintMetadataEventData) -> Result<(), ReplayError> {
+        if self.tiles.contains_key(&data.token_id) {
+            return Err(ReplayError::DuplicateMint {
+                token_id: data.token_id,
+            });
+        }
+
+        let metadata = TileMetadata {
+            pixels: data.new_pixels,
+            base_layer: vec![],
+        };
+        let actual = metadata.hash();
+        verify_hash(&data.token_id, &data.tile_hash, &actual)?;
+
+        self.tiles.insert(
+            data.token_id,
+            ReplayedTile {
+                owner: data.owner,
+                metadata,
+                tile_hash: actual,
+                frozen_at: None,
+            },
+        );
+        Ok(())
+    }
+
+    fn apply_pixel_update(&mut self, data: PixelUpdateEventData) -> Result<(), ReplayError> {
+        let tile = self.tile_mut(&data.token_id)?;
+
+        // Work on a copy so a rejected event leaves the tile as it was
+        let mut metadata = tile.metadata.clone();
+        for pixel in data.new_pixels {
+            let current = match metadata.pixels.get_mut(pixel.id as usize) {
+                Some(current) => current,
+                None => {
+                    return Err(ReplayError::InvalidPixelId {
+                        token_id: data.token_id,
+                        id: pixel.id,
+                    })
+                }
+            };
+            // Stored pixels keep the id they were minted with, as in `TileMetadata::apply_updates`
+            *current = PixelData {
+                id: current.id,
+                ..pixel
+            };
+        }
+
+        commit_metadata(tile, &data.token_id, &data.tile_hash, metadata)
+    }
+
+    fn apply_base_layer_update(
+        &mut self,
+        data: BaseLayerUpdateEventData,
+    ) -> Result<(), ReplayError> {
+        let tile = self.tile_mut(&data.token_id)?;
+
+        if let Some(pixel) = data
+            .pixels
+            .iter()
+            .find(|pixel| pixel.id as usize >= tile.metadata.pixels.len())
+        {
+            return Err(ReplayError::InvalidPixelId {
+                token_id: data.token_id,
+                id: pixel.id,
+            });
+        }
+        let mut metadata = tile.metadata.clone();
+        metadata.apply_base_pixels(&data.pixels);
+
+        commit_metadata(tile, &data.token_id, &data.tile_hash, metadata)
+    }
+
+    fn apply_annotation_clear(
+        &mut self,
+        data: AnnotationClearEventData,
+    ) -> Result<(), ReplayError> {
+        let tile = self.tile_mut(&data.token_id)?;
+
+        if let Some(id) = data
+            .pixel_ids
+            .iter()
+            .find(|id| **id as usize >= tile.metadata.pixels.len())
+        {
+            return Err(ReplayError::InvalidPixelId {
+                token_id: data.token_id,
+                id: *id,
+            });
+        }
+        let mut metadata = tile.metadata.clone();
+        metadata.clear_annotations(&data.pixel_ids);
+
+        commit_metadata(tile, &data.token_id, &data.tile_hash, metadata)
+    }
+
+    fn tile_mut(&mut self, token_id: &str) -> Result<&mut ReplayedTile, ReplayError> {
+        self.tiles
+            .get_mut(token_id)
+            .ok_or_else(|| ReplayError::UnknownToken {
+                token_id: token_id.to_string(),
+            })
+    }
+}
+
+fn parse<T: EventData>(event: &Event) -> Result<T, ReplayError> {
+    T::try_from_event(event).ok_or_else(|| ReplayError::MalformedEvent {
+        event_type: event.ty.clone(),
+    })
+}
+
+/// Replaces the tile's metadata once it is known to hash to what the event reported
+fn commit_metadata(
+    tile: &mut ReplayedTile,
+    token_id: &str,
+    expected: &str,
+    metadata: TileMetadata,
+) -> Result<(), ReplayError> {
+    let actual = metadata.hash();
+    verify_hash(token_id, expected, &actual)?;
+    tile.metadata = metadata;
+    tile.tile_hash = actual;
+    Ok(())
+}
+
+fn verify_hash(token_id: &str, expected: &str, actual: &str) -> Result<(), ReplayError> {
+    if expected != actual {
+        return Err(ReplayError::HashMismatch {
+            token_id: token_id.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        });
+    }
+    Ok(())
+}
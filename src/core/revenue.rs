@@ -0,0 +1,74 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+
+use crate::defaults::constants::BASIS_POINTS;
+
+/// Extra recipient of every paint, paid out of what would otherwise go to the owner
+#[cw_serde]
+pub struct RevenueSplit {
+    pub recipient: Addr,
+    /// Share of the post-royalty amount, out of 10,000
+    pub basis_points: u16,
+}
+
+/// One recipient's cut of a paint payment
+#[cw_serde]
+pub struct Payout {
+    pub recipient: Addr,
+    pub amount: Uint128,
+}
+
+/// How a single paint payment is divided
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentDistribution {
+    pub royalty: Payout,
+    pub splits: Vec<Payout>,
+    pub owner: Payout,
+}
+
+impl PaymentDistribution {
+    /// The royalty share comes off the top and each split takes its basis points of
+    /// what is left. Every cut rounds down, so the owner receives the remainder.
+    pub fn new(
+        total: Uint128,
+        royalty_share: Decimal,
+        royalty_address: Addr,
+        owner: Addr,
+        splits: &[RevenueSplit],
+    ) -> Self {
+        let royalty_amount = total * royalty_share;
+        let after_royalty = total - royalty_amount;
+        let splits: Vec<Payout> = splits
+            .iter()
+            .map(|split| Payout {
+                recipient: split.recipient.clone(),
+                amount: after_royalty.multiply_ratio(split.basis_points, BASIS_POINTS),
+            })
+            .collect();
+        let split_amount: Uint128 = splits.iter().map(|payout| payout.amount).sum();
+        Self {
+            royalty: Payout {
+                recipient: royalty_address,
+                amount: royalty_amount,
+            },
+            owner: Payout {
+                recipient: owner,
+                amount: after_royalty - split_amount,
+            },
+            splits,
+        }
+    }
+
+    pub fn split_amount(&self) -> Uint128 {
+        self.splits.iter().map(|payout| payout.amount).sum()
+    }
+
+    /// Every payout in a fixed order: royalty, splits as configured, then the owner
+    pub fn payouts(&self) -> Vec<Payout> {
+        let mut payouts = Vec::with_capacity(self.splits.len() + 2);
+        payouts.push(self.royalty.clone());
+        payouts.extend(self.splits.iter().cloned());
+        payouts.push(self.owner.clone());
+        payouts
+    }
+}
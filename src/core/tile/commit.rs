@@ -0,0 +1,99 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin};
+use sha2::{Digest, Sha256};
+
+use super::{
+    metadata::{encode_annotation, encode_keyframes, PixelUpdate},
+    policy::TilePolicy,
+};
+
+/// Paint hidden behind a commitment, holding its payment until revealed
+#[cw_serde]
+pub struct PaintCommit {
+    /// Hex SHA-256 from `paint_commitment`
+    pub commitment: String,
+    pub funds: Coin,
+    /// Block height the commit landed in
+    pub height: u64,
+    /// Pixels reserved for the reveal, only their colors stay hidden
+    pub pixel_ids: Vec<u32>,
+    /// Owner and policy at commit time. Forfeits only go to an owner who left
+    /// the tile as it was, anything else may have kept the paint from landing.
+    pub owner: Addr,
+    pub policy: TilePolicy,
+}
+
+impl PaintCommit {
+    /// Last block the paint can be revealed in
+    pub fn reveal_until(&self, window: u64) -> u64 {
+        self.height + window
+    }
+
+    /// Reveals have to land in a later block than the commit, so the design
+    /// never shares a block with the payment it claims
+    pub fn can_reveal(&self, height: u64, window: u64) -> bool {
+        height > self.height && height <= self.reveal_until(window)
+    }
+
+    pub fn is_expired(&self, height: u64, window: u64) -> bool {
+        height > self.reveal_until(window)
+    }
+}
+
+/// Hold a paint commit has on a pixel, other painters are kept off it until
+/// the reveal window closes
+#[cw_serde]
+pub struct PixelReservation {
+    pub committer: Addr,
+    /// Last block of the committer's reveal window
+    pub until: u64,
+}
+
+impl PixelReservation {
+    /// Whether `painter` is kept off the pixel at `height`
+    pub fn blocks(&self, painter: &Addr, height: u64) -> bool {
+        self.committer != *painter && height <= self.until
+    }
+}
+
+/// Commitment to `updates` on a tile. Pixels are encoded the way the tile hash
+/// encodes them, after the token and committer so commitments cannot be
+/// replayed by others. Every field is length prefixed, so no two reveals
+/// share an encoding.
+pub fn paint_commitment(
+    committer: &Addr,
+    token_id: &str,
+    updates: &[PixelUpdate],
+    salt: &str,
+) -> String {
+    let mut hasher = Sha256::new();
+    let mut field = |bytes: &[u8]| {
+        hasher.update((bytes.len() as u64).to_be_bytes());
+        hasher.update(bytes);
+    };
+    field(token_id.as_bytes());
+    field(committer.as_bytes());
+    for update in updates {
+        field(
+            format!(
+                "{}:{}:{}{}{}",
+                update.id,
+                update.color,
+                update.expiration_duration,
+                encode_keyframes(&update.keyframes),
+                encode_annotation(&update.annotation)
+            )
+            .as_bytes(),
+        );
+    }
+    field(salt.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Whether `commitment` looks like a hex SHA-256 digest
+pub fn is_commitment(commitment: &str) -> bool {
+    commitment.len() == 64
+        && commitment
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
@@ -0,0 +1,32 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::BlockInfo;
+use cw721::Expiration;
+
+/// Right to paint a tile as if owning it, given out by the owner
+#[cw_serde]
+pub struct PainterGrant {
+    pub expires: Expiration,
+    /// Pixels the grant covers in total, unlimited when unset
+    pub max_pixels: Option<u32>,
+    pub pixels_painted: u32,
+}
+
+impl PainterGrant {
+    pub fn new(expires: Option<Expiration>, max_pixels: Option<u32>) -> Self {
+        Self {
+            expires: expires.unwrap_or_default(),
+            max_pixels,
+            pixels_painted: 0,
+        }
+    }
+
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expires.is_expired(block)
+    }
+
+    /// Whether `pixels` more pixels fit in the grant's allowance
+    pub fn covers(&self, pixels: u32) -> bool {
+        self.max_pixels
+            .is_none_or(|max| self.pixels_painted.saturating_add(pixels) <= max)
+    }
+}
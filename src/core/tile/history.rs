@@ -0,0 +1,17 @@
+use cosmwasm_schema::cw_serde;
+
+use crate::core::tile::metadata::PixelData;
+
+/// A past paint of a single pixel, `seq` counts the paints of that pixel from zero
+#[cw_serde]
+pub struct PixelHistoryEntry {
+    pub seq: u64,
+    pub pixel: PixelData,
+}
+
+impl PixelHistoryEntry {
+    /// Ring buffer slot holding this entry for the given depth
+    pub fn slot(seq: u64, depth: u32) -> u32 {
+        (seq % depth as u64) as u32
+    }
+}
@@ -0,0 +1,113 @@
+use cosmwasm_schema::cw_serde;
+use std::iter::once;
+
+use crate::{
+    contract::error::ContractError,
+    core::tile::metadata::{PixelUpdate, TileMetadata},
+};
+
+/// Encoding of a `PaintImage` bitmap. Pixels are listed row by row from the
+/// top left, in pixel id order.
+#[cw_serde]
+pub enum ImageFormat {
+    /// Three bytes per pixel: red, green, blue
+    Rgb,
+    /// Runs of four bytes: a repeat count from 1 to 255, then red, green, blue
+    RgbRle,
+}
+
+impl ImageFormat {
+    /// Color of every pixel of a tile with `pixels` pixels, as `#RRGGBB`
+    pub fn decode(&self, data: &[u8], pixels: u32) -> Result<Vec<String>, ContractError> {
+        let pixels = pixels as usize;
+        let invalid = |reason: String| ContractError::InvalidImage { reason };
+        match self {
+            ImageFormat::Rgb => {
+                if data.len() != pixels * 3 {
+                    return Err(invalid(format!(
+                        "expected {} bytes for {} pixels, got {}",
+                        pixels * 3,
+                        pixels,
+                        data.len()
+                    )));
+                }
+                Ok(data.chunks_exact(3).map(hex_color).collect())
+            }
+            ImageFormat::RgbRle => {
+                if !data.len().is_multiple_of(4) {
+                    return Err(invalid("runs must be 4 bytes long".to_string()));
+                }
+                let mut colors = Vec::with_capacity(pixels);
+                for run in data.chunks_exact(4) {
+                    let count = run[0] as usize;
+                    if count == 0 {
+                        return Err(invalid("runs cannot be empty".to_string()));
+                    }
+                    // Checked per run so oversized payloads are never expanded
+                    if colors.len() + count > pixels {
+                        return Err(invalid(format!("runs cover more than {} pixels", pixels)));
+                    }
+                    colors.extend(std::iter::repeat_n(hex_color(&run[1..]), count));
+                }
+                if colors.len() != pixels {
+                    return Err(invalid(format!(
+                        "runs cover {} of {} pixels",
+                        colors.len(),
+                        pixels
+                    )));
+                }
+                Ok(colors)
+            }
+        }
+    }
+}
+
+fn hex_color(rgb: &[u8]) -> String {
+    format!("#{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2])
+}
+
+impl TileMetadata {
+    /// Updates painting `colors` over the tile for `duration` seconds. Pixels
+    /// that keep showing their color for the whole duration are left out, so
+    /// one about to expire or change keyframe is painted again.
+    pub fn image_updates(
+        &self,
+        colors: &[String],
+        default_color: &str,
+        current_time: u64,
+        duration: u64,
+    ) -> Vec<PixelUpdate> {
+        let until = current_time.saturating_add(duration);
+        colors
+            .iter()
+            .enumerate()
+            .filter(|(index, color)| {
+                !self.shows_color(*index, color, default_color, current_time, until)
+            })
+            .map(|(index, color)| PixelUpdate::new(index as u32, color.clone(), duration))
+            .collect()
+    }
+
+    /// Whether pixel `index` shows `color` from `from` until `until`. Its color
+    /// can only change when a keyframe starts or the paint expires.
+    fn shows_color(
+        &self,
+        index: usize,
+        color: &str,
+        default_color: &str,
+        from: u64,
+        until: u64,
+    ) -> bool {
+        let pixel = &self.pixels[index];
+        let changes = pixel
+            .keyframes
+            .iter()
+            .map(|keyframe| pixel.last_updated_at.saturating_add(keyframe.start_offset))
+            .chain(once(pixel.expiration_timestamp))
+            .filter(|&time| time > from && time < until);
+        once(from).chain(changes).all(|time| {
+            self.pixel_color(index, default_color, time)
+                .eq_ignore_ascii_case(color)
+        })
+    }
+}
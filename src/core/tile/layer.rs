@@ -0,0 +1,53 @@
+use cosmwasm_schema::cw_serde;
+
+use crate::{
+    contract::error::ContractError,
+    core::{
+        config::{is_hex_color, Config},
+        tile::metadata::TileMetadata,
+    },
+};
+
+/// Write to the owner's base layer, `None` clears the pixel
+#[cw_serde]
+pub struct BasePixel {
+    pub id: u32,
+    pub color: Option<String>,
+}
+
+impl BasePixel {
+    pub fn validate(&self, config: &Config) -> Result<(), ContractError> {
+        if self.id >= config.pixels_per_tile() {
+            return Err(ContractError::InvalidPixelId { id: self.id });
+        }
+        match &self.color {
+            Some(color) if !is_hex_color(color) => Err(ContractError::InvalidPixelUpdate {
+                reason: format!("Invalid color format: {}", color),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TileMetadata {
+    /// Writes to the base layer, creating it on first use and dropping it once
+    /// every pixel is cleared. Ids must be within the tile.
+    pub fn apply_base_pixels(&mut self, pixels: &[BasePixel]) {
+        if self.base_layer.is_empty() {
+            self.base_layer = vec![None; self.pixels.len()];
+        }
+        for pixel in pixels {
+            self.base_layer[pixel.id as usize] = pixel.color.clone();
+        }
+        if self.base_layer.iter().all(Option::is_none) {
+            self.base_layer.clear();
+        }
+    }
+
+    /// Owner's base color for a pixel, if the tile has one there
+    pub fn base_color(&self, index: usize) -> Option<&str> {
+        self.base_layer
+            .get(index)
+            .and_then(|color| color.as_deref())
+    }
+}
@@ -1,7 +1,6 @@
 use crate::contract::error::ContractError;
-use crate::defaults::constants::{
-    DEFAULT_COLOR, PIXELS_PER_TILE, PIXEL_MAX_EXPIRATION, PIXEL_MIN_EXPIRATION,
-};
+use crate::core::config::{is_hex_color, Config};
+use crate::defaults::constants::{DEFAULT_COLOR, MAX_ANNOTATION_BYTES, MAX_KEYFRAMES};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
 use sha2::{Digest, Sha256};
@@ -13,6 +12,12 @@ pub struct PixelData {
     pub expiration_timestamp: u64,
     pub last_updated_by: Addr,
     pub last_updated_at: u64,
+    /// Colors the pixel switches to while it is live, `color` shows until the first
+    #[serde(default)]
+    pub keyframes: Vec<Keyframe>,
+    /// Signature or link left by the painter, cleared by the next paint
+    #[serde(default)]
+    pub annotation: Option<String>,
 }
 
 impl Default for PixelData {
@@ -23,24 +28,42 @@ impl Default for PixelData {
             expiration_timestamp: 0,
             last_updated_by: Addr::unchecked(""),
             last_updated_at: 0,
+            keyframes: vec![],
+            annotation: None,
         }
     }
 }
 
 #[cw_serde]
 pub struct TileMetadata {
+    /// Public rental layer, painted by anyone allowed to
     pub pixels: Vec<PixelData>,
+    /// Owner's permanent background, shown wherever no rental paint is live.
+    /// Empty when the owner has not set one, otherwise one entry per pixel.
+    #[serde(default)]
+    pub base_layer: Vec<Option<String>>,
 }
 
 impl Default for TileMetadata {
     fn default() -> Self {
-        Self {
-            pixels: (0..PIXELS_PER_TILE).map(|_| PixelData::default()).collect(),
-        }
+        Self::new(&Config::default())
     }
 }
 
 impl TileMetadata {
+    /// Blank tile sized and colored according to the contract config
+    pub fn new(config: &Config) -> Self {
+        Self {
+            pixels: (0..config.pixels_per_tile())
+                .map(|_| PixelData {
+                    color: config.default_color.clone(),
+                    ..PixelData::default()
+                })
+                .collect(),
+            base_layer: vec![],
+        }
+    }
+
     pub fn apply_updates(&mut self, updates: Vec<PixelUpdate>, sender: &Addr, current_time: u64) {
         // All updates are just modifications of existing pixels
         for update in updates {
@@ -49,67 +72,222 @@ impl TileMetadata {
             pixel.expiration_timestamp = update.get_expiration_timestamp(current_time);
             pixel.last_updated_by = sender.clone();
             pixel.last_updated_at = current_time;
+            pixel.keyframes = update.keyframes;
+            pixel.annotation = update.annotation;
         }
     }
 
+    /// Drops the annotations of the given pixels, ids must be within the tile
+    pub fn clear_annotations(&mut self, pixel_ids: &[u32]) {
+        for id in pixel_ids {
+            self.pixels[*id as usize].annotation = None;
+        }
+    }
+
+    pub fn live_pixels(&self, current_time: u64) -> u32 {
+        self.pixels
+            .iter()
+            .filter(|pixel| pixel.expiration_timestamp > current_time)
+            .count() as u32
+    }
+
     pub fn hash(&self) -> String {
         let mut hasher = Sha256::new();
         for pixel in &self.pixels {
             hasher.update(format!(
-                "{}:{}:{}:{}:{}",
+                "{}:{}:{}:{}:{}{}{}",
                 pixel.id,
                 pixel.color,
                 pixel.expiration_timestamp,
                 pixel.last_updated_by,
-                pixel.last_updated_at
+                pixel.last_updated_at,
+                encode_keyframes(&pixel.keyframes),
+                encode_annotation(&pixel.annotation)
             ));
         }
+        // Only set base pixels are hashed, so tiles without a base layer keep their hash
+        for (id, color) in self.base_layer.iter().enumerate() {
+            if let Some(color) = color {
+                hasher.update(format!("base:{}:{}", id, color));
+            }
+        }
         format!("{:x}", hasher.finalize())
     }
 }
 
+/// Canonical encoding of a keyframe schedule for hashing, empty without keyframes
+/// so hashes of plain pixels are unchanged
+pub fn encode_keyframes(keyframes: &[Keyframe]) -> String {
+    keyframes
+        .iter()
+        .map(|keyframe| format!(";{}@{}", keyframe.start_offset, keyframe.color))
+        .collect()
+}
+
+/// Canonical encoding of an annotation for hashing, length prefixed so free text
+/// cannot pass for the pixels after it. Empty without an annotation.
+pub fn encode_annotation(annotation: &Option<String>) -> String {
+    annotation
+        .as_ref()
+        .map(|text| format!("|{}:{}", text.len(), text))
+        .unwrap_or_default()
+}
+
+/// Unicode general category Cf: bidi overrides, zero-width characters and
+/// other invisible formatting
+fn is_format_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{0600}'..='\u{0605}'
+            | '\u{061C}'
+            | '\u{06DD}'
+            | '\u{070F}'
+            | '\u{0890}'..='\u{0891}'
+            | '\u{08E2}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{206F}'
+            | '\u{FEFF}'
+            | '\u{FFF9}'..='\u{FFFB}'
+            | '\u{110BD}'
+            | '\u{110CD}'
+            | '\u{13430}'..='\u{1343F}'
+            | '\u{1BCA0}'..='\u{1BCA3}'
+            | '\u{1D173}'..='\u{1D17A}'
+            | '\u{E0001}'
+            | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+/// Color shown from `start_offset` seconds after the pixel was painted
+#[cw_serde]
+pub struct Keyframe {
+    pub start_offset: u64,
+    pub color: String,
+}
+
 #[cw_serde]
 pub struct PixelUpdate {
     pub id: u32,
     pub color: String,
     pub expiration_duration: u64, // Duration in seconds
+    /// Scheduled color changes within the paid duration, in order of `start_offset`
+    #[serde(default)]
+    pub keyframes: Vec<Keyframe>,
+    /// Short text or link stored with the pixel, charged per byte
+    #[serde(default)]
+    pub annotation: Option<String>,
 }
 
 impl PixelUpdate {
-    pub fn validate_integrity(&self) -> Result<(), ContractError> {
+    /// A plain color for `expiration_duration` seconds, without keyframes or annotation
+    pub fn new(id: u32, color: impl Into<String>, expiration_duration: u64) -> Self {
+        Self {
+            id,
+            color: color.into(),
+            expiration_duration,
+            keyframes: vec![],
+            annotation: None,
+        }
+    }
+
+    pub fn validate_integrity(&self, config: &Config) -> Result<(), ContractError> {
         // Validate pixel id is within bounds
-        if self.id >= PIXELS_PER_TILE {
+        if self.id >= config.pixels_per_tile() {
             return Err(ContractError::InvalidPixelId { id: self.id });
         }
 
         // Validate color format (#RRGGBB)
-        if !self.color.starts_with('#')
-            || self.color.len() != 7
-            || !self.color[1..].chars().all(|c| c.is_ascii_hexdigit())
-        {
+        if !is_hex_color(&self.color) {
             return Err(ContractError::InvalidPixelUpdate {
                 reason: format!("Invalid color format: {}", self.color),
             });
         }
 
         // Validate duration is within bounds
-        if self.expiration_duration < PIXEL_MIN_EXPIRATION {
+        if self.expiration_duration < config.pixel_min_expiration {
             return Err(ContractError::InvalidPixelUpdate {
                 reason: format!(
                     "Expiration duration {} is less than minimum {}",
-                    self.expiration_duration, PIXEL_MIN_EXPIRATION
+                    self.expiration_duration, config.pixel_min_expiration
                 ),
             });
         }
-        if self.expiration_duration > PIXEL_MAX_EXPIRATION {
+        if self.expiration_duration > config.pixel_max_expiration {
             return Err(ContractError::InvalidPixelUpdate {
                 reason: format!(
                     "Expiration duration {} is greater than maximum {}",
-                    self.expiration_duration, PIXEL_MAX_EXPIRATION
+                    self.expiration_duration, config.pixel_max_expiration
                 ),
             });
         }
 
+        self.validate_keyframes()?;
+        self.validate_annotation()
+    }
+
+    fn validate_annotation(&self) -> Result<(), ContractError> {
+        let annotation = match &self.annotation {
+            Some(annotation) => annotation,
+            None => return Ok(()),
+        };
+        let invalid = |reason: String| ContractError::InvalidPixelUpdate { reason };
+        if annotation.is_empty() || annotation.len() > MAX_ANNOTATION_BYTES {
+            return Err(invalid(format!(
+                "Annotations must be between 1 and {} bytes",
+                MAX_ANNOTATION_BYTES
+            )));
+        }
+        // Annotations end up in events and UIs, so only printable text is stored.
+        // Invisible format characters could reorder or hide what moderators see.
+        if annotation
+            .chars()
+            .any(|c| c.is_control() || is_format_char(c))
+        {
+            return Err(invalid(
+                "Annotations may only contain printable characters".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Bytes charged for by `PriceScaling::annotation_price`
+    pub fn annotation_bytes(&self) -> usize {
+        self.annotation.as_ref().map_or(0, String::len)
+    }
+
+    fn validate_keyframes(&self) -> Result<(), ContractError> {
+        let invalid = |reason: String| ContractError::InvalidPixelUpdate { reason };
+        if self.keyframes.len() > MAX_KEYFRAMES {
+            return Err(invalid(format!(
+                "Pixels cannot have more than {} keyframes",
+                MAX_KEYFRAMES
+            )));
+        }
+        let mut previous_offset = 0;
+        for keyframe in &self.keyframes {
+            if !is_hex_color(&keyframe.color) {
+                return Err(invalid(format!(
+                    "Invalid keyframe color format: {}",
+                    keyframe.color
+                )));
+            }
+            if keyframe.start_offset <= previous_offset {
+                return Err(invalid(
+                    "Keyframe offsets must be positive and increasing".to_string(),
+                ));
+            }
+            if keyframe.start_offset >= self.expiration_duration {
+                return Err(invalid(format!(
+                    "Keyframe offset {} is not within the expiration duration {}",
+                    keyframe.start_offset, self.expiration_duration
+                )));
+            }
+            previous_offset = keyframe.start_offset;
+        }
         Ok(())
     }
 
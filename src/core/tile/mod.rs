@@ -1,9 +1,39 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+pub mod commit;
+pub mod grant;
+pub mod history;
+pub mod image;
+pub mod layer;
 pub mod metadata;
+pub mod nft_metadata;
+pub mod policy;
+pub mod render;
+
+use policy::TilePolicy;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Tile {
     pub tile_hash: String,
+    #[serde(default)]
+    pub policy: TilePolicy,
+    /// When the owner froze the tile, renders stay at this moment from then on
+    #[serde(default)]
+    pub frozen_at: Option<u64>,
+}
+
+impl Tile {
+    pub fn new(tile_hash: String) -> Self {
+        Self {
+            tile_hash,
+            policy: TilePolicy::default(),
+            frozen_at: None,
+        }
+    }
+
+    /// Time to render the tile at, so expiring pixels do not fade from frozen art
+    pub fn render_time(&self, now: u64) -> u64 {
+        self.frozen_at.map_or(now, |frozen_at| frozen_at.min(now))
+    }
 }
@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Binary;
+
+use crate::{
+    core::{config::Config, tile::metadata::TileMetadata},
+    defaults::constants::DEFAULT_SVG_SCALE,
+};
+
+#[cw_serde]
+pub struct Trait {
+    pub display_type: Option<String>,
+    pub trait_type: String,
+    pub value: String,
+}
+
+/// ERC-721 style metadata generated from on-chain tile state
+#[cw_serde]
+pub struct TokenMetadata {
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub attributes: Vec<Trait>,
+    pub original_token_uri: Option<String>,
+}
+
+impl TokenMetadata {
+    pub fn to_data_uri(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        format!(
+            "data:application/json;base64,{}",
+            Binary::from(json).to_base64()
+        )
+    }
+}
+
+impl TileMetadata {
+    pub fn token_metadata(
+        &self,
+        config: &Config,
+        name: String,
+        description: String,
+        original_token_uri: Option<String>,
+        current_time: u64,
+    ) -> TokenMetadata {
+        let svg = self.to_svg(config, current_time, DEFAULT_SVG_SCALE, false);
+        let image = format!(
+            "data:image/svg+xml;base64,{}",
+            Binary::from(svg.as_bytes()).to_base64()
+        );
+
+        let live_pixels: Vec<_> = self
+            .pixels
+            .iter()
+            .filter(|pixel| pixel.expiration_timestamp > current_time)
+            .collect();
+        let live_painters: BTreeSet<_> = live_pixels
+            .iter()
+            .map(|pixel| &pixel.last_updated_by)
+            .collect();
+        let last_updated = self
+            .pixels
+            .iter()
+            .map(|pixel| pixel.last_updated_at)
+            .max()
+            .unwrap_or_default();
+
+        TokenMetadata {
+            name,
+            description,
+            image,
+            attributes: vec![
+                Trait {
+                    display_type: Some("number".to_string()),
+                    trait_type: "Painted Pixels".to_string(),
+                    value: live_pixels.len().to_string(),
+                },
+                Trait {
+                    display_type: Some("number".to_string()),
+                    trait_type: "Live Painters".to_string(),
+                    value: live_painters.len().to_string(),
+                },
+                Trait {
+                    display_type: None,
+                    trait_type: "Dominant Color".to_string(),
+                    value: self.dominant_color(&config.default_color, current_time),
+                },
+                Trait {
+                    display_type: Some("date".to_string()),
+                    trait_type: "Last Updated".to_string(),
+                    value: last_updated.to_string(),
+                },
+            ],
+            original_token_uri,
+        }
+    }
+
+    // Most frequent displayed color, ties broken by the lowest color code.
+    // Colors are uppercased first, `#ff0000` and `#FF0000` are the same red.
+    pub fn dominant_color(&self, default_color: &str, current_time: u64) -> String {
+        let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+        for index in 0..self.pixels.len() {
+            *counts
+                .entry(
+                    self.pixel_color(index, default_color, current_time)
+                        .to_ascii_uppercase(),
+                )
+                .or_default() += 1;
+        }
+
+        counts
+            .into_iter()
+            .fold(
+                None,
+                |best: Option<(String, u32)>, (color, count)| match best {
+                    Some((_, best_count)) if best_count >= count => best,
+                    _ => Some((color, count)),
+                },
+            )
+            .map(|(color, _)| color)
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,79 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal};
+use thiserror::Error;
+
+use crate::{core::config::Config, defaults::constants::MAX_TILE_ALLOWLIST};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum TilePolicyError {
+    #[error("Invalid tile policy: {0}")]
+    InvalidTilePolicy(String),
+}
+
+/// Who may paint a tile, the owner always can
+#[cw_serde]
+#[derive(Default)]
+pub enum PaintAccess {
+    #[default]
+    Anyone,
+    Allowlist(Vec<Addr>),
+    OwnerOnly,
+}
+
+/// Painting rules the owner sets for their tile
+#[cw_serde]
+pub struct TilePolicy {
+    pub access: PaintAccess,
+    /// Applied to the collection price of every paint, bounded by the config
+    pub price_multiplier: Decimal,
+    /// Frozen tiles can never be painted or have their policy changed again
+    pub frozen: bool,
+}
+
+impl Default for TilePolicy {
+    fn default() -> Self {
+        Self {
+            access: PaintAccess::Anyone,
+            price_multiplier: Decimal::one(),
+            frozen: false,
+        }
+    }
+}
+
+impl TilePolicy {
+    pub fn allows(&self, painter: &Addr, owner: &Addr) -> bool {
+        match &self.access {
+            PaintAccess::Anyone => true,
+            PaintAccess::Allowlist(allowed) => painter == owner || allowed.contains(painter),
+            PaintAccess::OwnerOnly => painter == owner,
+        }
+    }
+
+    pub fn validate(&self, config: &Config) -> Result<(), TilePolicyError> {
+        if self.price_multiplier < config.min_price_multiplier
+            || self.price_multiplier > config.max_price_multiplier
+        {
+            return Err(TilePolicyError::InvalidTilePolicy(format!(
+                "price_multiplier must be between {} and {}",
+                config.min_price_multiplier, config.max_price_multiplier
+            )));
+        }
+        if let PaintAccess::Allowlist(allowed) = &self.access {
+            if allowed.len() > MAX_TILE_ALLOWLIST {
+                return Err(TilePolicyError::InvalidTilePolicy(format!(
+                    "allowlist cannot have more than {} addresses",
+                    MAX_TILE_ALLOWLIST
+                )));
+            }
+            for (index, address) in allowed.iter().enumerate() {
+                if allowed[..index].contains(address) {
+                    return Err(TilePolicyError::InvalidTilePolicy(format!(
+                        "duplicate allowlist address: {}",
+                        address
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
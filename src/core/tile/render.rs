@@ -0,0 +1,86 @@
+use std::fmt::Write;
+
+use crate::core::{
+    config::Config,
+    tile::metadata::{PixelData, TileMetadata},
+};
+
+const GRID_COLOR: &str = "#000000";
+
+impl PixelData {
+    // Expired or never painted pixels fall back to the default color, live ones
+    // show their latest keyframe that has started
+    pub fn effective_color<'a>(&'a self, default_color: &'a str, current_time: u64) -> &'a str {
+        if self.expiration_timestamp <= current_time {
+            return default_color;
+        }
+        let elapsed = current_time.saturating_sub(self.last_updated_at);
+        self.keyframes
+            .iter()
+            .rev()
+            .find(|keyframe| keyframe.start_offset <= elapsed)
+            .map_or(&self.color, |keyframe| &keyframe.color)
+    }
+}
+
+impl TileMetadata {
+    /// Composited color of a pixel: live rental paint on top of the owner's
+    /// base layer, on top of the default color
+    pub fn pixel_color<'a>(
+        &'a self,
+        index: usize,
+        default_color: &'a str,
+        current_time: u64,
+    ) -> &'a str {
+        let background = self.base_color(index).unwrap_or(default_color);
+        self.pixels[index].effective_color(background, current_time)
+    }
+
+    /// Renders the tile as an SVG document, one `scale`x`scale` square per pixel.
+    pub fn to_svg(
+        &self,
+        config: &Config,
+        current_time: u64,
+        scale: u32,
+        grid_lines: bool,
+    ) -> String {
+        let (width, height) = (config.tile_width, config.tile_height);
+        let mut svg = String::new();
+
+        write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#,
+            width * scale,
+            height * scale
+        )
+        .unwrap();
+
+        for index in 0..self.pixels.len() {
+            let (x, y) = config.pixel_position(index as u32);
+            write!(
+                svg,
+                r#"<rect x="{x}" y="{y}" width="1" height="1" fill="{}"/>"#,
+                self.pixel_color(index, &config.default_color, current_time)
+            )
+            .unwrap();
+        }
+
+        if grid_lines {
+            let mut path = String::new();
+            for column in 0..=width {
+                write!(path, "M{column} 0V{height}").unwrap();
+            }
+            for row in 0..=height {
+                write!(path, "M0 {row}H{width}").unwrap();
+            }
+            write!(
+                svg,
+                r#"<path d="{path}" stroke="{GRID_COLOR}" stroke-width="1" vector-effect="non-scaling-stroke" fill="none"/>"#
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
@@ -1,10 +1,33 @@
 // Protocol constants that should never change
-pub const PIXELS_PER_TILE: u32 = 100;
-pub const TILE_SIZE: u32 = 10; // 10x10 grid
+pub const DEFAULT_TILE_WIDTH: u32 = 10; // 10x10 grid unless configured at instantiate
+pub const DEFAULT_TILE_HEIGHT: u32 = 10;
+pub const MAX_TILE_DIMENSION: u32 = 64;
+pub const MAX_PIXELS_PER_TILE: u32 = 1024; // Keeps a full tile within storage and event limits
 pub const DEFAULT_COLOR: &str = "#FFFFFF"; // Default white color
 pub const PIXEL_MIN_EXPIRATION: u64 = 3600; // 1 hour
 pub const PIXEL_MAX_EXPIRATION: u64 = 86400; // 24 hours
 pub const DEFAULT_ROYALTY_SHARE: u64 = 10; // 10% royalty share
+pub const DEFAULT_PIXEL_HISTORY_DEPTH: u32 = 0; // History is opt-in per collection
+pub const MAX_PIXEL_HISTORY_DEPTH: u32 = 16; // Bounds storage per pixel and query cost per tile
+pub const DEFAULT_HISTORY_LIMIT: u32 = 30;
+pub const MAX_HISTORY_LIMIT: u32 = 100;
+pub const DEFAULT_LEADERBOARD_LIMIT: u32 = 10;
+pub const MAX_LEADERBOARD_LIMIT: u32 = 30;
+pub const BASIS_POINTS: u16 = 10_000;
+pub const MAX_REVENUE_SPLITS: usize = 8; // Each split adds a transfer to every paint
+pub const MAX_TILE_ALLOWLIST: usize = 50;
+pub const MAX_OUTBID_PREMIUM_BPS: u16 = 50_000; // 5x the remaining value
+pub const DEFAULT_REVEAL_WINDOW_BLOCKS: u64 = 20; // About two minutes of blocks to reveal a paint
+pub const MAX_REVEAL_WINDOW_BLOCKS: u64 = 14_400; // About a day
+pub const MAX_KEYFRAMES: usize = 16; // Per pixel update
+pub const DEFAULT_KEYFRAME_SURCHARGE_BPS: u16 = 1_000; // Each keyframe adds 10% to the pixel price
+pub const MAX_KEYFRAME_SURCHARGE_BPS: u16 = 10_000;
+pub const MAX_ANNOTATION_BYTES: usize = 64; // Enough for a signature or a short link
+pub const MAX_MODERATORS: usize = 10;
+pub const MAX_PAINTER_GRANTS: usize = 20; // Per tile, so grants can be listed and cleared in one go
+pub const DEFAULT_MIN_PRICE_MULTIPLIER_PERCENT: u64 = 100; // Owners cannot reprice tiles unless
+pub const DEFAULT_MAX_PRICE_MULTIPLIER_PERCENT: u64 = 100; // the collection widens the bounds
+pub const MAX_PRICE_MULTIPLIER_PERCENT: u64 = 10_000; // 100x
 
 // Time thresholds for pricing (in seconds)
 pub const ONE_HOUR: u64 = 3600;
@@ -24,36 +47,6 @@ pub const DEFAULT_PRICE_QUADRATIC_BASE: u128 = 400_000; // 0.4 STARS
 pub const CONTRACT_NAME: &str = "crates.io:tiles";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-// Minting price values (in uSTARS)
-pub const MINT_PRICE: u128 = 100_000_000; // 100 STARS
-pub const CREATION_FEE: u128 = 1_000_000; // 1 STARS
-
-// Vending minter constants
-pub const MINT_FEE_BPS: u64 = 1000; // 10%
-pub const MAX_TOKEN_LIMIT: u32 = 10000;
-pub const MAX_PER_ADDRESS_LIMIT: u32 = 3;
-pub const MAX_TRADING_OFFSET_SECS: u64 = 60 * 60 * 24 * 7; // 1 week
-pub const MIN_MINT_PRICE: u128 = 0;
-pub const AIRDROP_MINT_PRICE: u128 = 0;
-pub const AIRDROP_MINT_FEE_BPS: u64 = 0;
-pub const SHUFFLE_FEE: u128 = 0;
-
-// Chain configuration
-pub const CHAIN_ID: &str = "elgafar-1";
-pub const NODE_URL: &str = "https://rpc.elgafar-1.stargaze-apis.com:443";
-pub const GAS_PRICE: &str = "0.025";
-pub const GAS_ADJUSTMENT: f64 = 1.3;
-pub const BROADCAST_MODE: &str = "sync";
-
-// Collection configuration
-pub const COLLECTION_NAME: &str = "Tiles";
-pub const COLLECTION_SYMBOL: &str = "TILE";
-pub const COLLECTION_DESCRIPTION: &str = "A collaborative pixel art canvas on Stargaze";
-pub const BASE_TOKEN_URI: &str =
-    "ipfs://bafybeidrmkt5uzfpz66esvhk3qflp47reztskaijxlsfu4fysujxicw7mu";
-pub const COLLECTION_URI: &str = "ipfs://QmXzzVdLPNZCG1RnCSxDCSu9TfFd7fpnnwt8GuXdjNkjZw";
-
-// Start time configuration
-pub const START_TIME: &str = "1625097600"; // Example timestamp, should be set appropriately
-
-pub const DEPLOYER_ADDRESS: &str = "stars1pnet2e7tz7klwy48r7h3wl0n97td0haqjvs7mx";
+// SVG rendering
+pub const DEFAULT_SVG_SCALE: u32 = 10; // Output pixels per tile pixel
+pub const MAX_SVG_SCALE: u32 = 100;
@@ -0,0 +1,52 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+
+/// A moderator removed the annotations of some pixels
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AnnotationClearEventData {
+    pub token_id: String,
+    pub moderator: Addr,
+    pub pixel_ids: Vec<u32>,
+    pub tile_hash: String,
+}
+
+impl EventData for AnnotationClearEventData {
+    fn event_type() -> EventType {
+        EventType::AnnotationClearEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str())
+            .add_attribute("token_id", self.token_id)
+            .add_attribute("moderator", self.moderator.to_string())
+            .add_attribute(
+                "pixel_ids",
+                serde_json::to_string(&self.pixel_ids).unwrap_or_default(),
+            )
+            .add_attribute("tile_hash", self.tile_hash)
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            moderator: Addr::unchecked(get_attr("moderator")?),
+            pixel_ids: serde_json::from_str(&get_attr("pixel_ids")?).ok()?,
+            tile_hash: get_attr("tile_hash")?,
+        })
+    }
+}
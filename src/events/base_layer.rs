@@ -0,0 +1,53 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+use crate::core::tile::layer::BasePixel;
+
+/// The owner wrote to the base layer of a tile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BaseLayerUpdateEventData {
+    pub token_id: String,
+    pub owner: Addr,
+    pub pixels: Vec<BasePixel>,
+    pub tile_hash: String,
+}
+
+impl EventData for BaseLayerUpdateEventData {
+    fn event_type() -> EventType {
+        EventType::BaseLayerUpdateEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str())
+            .add_attribute("token_id", self.token_id)
+            .add_attribute("owner", self.owner.to_string())
+            .add_attribute(
+                "pixels",
+                serde_json::to_string(&self.pixels).unwrap_or_default(),
+            )
+            .add_attribute("tile_hash", self.tile_hash)
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            owner: Addr::unchecked(get_attr("owner")?),
+            pixels: serde_json::from_str(&get_attr("pixels")?).ok()?,
+            tile_hash: get_attr("tile_hash")?,
+        })
+    }
+}
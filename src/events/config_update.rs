@@ -0,0 +1,41 @@
+use cosmwasm_std::Event;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+use crate::core::config::Config;
+
+/// The tiles config was replaced with `UpdateConfig`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ConfigUpdateEventData {
+    pub config: Config,
+}
+
+impl EventData for ConfigUpdateEventData {
+    fn event_type() -> EventType {
+        EventType::ConfigUpdateEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str()).add_attribute(
+            "config",
+            serde_json::to_string(&self.config).unwrap_or_default(),
+        )
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let config = event
+            .attributes
+            .iter()
+            .find(|a| a.key == "config")
+            .map(|a| a.value.clone())?;
+
+        Some(Self {
+            config: serde_json::from_str(&config).ok()?,
+        })
+    }
+}
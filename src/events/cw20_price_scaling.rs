@@ -0,0 +1,74 @@
+use cosmwasm_std::{Addr, Event, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+use crate::core::pricing::PriceScaling;
+
+/// A CW20 token was whitelisted or repriced, or removed when `price_scaling` is `None`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Cw20PriceScalingUpdateEventData {
+    pub token: Addr,
+    pub price_scaling: Option<PriceScaling>,
+}
+
+impl EventData for Cw20PriceScalingUpdateEventData {
+    fn event_type() -> EventType {
+        EventType::Cw20PriceScalingUpdateEvent
+    }
+
+    fn into_event(self) -> Event {
+        let event = Event::new(Self::event_type().as_str())
+            .add_attribute("token", self.token.to_string())
+            .add_attribute("whitelisted", self.price_scaling.is_some().to_string());
+        match self.price_scaling {
+            Some(price_scaling) => event
+                .add_attribute("hour_1_price", price_scaling.hour_1_price.to_string())
+                .add_attribute("hour_12_price", price_scaling.hour_12_price.to_string())
+                .add_attribute("hour_24_price", price_scaling.hour_24_price.to_string())
+                .add_attribute("quadratic_base", price_scaling.quadratic_base.to_string())
+                .add_attribute(
+                    "annotation_byte_price",
+                    price_scaling.annotation_byte_price.to_string(),
+                ),
+            None => event,
+        }
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+        let get_amount = |key: &str| -> Option<Uint128> { get_attr(key)?.parse().ok() };
+
+        let whitelisted: bool = get_attr("whitelisted")?.parse().ok()?;
+        let price_scaling = if whitelisted {
+            Some(PriceScaling {
+                hour_1_price: get_amount("hour_1_price")?,
+                hour_12_price: get_amount("hour_12_price")?,
+                hour_24_price: get_amount("hour_24_price")?,
+                quadratic_base: get_amount("quadratic_base")?,
+                // Missing from events emitted before annotations existed
+                annotation_byte_price: match get_attr("annotation_byte_price") {
+                    Some(price) => price.parse().ok()?,
+                    None => Uint128::zero(),
+                },
+            })
+        } else {
+            None
+        };
+
+        Some(Self {
+            token: Addr::unchecked(get_attr("token")?),
+            price_scaling,
+        })
+    }
+}
@@ -1,13 +1,15 @@
 use cosmwasm_std::{Attribute, Event};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::events::{EventData, EventType};
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiatePriceScalingEventData {
     pub collection_info: String,
     pub minter: String,
     pub price_scaling: String,
+    pub config: String,
     pub time: String,
 }
 
@@ -21,6 +23,7 @@ impl EventData for InstantiatePriceScalingEventData {
             Attribute::new("collection_info", self.collection_info),
             Attribute::new("minter", self.minter),
             Attribute::new("price_scaling", self.price_scaling),
+            Attribute::new("config", self.config),
             Attribute::new("time", self.time),
         ])
     }
@@ -33,6 +36,7 @@ impl EventData for InstantiatePriceScalingEventData {
         let mut collection_info = None;
         let mut minter = None;
         let mut price_scaling = None;
+        let mut config = None;
         let mut time = None;
 
         for attr in &event.attributes {
@@ -40,6 +44,7 @@ impl EventData for InstantiatePriceScalingEventData {
                 "collection_info" => collection_info = Some(attr.value.clone()),
                 "minter" => minter = Some(attr.value.clone()),
                 "price_scaling" => price_scaling = Some(attr.value.clone()),
+                "config" => config = Some(attr.value.clone()),
                 "time" => time = Some(attr.value.clone()),
                 _ => {}
             }
@@ -49,6 +54,7 @@ impl EventData for InstantiatePriceScalingEventData {
             collection_info: collection_info?,
             minter: minter?,
             price_scaling: price_scaling?,
+            config: config?,
             time: time?,
         })
     }
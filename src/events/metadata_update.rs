@@ -1,9 +1,10 @@
 use cosmwasm_std::Event;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::{EventData, EventType};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct MetadataUpdateEventData {
     pub token_id: String,
     pub resulting_hash: String,
@@ -1,4 +1,5 @@
 use cosmwasm_std::{Addr, Attribute, Event};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -6,7 +7,7 @@ use crate::{
     events::{EventData, EventType},
 };
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct MintMetadataEventData {
     pub token_id: String,
     pub owner: Addr,
@@ -1,18 +1,42 @@
 use cosmwasm_std::Event;
 
+mod annotation_clear;
+mod base_layer;
+mod config_update;
+mod cw20_price_scaling;
 mod instantiate_price_scaling;
 mod metadata_update;
 mod mint_metadata;
+mod paint_commit;
+mod paint_commit_expire;
+mod painter_grant;
 mod payment_distribution;
+mod pixel_outbid;
 mod pixel_update;
 mod price_scaling;
+mod tile_burn;
+mod tile_policy;
+mod tile_transfer;
+mod withdraw;
 
+pub use annotation_clear::AnnotationClearEventData;
+pub use base_layer::BaseLayerUpdateEventData;
+pub use config_update::ConfigUpdateEventData;
+pub use cw20_price_scaling::Cw20PriceScalingUpdateEventData;
 pub use instantiate_price_scaling::InstantiatePriceScalingEventData;
 pub use metadata_update::MetadataUpdateEventData;
 pub use mint_metadata::MintMetadataEventData;
+pub use paint_commit::PaintCommitEventData;
+pub use paint_commit_expire::PaintCommitExpireEventData;
+pub use painter_grant::PainterGrantEventData;
 pub use payment_distribution::PaymentDistributionEventData;
+pub use pixel_outbid::PixelOutbidEventData;
 pub use pixel_update::PixelUpdateEventData;
 pub use price_scaling::PriceScalingUpdateEventData;
+pub use tile_burn::TileBurnEventData;
+pub use tile_policy::TilePolicyUpdateEventData;
+pub use tile_transfer::TileTransferEventData;
+pub use withdraw::WithdrawEventData;
 
 #[derive(Debug, Clone, Copy)]
 pub enum EventType {
@@ -22,6 +46,18 @@ pub enum EventType {
     PriceScalingUpdateEvent,
     InstantiatePriceScalingEvent,
     MintMetadataEvent,
+    TileTransferEvent,
+    TileBurnEvent,
+    WithdrawEvent,
+    Cw20PriceScalingUpdateEvent,
+    TilePolicyUpdateEvent,
+    PainterGrantEvent,
+    PixelOutbidEvent,
+    PaintCommitEvent,
+    PaintCommitExpireEvent,
+    BaseLayerUpdateEvent,
+    AnnotationClearEvent,
+    ConfigUpdateEvent,
 }
 
 impl EventType {
@@ -33,6 +69,18 @@ impl EventType {
             EventType::PriceScalingUpdateEvent => "price_scaling_update",
             EventType::InstantiatePriceScalingEvent => "instantiate_price_scaling",
             EventType::MintMetadataEvent => "mint_metadata",
+            EventType::TileTransferEvent => "tile_transfer",
+            EventType::TileBurnEvent => "tile_burn",
+            EventType::WithdrawEvent => "withdraw",
+            EventType::Cw20PriceScalingUpdateEvent => "cw20_price_scaling_update",
+            EventType::TilePolicyUpdateEvent => "tile_policy_update",
+            EventType::PainterGrantEvent => "painter_grant",
+            EventType::PixelOutbidEvent => "pixel_outbid",
+            EventType::PaintCommitEvent => "paint_commit",
+            EventType::PaintCommitExpireEvent => "paint_commit_expire",
+            EventType::BaseLayerUpdateEvent => "base_layer_update",
+            EventType::AnnotationClearEvent => "annotation_clear",
+            EventType::ConfigUpdateEvent => "config_update",
         }
     }
 
@@ -0,0 +1,62 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+
+/// Payment escrowed for a hidden paint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PaintCommitEventData {
+    pub token_id: String,
+    pub committer: Addr,
+    pub commitment: String,
+    pub pixel_ids: Vec<u32>,
+    pub denom: String,
+    pub amount: u128,
+    /// Last block the paint can be revealed in
+    pub reveal_until: u64,
+}
+
+impl EventData for PaintCommitEventData {
+    fn event_type() -> EventType {
+        EventType::PaintCommitEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str())
+            .add_attribute("token_id", self.token_id)
+            .add_attribute("committer", self.committer.to_string())
+            .add_attribute("commitment", self.commitment)
+            .add_attribute(
+                "pixel_ids",
+                serde_json::to_string(&self.pixel_ids).unwrap_or_default(),
+            )
+            .add_attribute("denom", self.denom)
+            .add_attribute("amount", self.amount.to_string())
+            .add_attribute("reveal_until", self.reveal_until.to_string())
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            committer: Addr::unchecked(get_attr("committer")?),
+            commitment: get_attr("commitment")?,
+            pixel_ids: serde_json::from_str(&get_attr("pixel_ids")?).ok()?,
+            denom: get_attr("denom")?,
+            amount: get_attr("amount")?.parse().ok()?,
+            reveal_until: get_attr("reveal_until")?.parse().ok()?,
+        })
+    }
+}
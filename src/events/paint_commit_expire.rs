@@ -0,0 +1,57 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+
+/// A paint commit ran out of time without being revealed, or was refunded
+/// because its tile was burned
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PaintCommitExpireEventData {
+    pub token_id: String,
+    pub committer: Addr,
+    /// Committer on refunds, tile owner on forfeits
+    pub recipient: Addr,
+    pub denom: String,
+    pub amount: u128,
+    pub forfeited: bool,
+}
+
+impl EventData for PaintCommitExpireEventData {
+    fn event_type() -> EventType {
+        EventType::PaintCommitExpireEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str())
+            .add_attribute("token_id", self.token_id)
+            .add_attribute("committer", self.committer.to_string())
+            .add_attribute("recipient", self.recipient.to_string())
+            .add_attribute("denom", self.denom)
+            .add_attribute("amount", self.amount.to_string())
+            .add_attribute("forfeited", self.forfeited.to_string())
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            committer: Addr::unchecked(get_attr("committer")?),
+            recipient: Addr::unchecked(get_attr("recipient")?),
+            denom: get_attr("denom")?,
+            amount: get_attr("amount")?.parse().ok()?,
+            forfeited: get_attr("forfeited")?.parse().ok()?,
+        })
+    }
+}
@@ -0,0 +1,58 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+use crate::core::tile::grant::PainterGrant;
+
+/// A painting grant was given or replaced, or revoked when `grant` is `None`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PainterGrantEventData {
+    pub token_id: String,
+    pub painter: Addr,
+    pub grant: Option<PainterGrant>,
+}
+
+impl EventData for PainterGrantEventData {
+    fn event_type() -> EventType {
+        EventType::PainterGrantEvent
+    }
+
+    fn into_event(self) -> Event {
+        let event = Event::new(Self::event_type().as_str())
+            .add_attribute("token_id", self.token_id)
+            .add_attribute("painter", self.painter.to_string())
+            .add_attribute("granted", self.grant.is_some().to_string());
+        match self.grant {
+            Some(grant) => {
+                event.add_attribute("grant", serde_json::to_string(&grant).unwrap_or_default())
+            }
+            None => event,
+        }
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        let grant = match get_attr("granted")?.as_str() {
+            "true" => Some(serde_json::from_str(&get_attr("grant")?).ok()?),
+            _ => None,
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            painter: Addr::unchecked(get_attr("painter")?),
+            grant,
+        })
+    }
+}
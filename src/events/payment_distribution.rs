@@ -1,14 +1,18 @@
 use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::{EventData, EventType};
+use crate::core::revenue::Payout;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct PaymentDistributionEventData {
     pub token_id: String,
     pub sender: Addr,
     pub royalty_amount: u128,
     pub owner_amount: u128,
+    /// Every recipient of the paint, royalty first and owner last
+    pub payouts: Vec<Payout>,
 }
 
 impl EventData for PaymentDistributionEventData {
@@ -22,6 +26,10 @@ impl EventData for PaymentDistributionEventData {
             .add_attribute("sender", self.sender.to_string())
             .add_attribute("royalty_amount", self.royalty_amount.to_string())
             .add_attribute("owner_amount", self.owner_amount.to_string())
+            .add_attribute(
+                "payouts",
+                serde_json::to_string(&self.payouts).unwrap_or_default(),
+            )
     }
 
     fn try_from_event(event: &Event) -> Option<Self> {
@@ -42,6 +50,7 @@ impl EventData for PaymentDistributionEventData {
             sender: Addr::unchecked(get_attr("sender")?),
             royalty_amount: get_attr("royalty_amount")?.parse().ok()?,
             owner_amount: get_attr("owner_amount")?.parse().ok()?,
+            payouts: serde_json::from_str(&get_attr("payouts")?).ok()?,
         })
     }
 }
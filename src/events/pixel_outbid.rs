@@ -0,0 +1,63 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+
+/// A live pixel was taken over before it expired
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PixelOutbidEventData {
+    pub token_id: String,
+    pub pixel_id: u32,
+    pub challenger: Addr,
+    pub displaced: Addr,
+    /// Native denom or CW20 token address of the takeover payment
+    pub denom: String,
+    pub remaining_value: u128,
+    pub premium: u128,
+    /// Sent back to the displaced painter
+    pub refund: u128,
+}
+
+impl EventData for PixelOutbidEventData {
+    fn event_type() -> EventType {
+        EventType::PixelOutbidEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str())
+            .add_attribute("token_id", self.token_id)
+            .add_attribute("pixel_id", self.pixel_id.to_string())
+            .add_attribute("challenger", self.challenger.to_string())
+            .add_attribute("displaced", self.displaced.to_string())
+            .add_attribute("denom", self.denom)
+            .add_attribute("remaining_value", self.remaining_value.to_string())
+            .add_attribute("premium", self.premium.to_string())
+            .add_attribute("refund", self.refund.to_string())
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            pixel_id: get_attr("pixel_id")?.parse().ok()?,
+            challenger: Addr::unchecked(get_attr("challenger")?),
+            displaced: Addr::unchecked(get_attr("displaced")?),
+            denom: get_attr("denom")?,
+            remaining_value: get_attr("remaining_value")?.parse().ok()?,
+            premium: get_attr("premium")?.parse().ok()?,
+            refund: get_attr("refund")?.parse().ok()?,
+        })
+    }
+}
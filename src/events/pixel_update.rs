@@ -1,10 +1,11 @@
 use cosmwasm_std::{Attribute, Event};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::{EventData, EventType};
 use crate::core::tile::metadata::PixelData;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct PixelUpdateEventData {
     pub token_id: String,
     pub new_pixels: Vec<PixelData>,
@@ -1,14 +1,17 @@
 use cosmwasm_std::Event;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::{EventData, EventType};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct PriceScalingUpdateEventData {
     pub hour_1_price: u128,
     pub hour_12_price: u128,
     pub hour_24_price: u128,
     pub quadratic_base: u128,
+    #[serde(default)]
+    pub annotation_byte_price: u128,
 }
 
 impl EventData for PriceScalingUpdateEventData {
@@ -22,6 +25,10 @@ impl EventData for PriceScalingUpdateEventData {
             .add_attribute("hour_12_price", self.hour_12_price.to_string())
             .add_attribute("hour_24_price", self.hour_24_price.to_string())
             .add_attribute("quadratic_base", self.quadratic_base.to_string())
+            .add_attribute(
+                "annotation_byte_price",
+                self.annotation_byte_price.to_string(),
+            )
     }
 
     fn try_from_event(event: &Event) -> Option<Self> {
@@ -42,6 +49,11 @@ impl EventData for PriceScalingUpdateEventData {
             hour_12_price: get_attr("hour_12_price")?.parse().ok()?,
             hour_24_price: get_attr("hour_24_price")?.parse().ok()?,
             quadratic_base: get_attr("quadratic_base")?.parse().ok()?,
+            // Missing from events emitted before annotations existed
+            annotation_byte_price: match get_attr("annotation_byte_price") {
+                Some(price) => price.parse().ok()?,
+                None => 0,
+            },
         })
     }
 }
@@ -0,0 +1,49 @@
+use cosmwasm_std::{Addr, Attribute, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TileBurnEventData {
+    pub token_id: String,
+    pub owner: Addr,
+    pub tile_hash: String,
+    pub live_pixels: u32,
+}
+
+impl EventData for TileBurnEventData {
+    fn event_type() -> EventType {
+        EventType::TileBurnEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str()).add_attributes(vec![
+            Attribute::new("token_id", self.token_id),
+            Attribute::new("owner", self.owner.to_string()),
+            Attribute::new("tile_hash", self.tile_hash),
+            Attribute::new("live_pixels", self.live_pixels.to_string()),
+        ])
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            owner: Addr::unchecked(get_attr("owner")?),
+            tile_hash: get_attr("tile_hash")?,
+            live_pixels: get_attr("live_pixels")?.parse().ok()?,
+        })
+    }
+}
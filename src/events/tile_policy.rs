@@ -0,0 +1,61 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+use crate::core::tile::policy::TilePolicy;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TilePolicyUpdateEventData {
+    pub token_id: String,
+    pub owner: Addr,
+    pub policy: TilePolicy,
+    /// Block time the tile was frozen at, set once the policy freezes it
+    #[serde(default)]
+    pub frozen_at: Option<u64>,
+}
+
+impl EventData for TilePolicyUpdateEventData {
+    fn event_type() -> EventType {
+        EventType::TilePolicyUpdateEvent
+    }
+
+    fn into_event(self) -> Event {
+        let event = Event::new(Self::event_type().as_str())
+            .add_attribute("token_id", self.token_id)
+            .add_attribute("owner", self.owner.to_string())
+            .add_attribute("frozen", self.policy.frozen.to_string())
+            .add_attribute(
+                "policy",
+                serde_json::to_string(&self.policy).unwrap_or_default(),
+            );
+        match self.frozen_at {
+            Some(frozen_at) => event.add_attribute("frozen_at", frozen_at.to_string()),
+            None => event,
+        }
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            owner: Addr::unchecked(get_attr("owner")?),
+            policy: serde_json::from_str(&get_attr("policy")?).ok()?,
+            frozen_at: match get_attr("frozen_at") {
+                Some(frozen_at) => Some(frozen_at.parse().ok()?),
+                None => None,
+            },
+        })
+    }
+}
@@ -0,0 +1,52 @@
+use cosmwasm_std::{Addr, Attribute, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TileTransferEventData {
+    pub token_id: String,
+    pub previous_owner: Addr,
+    pub new_owner: Addr,
+    pub tile_hash: String,
+    pub live_pixels: u32,
+}
+
+impl EventData for TileTransferEventData {
+    fn event_type() -> EventType {
+        EventType::TileTransferEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str()).add_attributes(vec![
+            Attribute::new("token_id", self.token_id),
+            Attribute::new("previous_owner", self.previous_owner.to_string()),
+            Attribute::new("new_owner", self.new_owner.to_string()),
+            Attribute::new("tile_hash", self.tile_hash),
+            Attribute::new("live_pixels", self.live_pixels.to_string()),
+        ])
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            token_id: get_attr("token_id")?,
+            previous_owner: Addr::unchecked(get_attr("previous_owner")?),
+            new_owner: Addr::unchecked(get_attr("new_owner")?),
+            tile_hash: get_attr("tile_hash")?,
+            live_pixels: get_attr("live_pixels")?.parse().ok()?,
+        })
+    }
+}
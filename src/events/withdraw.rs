@@ -0,0 +1,45 @@
+use cosmwasm_std::{Addr, Event};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{EventData, EventType};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WithdrawEventData {
+    pub address: Addr,
+    pub denom: String,
+    pub amount: u128,
+}
+
+impl EventData for WithdrawEventData {
+    fn event_type() -> EventType {
+        EventType::WithdrawEvent
+    }
+
+    fn into_event(self) -> Event {
+        Event::new(Self::event_type().as_str())
+            .add_attribute("address", self.address.to_string())
+            .add_attribute("denom", self.denom)
+            .add_attribute("amount", self.amount.to_string())
+    }
+
+    fn try_from_event(event: &Event) -> Option<Self> {
+        if event.ty != Self::event_type().as_wasm_str() {
+            return None;
+        }
+
+        let get_attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(Self {
+            address: Addr::unchecked(get_attr("address")?),
+            denom: get_attr("denom")?,
+            amount: get_attr("amount")?.parse().ok()?,
+        })
+    }
+}
@@ -2,3 +2,5 @@ pub mod contract;
 pub mod core;
 pub mod defaults;
 pub mod events;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod schema;
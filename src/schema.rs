@@ -0,0 +1,88 @@
+use std::{fs, io, path::Path};
+
+use cosmwasm_schema::{generate_api, schema_for, schemars::schema::RootSchema, Api};
+
+use crate::{
+    contract::msg::{ContractExecuteMsg, InstantiateMsg, QueryMsg},
+    events::{
+        AnnotationClearEventData, BaseLayerUpdateEventData, ConfigUpdateEventData,
+        Cw20PriceScalingUpdateEventData, EventData, InstantiatePriceScalingEventData,
+        MetadataUpdateEventData, MintMetadataEventData, PaintCommitEventData,
+        PaintCommitExpireEventData, PainterGrantEventData, PaymentDistributionEventData,
+        PixelOutbidEventData, PixelUpdateEventData, PriceScalingUpdateEventData, TileBurnEventData,
+        TilePolicyUpdateEventData, TileTransferEventData, WithdrawEventData,
+    },
+};
+
+/// Directory the schemas are checked in to, relative to the crate root
+pub const SCHEMA_DIR: &str = "schema";
+
+/// Contract messages together with the response of every query
+pub fn api() -> Api {
+    generate_api! {
+        name: "tiles",
+        instantiate: InstantiateMsg,
+        execute: ContractExecuteMsg,
+        query: QueryMsg,
+    }
+}
+
+/// Decoded payload of every event, keyed by event type
+pub fn event_schemas() -> Vec<(&'static str, RootSchema)> {
+    fn entry<T: EventData>(schema: RootSchema) -> (&'static str, RootSchema) {
+        (T::event_type().as_str(), schema)
+    }
+
+    vec![
+        entry::<AnnotationClearEventData>(schema_for!(AnnotationClearEventData)),
+        entry::<BaseLayerUpdateEventData>(schema_for!(BaseLayerUpdateEventData)),
+        entry::<ConfigUpdateEventData>(schema_for!(ConfigUpdateEventData)),
+        entry::<Cw20PriceScalingUpdateEventData>(schema_for!(Cw20PriceScalingUpdateEventData)),
+        entry::<InstantiatePriceScalingEventData>(schema_for!(InstantiatePriceScalingEventData)),
+        entry::<MetadataUpdateEventData>(schema_for!(MetadataUpdateEventData)),
+        entry::<MintMetadataEventData>(schema_for!(MintMetadataEventData)),
+        entry::<PainterGrantEventData>(schema_for!(PainterGrantEventData)),
+        entry::<PaymentDistributionEventData>(schema_for!(PaymentDistributionEventData)),
+        entry::<PaintCommitEventData>(schema_for!(PaintCommitEventData)),
+        entry::<PaintCommitExpireEventData>(schema_for!(PaintCommitExpireEventData)),
+        entry::<PixelOutbidEventData>(schema_for!(PixelOutbidEventData)),
+        entry::<PixelUpdateEventData>(schema_for!(PixelUpdateEventData)),
+        entry::<PriceScalingUpdateEventData>(schema_for!(PriceScalingUpdateEventData)),
+        entry::<TileBurnEventData>(schema_for!(TileBurnEventData)),
+        entry::<TilePolicyUpdateEventData>(schema_for!(TilePolicyUpdateEventData)),
+        entry::<TileTransferEventData>(schema_for!(TileTransferEventData)),
+        entry::<WithdrawEventData>(schema_for!(WithdrawEventData)),
+    ]
+}
+
+/// Every schema file as (path relative to the schema directory, contents)
+pub fn schema_files() -> io::Result<Vec<(String, String)>> {
+    let api = api().render();
+    let encode = io::Error::other;
+    let mut files = vec![("tiles.json".to_string(), api.to_string().map_err(encode)?)];
+    for (name, contents) in api.to_schema_files().map_err(encode)? {
+        files.push((format!("raw/{}", name), contents));
+    }
+    for (event, schema) in event_schemas() {
+        files.push((
+            format!("events/{}.json", event),
+            serde_json::to_string_pretty(&schema)?,
+        ));
+    }
+    Ok(files)
+}
+
+/// Replaces the schema directory with freshly generated files
+pub fn write_schemas(dir: &Path) -> io::Result<()> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    for (name, contents) in schema_files()? {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use anyhow::Result;
+use cosmwasm_std::{Addr, Event, Uint128};
+use tiles::{
+    core::{
+        pricing::PriceScaling,
+        tile::metadata::{PixelUpdate, TileMetadata},
+    },
+    defaults::constants::MAX_ANNOTATION_BYTES,
+    events::{AnnotationClearEventData, EventData, PixelUpdateEventData},
+};
+
+use crate::utils::{TestSetup, TestUsers};
+
+const BYTE_PRICE: u128 = 1_000;
+
+struct AnnotationSetup {
+    base: TestSetup,
+    painter: Addr,
+    moderator: Addr,
+    metadata: TileMetadata,
+}
+
+impl AnnotationSetup {
+    fn new() -> Result<Self> {
+        let moderator = TestUsers::new().creator().address.clone();
+        let base = TestSetup::with_config(|msg| {
+            msg.price_scaling = Some(PriceScaling {
+                annotation_byte_price: Uint128::new(BYTE_PRICE),
+                ..PriceScaling::default()
+            });
+            msg.pixel_history_depth = Some(2);
+            msg.moderators = Some(vec![moderator.clone()]);
+        })?;
+        Ok(Self {
+            painter: base.users.pixel_operator().address.clone(),
+            moderator,
+            metadata: base.state.get_token_metadata(1)?,
+            base,
+        })
+    }
+
+    fn price(&self, update: &PixelUpdate) -> Result<u128> {
+        let scaling = self.base.tiles.query_price_scaling(&self.base.app)?;
+        Ok(scaling.calculate_price(update.expiration_duration).u128()
+            + scaling.annotation_price(update.annotation_bytes()).u128())
+    }
+
+    fn paint(&mut self, update: PixelUpdate, amount: u128) -> Result<Vec<Event>> {
+        let response = self.base.tiles.update_pixel_with_funds(
+            &mut self.base.app,
+            &self.painter,
+            1,
+            vec![update.clone()],
+            amount,
+            self.metadata.clone(),
+        )?;
+        let now = self.base.app.inner().block_info().time.seconds();
+        self.metadata
+            .apply_updates(vec![update], &self.painter, now);
+        Ok(response.events)
+    }
+}
+
+fn signed(id: u32, annotation: &str) -> PixelUpdate {
+    PixelUpdate {
+        id,
+        color: "#FF0000".to_string(),
+        expiration_duration: 3600,
+        keyframes: vec![],
+        annotation: Some(annotation.to_string()),
+    }
+}
+
+#[test]
+fn annotations_are_stored_with_the_pixel() -> Result<()> {
+    let mut setup = AnnotationSetup::new()?;
+    let update = signed(7, "painted by anon");
+    let price = setup.price(&update)?;
+
+    let events = setup.paint(update, price)?;
+    let event = events
+        .iter()
+        .find_map(PixelUpdateEventData::try_from_event)
+        .expect("pixel update event");
+    assert_eq!(
+        event.new_pixels[0].annotation.as_deref(),
+        Some("painted by anon")
+    );
+    setup
+        .base
+        .tiles
+        .assert_token_hash(&setup.base.app, 1, &setup.metadata.hash());
+
+    let response = setup.base.tiles.query_pixel(&setup.base.app, 1, 7)?;
+    assert!(response.live);
+    assert_eq!(response.pixel, setup.metadata.pixels[7]);
+    assert_eq!(response.color, setup.metadata.pixels[7].color);
+
+    // Painters have to include the annotation in the metadata they send
+    let mut stale = setup.metadata.clone();
+    stale.pixels[7].annotation = None;
+    let update = signed(8, "me too");
+    let price = setup.price(&update)?;
+    assert!(setup
+        .base
+        .tiles
+        .update_pixel_with_funds(
+            &mut setup.base.app,
+            &setup.painter,
+            1,
+            vec![update],
+            price,
+            stale
+        )
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn annotations_are_charged_per_byte() -> Result<()> {
+    let mut setup = AnnotationSetup::new()?;
+    let update = signed(0, "gm");
+    let price = setup.price(&update)?;
+    assert_eq!(
+        price,
+        setup
+            .base
+            .tiles
+            .query_price_scaling(&setup.base.app)?
+            .calculate_price(3600)
+            .u128()
+            + 2 * BYTE_PRICE
+    );
+
+    assert!(setup.paint(update.clone(), price - BYTE_PRICE).is_err());
+    setup.paint(update, price)?;
+
+    Ok(())
+}
+
+#[test]
+fn annotations_must_be_short_printable_text() -> Result<()> {
+    let mut setup = AnnotationSetup::new()?;
+    for annotation in [
+        String::new(),
+        "a".repeat(MAX_ANNOTATION_BYTES + 1),
+        "line\nbreak".to_string(),
+        "bell\u{7}".to_string(),
+        // Invisible format characters
+        "\u{202E}txt.exe".to_string(),
+        "zero\u{200B}width".to_string(),
+        "\u{FEFF}bom".to_string(),
+        "tag\u{E0041}".to_string(),
+    ] {
+        let update = signed(0, &annotation);
+        let price = setup.price(&update)?;
+        assert!(setup.paint(update, price).is_err(), "{:?}", annotation);
+    }
+
+    let update = signed(0, &"a".repeat(MAX_ANNOTATION_BYTES));
+    let price = setup.price(&update)?;
+    setup.paint(update, price)?;
+
+    Ok(())
+}
+
+#[test]
+fn moderators_clear_annotations() -> Result<()> {
+    let mut setup = AnnotationSetup::new()?;
+    let update = signed(4, "https://spam.example");
+    let price = setup.price(&update)?;
+    setup.paint(update, price)?;
+
+    let painter = setup.painter.clone();
+    assert!(setup
+        .base
+        .tiles
+        .execute_clear_annotations(&mut setup.base.app, &painter, 1, vec![4])
+        .is_err());
+
+    let moderator = setup.moderator.clone();
+    let response =
+        setup
+            .base
+            .tiles
+            .execute_clear_annotations(&mut setup.base.app, &moderator, 1, vec![4])?;
+    setup.metadata.clear_annotations(&[4]);
+
+    let event = response
+        .events
+        .iter()
+        .find_map(AnnotationClearEventData::try_from_event)
+        .expect("annotation clear event");
+    assert_eq!(event.pixel_ids, vec![4]);
+    assert_eq!(event.tile_hash, setup.metadata.hash());
+    setup
+        .base
+        .tiles
+        .assert_token_hash(&setup.base.app, 1, &setup.metadata.hash());
+
+    // The paint itself stays, only the annotation is gone, history included
+    let pixel = setup.base.tiles.query_pixel(&setup.base.app, 1, 4)?.pixel;
+    assert_eq!(pixel.color, "#FF0000");
+    assert_eq!(pixel.annotation, None);
+    let history = setup
+        .base
+        .tiles
+        .query_pixel_history(&setup.base.app, 1, 4, None, None)?;
+    assert!(history
+        .entries
+        .iter()
+        .all(|entry| entry.pixel.annotation.is_none()));
+
+    Ok(())
+}
@@ -0,0 +1,496 @@
+use anyhow::Result;
+use cosmwasm_std::{coins, Addr, Coin};
+use sg_std::NATIVE_DENOM;
+use tiles::{
+    contract::error::ContractError,
+    core::{
+        config::UnrevealedCommits,
+        tile::{
+            commit::paint_commitment,
+            metadata::{PixelUpdate, TileMetadata},
+            policy::{PaintAccess, TilePolicy},
+        },
+    },
+    events::{EventData, PaintCommitExpireEventData},
+};
+
+use crate::utils::TestSetup;
+
+const WINDOW: u64 = 5;
+const SALT: &str = "8f1d2c";
+
+struct CommitSetup {
+    base: TestSetup,
+    owner: Addr,
+    painter: Addr,
+    price: u128,
+    /// The tile as the contract stores it
+    metadata: TileMetadata,
+}
+
+impl CommitSetup {
+    fn new(unrevealed_commits: UnrevealedCommits) -> Result<Self> {
+        let base = TestSetup::with_config(|msg| {
+            msg.reveal_window_blocks = Some(WINDOW);
+            msg.unrevealed_commits = Some(unrevealed_commits);
+        })?;
+        Ok(Self {
+            owner: base.users.get_buyer().address.clone(),
+            painter: base.users.pixel_operator().address.clone(),
+            price: base
+                .tiles
+                .query_price_scaling(&base.app)?
+                .calculate_price(3600)
+                .u128(),
+            metadata: base.state.get_token_metadata(1)?,
+            base,
+        })
+    }
+
+    /// Commits `updates`, reserving the pixels they paint
+    fn commit(&mut self, updates: &[PixelUpdate], amount: u128) -> Result<()> {
+        let pixel_ids = updates.iter().map(|update| update.id).collect();
+        self.commit_pixels(pixel_ids, updates, amount)
+    }
+
+    fn commit_pixels(
+        &mut self,
+        pixel_ids: Vec<u32>,
+        updates: &[PixelUpdate],
+        amount: u128,
+    ) -> Result<()> {
+        let commitment = paint_commitment(&self.painter, "1", updates, SALT);
+        self.base.tiles.execute_commit_paint(
+            &mut self.base.app,
+            &self.painter,
+            1,
+            pixel_ids,
+            commitment,
+            &coins(amount, NATIVE_DENOM),
+        )?;
+        Ok(())
+    }
+
+    /// Paints pixel `id` directly, without a commit
+    fn paint(&mut self, painter: &Addr, id: u32) -> Result<()> {
+        let updates = vec![PixelUpdate {
+            id,
+            ..updates()[0].clone()
+        }];
+        self.base.tiles.update_pixel_with_funds(
+            &mut self.base.app,
+            painter,
+            1,
+            updates.clone(),
+            self.price,
+            self.metadata.clone(),
+        )?;
+        self.apply(updates, painter);
+        Ok(())
+    }
+
+    fn expire(&mut self) -> Result<PaintCommitExpireEventData> {
+        let painter = self.painter.clone();
+        let response =
+            self.base
+                .tiles
+                .execute_expire_commit(&mut self.base.app, &painter, 1, &painter)?;
+        Ok(response
+            .events
+            .iter()
+            .find_map(PaintCommitExpireEventData::try_from_event)
+            .expect("paint commit expire event"))
+    }
+
+    fn reveal(&mut self, updates: Vec<PixelUpdate>, salt: &str) -> Result<()> {
+        self.base.tiles.execute_reveal_paint(
+            &mut self.base.app,
+            &self.painter,
+            1,
+            updates.clone(),
+            salt,
+        )?;
+        let painter = self.painter.clone();
+        self.apply(updates, &painter);
+        Ok(())
+    }
+
+    fn apply(&mut self, updates: Vec<PixelUpdate>, painter: &Addr) {
+        let now = self.base.app.inner().block_info().time.seconds();
+        self.metadata.apply_updates(updates, painter, now);
+    }
+
+    fn has_commit(&self) -> bool {
+        self.base
+            .tiles
+            .query_paint_commit(&self.base.app, 1, &self.painter)
+            .unwrap()
+            .commit
+            .is_some()
+    }
+
+    fn balance(&self, address: &Addr) -> u128 {
+        self.base.app.get_balance(address, NATIVE_DENOM).unwrap()
+    }
+}
+
+fn updates() -> Vec<PixelUpdate> {
+    vec![PixelUpdate::new(0, "#FF0000", 3600)]
+}
+
+#[test]
+fn reveal_paints_the_committed_updates() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    let painter = setup.painter.clone();
+    let before = setup.balance(&painter);
+
+    // Overpaying is fine, the change comes back on reveal
+    setup.commit(&updates(), setup.price + 1_000)?;
+    assert_eq!(setup.balance(&painter), before - setup.price - 1_000);
+    let response = setup
+        .base
+        .tiles
+        .query_paint_commit(&setup.base.app, 1, &painter)?;
+    assert_eq!(
+        response.reveal_until,
+        Some(setup.base.app.inner().block_info().height + WINDOW)
+    );
+
+    setup.base.app.advance_blocks(1);
+    setup.reveal(updates(), SALT)?;
+
+    let block = setup.base.app.inner().block_info();
+    let mut expected = TileMetadata::new(&setup.base.tiles.query_config(&setup.base.app)?);
+    expected.apply_updates(updates(), &painter, block.time.seconds());
+    setup
+        .base
+        .tiles
+        .assert_token_hash(&setup.base.app, 1, &expected.hash());
+    assert_eq!(setup.balance(&painter), before - setup.price);
+    assert!(!setup.has_commit());
+
+    Ok(())
+}
+
+#[test]
+fn reveal_cannot_share_the_commit_block() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    setup.commit(&updates(), setup.price)?;
+
+    assert!(setup.reveal(updates(), SALT).is_err());
+    assert!(setup.has_commit());
+
+    Ok(())
+}
+
+#[test]
+fn reveal_is_allowed_in_the_last_block_of_the_window() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    setup.commit(&updates(), setup.price)?;
+
+    setup.base.app.advance_blocks(WINDOW);
+    setup.reveal(updates(), SALT)?;
+    assert!(!setup.has_commit());
+
+    Ok(())
+}
+
+#[test]
+fn reveal_after_the_window_fails() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    setup.commit(&updates(), setup.price)?;
+
+    setup.base.app.advance_blocks(WINDOW + 1);
+    assert!(setup.reveal(updates(), SALT).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn reveal_must_match_the_commitment() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    setup.commit(&updates(), setup.price)?;
+    setup.base.app.advance_blocks(1);
+
+    assert!(setup.reveal(updates(), "other salt").is_err());
+    let mut other = updates();
+    other[0].color = "#00FF00".to_string();
+    assert!(setup.reveal(other, SALT).is_err());
+
+    // Commitments are bound to the committer
+    let owner = setup.owner.clone();
+    assert!(setup
+        .base
+        .tiles
+        .execute_reveal_paint(&mut setup.base.app, &owner, 1, updates(), SALT)
+        .is_err());
+
+    assert!(setup.has_commit());
+    setup.reveal(updates(), SALT)?;
+
+    Ok(())
+}
+
+#[test]
+fn reveal_fails_when_escrow_does_not_cover_the_price() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    setup.commit(&updates(), setup.price - 1)?;
+    setup.base.app.advance_blocks(1);
+
+    assert!(setup.reveal(updates(), SALT).is_err());
+    assert!(setup.has_commit());
+
+    Ok(())
+}
+
+#[test]
+fn commits_are_validated() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    let painter = setup.painter.clone();
+    let commitment = paint_commitment(&painter, "1", &updates(), SALT);
+    let pixels_per_tile = setup
+        .base
+        .tiles
+        .query_config(&setup.base.app)?
+        .pixels_per_tile();
+    let mut commit = |pixel_ids: Vec<u32>, commitment: &str, funds: &[Coin]| {
+        setup.base.tiles.execute_commit_paint(
+            &mut setup.base.app,
+            &painter,
+            1,
+            pixel_ids,
+            commitment.to_string(),
+            funds,
+        )
+    };
+
+    assert!(commit(vec![0], "not a digest", &coins(100, NATIVE_DENOM)).is_err());
+    assert!(commit(vec![0], &commitment, &[]).is_err());
+    assert!(commit(vec![0], &commitment, &coins(100, "uatom")).is_err());
+    assert!(commit(vec![], &commitment, &coins(100, NATIVE_DENOM)).is_err());
+    assert!(commit(vec![0, 0], &commitment, &coins(100, NATIVE_DENOM)).is_err());
+    assert!(commit(
+        vec![pixels_per_tile],
+        &commitment,
+        &coins(100, NATIVE_DENOM)
+    )
+    .is_err());
+    commit(vec![0], &commitment, &coins(100, NATIVE_DENOM))?;
+    assert!(commit(vec![1], &commitment, &coins(100, NATIVE_DENOM)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn unrevealed_commits_are_refunded() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    let before = setup.balance(&painter);
+    setup.commit(&updates(), setup.price)?;
+
+    // Still revealable in the last block of the window
+    setup.base.app.advance_blocks(WINDOW);
+    assert!(setup
+        .base
+        .tiles
+        .execute_expire_commit(&mut setup.base.app, &owner, 1, &painter)
+        .is_err());
+
+    setup.base.app.advance_blocks(1);
+    let response =
+        setup
+            .base
+            .tiles
+            .execute_expire_commit(&mut setup.base.app, &owner, 1, &painter)?;
+    let event = response
+        .events
+        .iter()
+        .find_map(PaintCommitExpireEventData::try_from_event)
+        .expect("paint commit expire event");
+    assert!(!event.forfeited);
+    assert_eq!(event.recipient, painter);
+    assert_eq!(setup.balance(&painter), before);
+    assert!(!setup.has_commit());
+
+    Ok(())
+}
+
+#[test]
+fn unrevealed_commits_can_be_forfeited_to_the_owner() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Forfeit)?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    let owner_before = setup.balance(&owner);
+    setup.commit(&updates(), setup.price)?;
+
+    setup.base.app.advance_blocks(WINDOW + 1);
+    setup
+        .base
+        .tiles
+        .execute_expire_commit(&mut setup.base.app, &painter, 1, &painter)?;
+    assert_eq!(setup.balance(&owner), owner_before + setup.price);
+
+    Ok(())
+}
+
+#[test]
+fn committed_pixels_are_reserved_until_the_window_closes() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    let owner = setup.owner.clone();
+    setup.commit(&updates(), setup.price)?;
+
+    let err = setup.paint(&owner, 0).unwrap_err();
+    assert!(err.root_cause().to_string().contains("reserved"));
+    setup.paint(&owner, 1)?;
+    // Nor can another commit take the pixel
+    let commitment = paint_commitment(&owner, "1", &updates(), SALT);
+    assert!(setup
+        .base
+        .tiles
+        .execute_commit_paint(
+            &mut setup.base.app,
+            &owner,
+            1,
+            vec![0],
+            commitment,
+            &coins(setup.price, NATIVE_DENOM),
+        )
+        .is_err());
+
+    setup.base.app.advance_blocks(WINDOW + 1);
+    setup.paint(&owner, 0)?;
+    Ok(())
+}
+
+#[test]
+fn reveals_release_their_pixels() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    let owner = setup.owner.clone();
+    setup.commit(&updates(), setup.price)?;
+
+    setup.base.app.advance_blocks(1);
+    setup.reveal(updates(), SALT)?;
+    setup.paint(&owner, 0)?;
+    Ok(())
+}
+
+#[test]
+fn reveals_only_paint_reserved_pixels() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    setup.commit_pixels(vec![1], &updates(), setup.price)?;
+
+    setup.base.app.advance_blocks(1);
+    let err = setup.reveal(updates(), SALT).unwrap_err();
+    assert!(err.root_cause().to_string().contains("not reserved"));
+    assert!(setup.has_commit());
+    Ok(())
+}
+
+#[test]
+fn commits_need_painting_rights() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Refund)?;
+    let owner = setup.owner.clone();
+    setup.base.tiles.execute_set_tile_policy(
+        &mut setup.base.app,
+        &owner,
+        1,
+        TilePolicy {
+            access: PaintAccess::OwnerOnly,
+            ..TilePolicy::default()
+        },
+    )?;
+
+    let err = setup.commit(&updates(), setup.price).unwrap_err();
+    assert!(err.root_cause().to_string().contains("not allowed"));
+    Ok(())
+}
+
+#[test]
+fn commits_are_refunded_when_the_owner_blocks_the_reveal() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Forfeit)?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    let before = setup.balance(&painter);
+    setup.commit(&updates(), setup.price)?;
+
+    // Shutting the committer out must not earn the owner the escrow
+    setup.base.tiles.execute_set_tile_policy(
+        &mut setup.base.app,
+        &owner,
+        1,
+        TilePolicy {
+            access: PaintAccess::OwnerOnly,
+            ..TilePolicy::default()
+        },
+    )?;
+    setup.base.app.advance_blocks(WINDOW + 1);
+    let event = setup.expire()?;
+    assert!(!event.forfeited);
+    assert_eq!(event.recipient, painter);
+    assert_eq!(setup.balance(&painter), before);
+    Ok(())
+}
+
+#[test]
+fn commits_are_refunded_when_the_tile_changes_hands() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Forfeit)?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    let buyer = setup.base.users.creator().address.clone();
+    setup.commit(&updates(), setup.price)?;
+
+    setup
+        .base
+        .tiles
+        .execute_transfer_nft(&mut setup.base.app, &owner, &buyer, "1".to_string())?;
+    setup.base.app.advance_blocks(WINDOW + 1);
+    let event = setup.expire()?;
+    assert!(!event.forfeited);
+    assert_eq!(event.recipient, painter);
+    Ok(())
+}
+
+#[test]
+fn burning_the_tile_refunds_open_commits() -> Result<()> {
+    let mut setup = CommitSetup::new(UnrevealedCommits::Forfeit)?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    let before = setup.balance(&painter);
+    setup.commit(&updates(), setup.price)?;
+
+    let response = setup
+        .base
+        .tiles
+        .execute_burn(&mut setup.base.app, &owner, "1".to_string())?;
+    let event = response
+        .events
+        .iter()
+        .find_map(PaintCommitExpireEventData::try_from_event)
+        .expect("paint commit expire event");
+    assert!(!event.forfeited);
+    assert_eq!(event.recipient, painter);
+    assert_eq!(setup.balance(&painter), before);
+    assert!(!setup.has_commit());
+
+    // Nothing is left to reveal or expire
+    setup.base.app.advance_blocks(1);
+    let err = setup.reveal(updates(), SALT).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::NoPaintCommit {
+            token_id: "1".to_string(),
+            committer: painter.to_string(),
+        }
+        .to_string()
+    );
+    setup.base.app.advance_blocks(WINDOW);
+    assert!(setup.expire().is_err());
+    assert_eq!(setup.balance(&painter), before);
+
+    // A tile minted under the same id starts without reservations
+    let minter = setup.base.minter.contract_addr.clone();
+    setup
+        .base
+        .tiles
+        .execute_mint(&mut setup.base.app, &minter, &owner, 1)?;
+    setup.base.app.advance_blocks(1);
+    setup.paint(&owner, 0)?;
+
+    Ok(())
+}
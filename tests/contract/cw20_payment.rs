@@ -0,0 +1,172 @@
+use anyhow::Result;
+use cosmwasm_std::{to_json_binary, Addr, Decimal, Uint128};
+use tiles::{
+    contract::msg::Cw20HookMsg,
+    core::{pricing::PriceScaling, tile::metadata::PixelUpdate},
+    defaults::constants::DEFAULT_ROYALTY_SHARE,
+    events::{Cw20PriceScalingUpdateEventData, EventData},
+};
+
+use crate::utils::{contracts::Cw20Contract, TestSetup};
+
+const PAINTER_BALANCE: u128 = 100_000_000;
+
+// Deliberately different from the native defaults
+fn cw20_price_scaling() -> PriceScaling {
+    PriceScaling {
+        hour_1_price: Uint128::new(5_000),
+        hour_12_price: Uint128::new(10_000),
+        hour_24_price: Uint128::new(15_000),
+        quadratic_base: Uint128::new(20_000),
+        annotation_byte_price: Uint128::zero(),
+    }
+}
+
+struct Cw20Setup {
+    setup: TestSetup,
+    token: Cw20Contract,
+    token_id: u32,
+    owner: Addr,
+    creator: Addr,
+    painter: Addr,
+}
+
+impl Cw20Setup {
+    fn new(whitelist: bool) -> Result<Self> {
+        let (mut setup, token_id) = TestSetup::with_minted_token()?;
+        let owner = setup.users.get_buyer().address.clone();
+        let creator = setup.users.tile_contract_creator().address.clone();
+        let painter = setup.users.pixel_operator().address.clone();
+        let token =
+            Cw20Contract::instantiate(&mut setup.app, &creator, &[(&painter, PAINTER_BALANCE)])?;
+        if whitelist {
+            setup.tiles.execute_set_cw20_price_scaling(
+                &mut setup.app,
+                &creator,
+                &token.contract_addr,
+                Some(cw20_price_scaling()),
+            )?;
+        }
+        Ok(Self {
+            setup,
+            token,
+            token_id,
+            owner,
+            creator,
+            painter,
+        })
+    }
+
+    fn paint(&mut self, updates: Vec<PixelUpdate>, amount: u128) -> Result<()> {
+        let msg = to_json_binary(&Cw20HookMsg::SetPixelColor {
+            token_id: self.token_id.to_string(),
+            current_metadata: self.setup.state.get_token_metadata(self.token_id)?,
+            updates,
+        })?;
+        let tiles = self.setup.tiles.contract_addr.clone();
+        self.token
+            .send(&mut self.setup.app, &self.painter, &tiles, amount, msg)?;
+        Ok(())
+    }
+
+    fn balance(&self, address: &Addr) -> u128 {
+        self.token.balance(&self.setup.app, address).unwrap()
+    }
+}
+
+fn update(id: u32) -> PixelUpdate {
+    PixelUpdate::new(id, "#FF0000", 3600)
+}
+
+#[test]
+fn paints_with_whitelisted_cw20_and_pays_out_in_tokens() -> Result<()> {
+    let mut cw20 = Cw20Setup::new(true)?;
+    let price = cw20_price_scaling().calculate_price(3600) * Uint128::new(2);
+    let royalty = price * Decimal::percent(DEFAULT_ROYALTY_SHARE);
+    let hash_before = cw20
+        .setup
+        .tiles
+        .query_token_hash(&cw20.setup.app, cw20.token_id)?;
+
+    cw20.paint(vec![update(0), update(1)], price.u128())?;
+
+    assert_eq!(cw20.balance(&cw20.painter), PAINTER_BALANCE - price.u128());
+    assert_eq!(cw20.balance(&cw20.creator), royalty.u128());
+    assert_eq!(cw20.balance(&cw20.owner), (price - royalty).u128());
+    // Everything is forwarded, the tiles contract keeps nothing
+    assert_eq!(cw20.balance(&cw20.setup.tiles.contract_addr), 0);
+    assert_ne!(
+        cw20.setup
+            .tiles
+            .query_token_hash(&cw20.setup.app, cw20.token_id)?,
+        hash_before
+    );
+
+    // Earnings are keyed by the token address
+    let earnings = cw20
+        .setup
+        .tiles
+        .query_tile_earnings(&cw20.setup.app, cw20.token_id)?
+        .earnings;
+    assert_eq!(earnings.len(), 1);
+    assert_eq!(earnings[0].denom, cw20.token.contract_addr.to_string());
+    assert_eq!(earnings[0].royalty_amount, royalty);
+    Ok(())
+}
+
+#[test]
+fn rejects_tokens_that_are_not_whitelisted() -> Result<()> {
+    let mut cw20 = Cw20Setup::new(false)?;
+    let price = cw20_price_scaling().calculate_price(3600);
+
+    assert!(cw20.paint(vec![update(0)], price.u128()).is_err());
+    assert_eq!(cw20.balance(&cw20.painter), PAINTER_BALANCE);
+    Ok(())
+}
+
+#[test]
+fn rejects_wrong_cw20_amounts() -> Result<()> {
+    let mut cw20 = Cw20Setup::new(true)?;
+    let price = cw20_price_scaling().calculate_price(3600).u128();
+
+    assert!(cw20.paint(vec![update(0)], price - 1).is_err());
+    assert!(cw20.paint(vec![update(0)], price + 1).is_err());
+    // Native pricing does not apply to the token
+    let native_price = PriceScaling::default().calculate_price(3600).u128();
+    assert!(cw20.paint(vec![update(0)], native_price).is_err());
+    assert_eq!(cw20.balance(&cw20.painter), PAINTER_BALANCE);
+    Ok(())
+}
+
+#[test]
+fn royalty_address_manages_the_whitelist() -> Result<()> {
+    let mut cw20 = Cw20Setup::new(false)?;
+    let token = cw20.token.contract_addr.clone();
+    let painter = cw20.painter.clone();
+    let creator = cw20.creator.clone();
+    let tiles = &cw20.setup.tiles;
+    let app = &mut cw20.setup.app;
+
+    assert!(tiles
+        .execute_set_cw20_price_scaling(app, &painter, &token, Some(cw20_price_scaling()))
+        .is_err());
+
+    let response =
+        tiles.execute_set_cw20_price_scaling(app, &creator, &token, Some(cw20_price_scaling()))?;
+    let event = response
+        .events
+        .iter()
+        .find_map(Cw20PriceScalingUpdateEventData::try_from_event)
+        .expect("cw20 price scaling event");
+    assert_eq!(event.token, token);
+    assert_eq!(event.price_scaling, Some(cw20_price_scaling()));
+
+    let tokens = tiles.query_cw20_tokens(app)?.tokens;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].token, token);
+    assert_eq!(tokens[0].price_scaling, cw20_price_scaling());
+
+    tiles.execute_set_cw20_price_scaling(app, &creator, &token, None)?;
+    assert!(tiles.query_cw20_tokens(app)?.tokens.is_empty());
+    Ok(())
+}
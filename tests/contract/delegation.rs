@@ -0,0 +1,265 @@
+use anyhow::Result;
+use cosmwasm_std::{Addr, Uint128};
+use cw721::Expiration;
+use cw_utils::PaymentError;
+use tiles::{
+    core::tile::{
+        metadata::{PixelUpdate, TileMetadata},
+        policy::{PaintAccess, TilePolicy},
+    },
+    events::{EventData, PainterGrantEventData},
+};
+
+use crate::utils::TestSetup;
+
+struct DelegationSetup {
+    base: TestSetup,
+    owner: Addr,
+    painter: Addr,
+    outsider: Addr,
+    metadata: TileMetadata,
+}
+
+impl DelegationSetup {
+    /// A minted tile whose owner only lets themselves paint
+    fn new() -> Result<Self> {
+        Self::with(false, PaintAccess::OwnerOnly)
+    }
+
+    fn with(free_owner_painting: bool, access: PaintAccess) -> Result<Self> {
+        let mut base =
+            TestSetup::with_config(|msg| msg.free_owner_painting = Some(free_owner_painting))?;
+        let owner = base.users.get_buyer().address.clone();
+        base.tiles.execute_set_tile_policy(
+            &mut base.app,
+            &owner,
+            1,
+            TilePolicy {
+                access,
+                ..TilePolicy::default()
+            },
+        )?;
+
+        Ok(Self {
+            owner,
+            painter: base.users.pixel_operator().address.clone(),
+            outsider: base.users.creator().address.clone(),
+            metadata: base.state.get_token_metadata(1)?,
+            base,
+        })
+    }
+
+    fn grant(&mut self, expires: Option<Expiration>, max_pixels: Option<u32>) -> Result<()> {
+        self.base.tiles.execute_grant_painter(
+            &mut self.base.app,
+            &self.owner,
+            1,
+            &self.painter,
+            expires,
+            max_pixels,
+        )?;
+        Ok(())
+    }
+
+    /// Paints the first `pixels` pixels of the tile for an hour at the regular price
+    fn paint(&mut self, painter: &Addr, pixels: u32) -> Result<()> {
+        let price = self
+            .base
+            .tiles
+            .query_price_scaling(&self.base.app)?
+            .calculate_price(3600)
+            * Uint128::from(pixels);
+        self.paint_with_funds(painter, pixels, price.u128())
+    }
+
+    fn paint_with_funds(&mut self, painter: &Addr, pixels: u32, funds: u128) -> Result<()> {
+        let updates: Vec<PixelUpdate> = (0..pixels)
+            .map(|id| PixelUpdate::new(id, "#FF0000", 3600))
+            .collect();
+        self.base.tiles.update_pixel_with_funds(
+            &mut self.base.app,
+            painter,
+            1,
+            updates.clone(),
+            funds,
+            self.metadata.clone(),
+        )?;
+        let now = self.base.app.inner().block_info().time.seconds();
+        self.metadata.apply_updates(updates, painter, now);
+        Ok(())
+    }
+}
+
+#[test]
+fn granted_painters_can_paint_owner_only_tiles() -> Result<()> {
+    let mut setup = DelegationSetup::new()?;
+    let (painter, outsider) = (setup.painter.clone(), setup.outsider.clone());
+    assert!(setup.paint(&painter, 1).is_err());
+
+    setup.grant(None, None)?;
+    assert!(setup.paint(&outsider, 1).is_err());
+    setup.paint(&painter, 2)?;
+
+    let painters = setup
+        .base
+        .tiles
+        .query_painters(&setup.base.app, 1)?
+        .painters;
+    assert_eq!(painters.len(), 1);
+    assert_eq!(painters[0].painter, painter);
+    assert_eq!(painters[0].grant.expires, Expiration::Never {});
+    assert_eq!(painters[0].grant.pixels_painted, 2);
+    Ok(())
+}
+
+#[test]
+fn grants_are_capped_by_max_pixels() -> Result<()> {
+    let mut setup = DelegationSetup::new()?;
+    let painter = setup.painter.clone();
+    setup.grant(None, Some(1))?;
+
+    assert!(setup.paint(&painter, 2).is_err());
+    setup.paint(&painter, 1)?;
+    // The allowance is checked before the metadata, so this fails on the grant alone
+    let err = setup.paint(&painter, 1).unwrap_err();
+    assert!(err.root_cause().to_string().contains("does not cover"));
+    Ok(())
+}
+
+#[test]
+fn expired_grants_no_longer_apply() -> Result<()> {
+    let mut setup = DelegationSetup::new()?;
+    let painter = setup.painter.clone();
+    let now = setup.base.app.inner().block_info().time;
+
+    // Already expired grants are refused outright
+    assert!(setup
+        .grant(Some(Expiration::AtTime(now.minus_seconds(1))), None)
+        .is_err());
+
+    setup.grant(Some(Expiration::AtTime(now.plus_seconds(100))), None)?;
+    setup.base.app.advance_time(200);
+
+    assert!(setup.paint(&painter, 1).is_err());
+    assert!(setup
+        .base
+        .tiles
+        .query_painters(&setup.base.app, 1)?
+        .painters
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+fn only_the_owner_grants_and_revokes() -> Result<()> {
+    let mut setup = DelegationSetup::new()?;
+    let (owner, painter, outsider) = (
+        setup.owner.clone(),
+        setup.painter.clone(),
+        setup.outsider.clone(),
+    );
+    let tiles = &setup.base.tiles;
+    let app = &mut setup.base.app;
+
+    assert!(tiles
+        .execute_grant_painter(app, &outsider, 1, &painter, None, None)
+        .is_err());
+    assert!(tiles
+        .execute_grant_painter(app, &owner, 1, &owner, None, None)
+        .is_err());
+
+    let response = tiles.execute_grant_painter(app, &owner, 1, &painter, None, Some(5))?;
+    let event = response
+        .events
+        .iter()
+        .find_map(PainterGrantEventData::try_from_event)
+        .expect("painter grant event");
+    assert_eq!(event.painter, painter);
+    assert_eq!(event.grant.map(|grant| grant.max_pixels), Some(Some(5)));
+
+    assert!(tiles
+        .execute_revoke_painter(app, &outsider, 1, &painter)
+        .is_err());
+    let response = tiles.execute_revoke_painter(app, &owner, 1, &painter)?;
+    let event = response
+        .events
+        .iter()
+        .find_map(PainterGrantEventData::try_from_event)
+        .expect("painter grant event");
+    assert_eq!(event.grant, None);
+    assert!(tiles.query_painters(app, 1)?.painters.is_empty());
+    assert!(tiles
+        .execute_revoke_painter(app, &owner, 1, &painter)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn owners_paint_for_free_with_free_owner_painting() -> Result<()> {
+    let mut setup = DelegationSetup::with(true, PaintAccess::Anyone)?;
+    let (owner, outsider) = (setup.owner.clone(), setup.outsider.clone());
+
+    setup.paint_with_funds(&owner, 2, 0)?;
+    let err = setup.paint_with_funds(&owner, 1, 1).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        PaymentError::NonPayable {}.to_string()
+    );
+
+    // Everyone else still pays
+    assert!(setup.paint_with_funds(&outsider, 1, 0).is_err());
+    setup.paint(&outsider, 1)?;
+    Ok(())
+}
+
+#[test]
+fn grantees_paint_for_free_within_their_grant() -> Result<()> {
+    let mut setup = DelegationSetup::with(true, PaintAccess::Anyone)?;
+    let painter = setup.painter.clone();
+    setup.grant(None, Some(1))?;
+
+    setup.paint_with_funds(&painter, 1, 0)?;
+    // Past the grant the tile is open to them at the regular price
+    assert!(setup.paint_with_funds(&painter, 1, 0).is_err());
+    setup.paint(&painter, 1)?;
+
+    let painters = setup
+        .base
+        .tiles
+        .query_painters(&setup.base.app, 1)?
+        .painters;
+    assert_eq!(painters[0].grant.pixels_painted, 1);
+    Ok(())
+}
+
+#[test]
+fn owners_pay_without_free_owner_painting() -> Result<()> {
+    let mut setup = DelegationSetup::with(false, PaintAccess::Anyone)?;
+    let owner = setup.owner.clone();
+
+    assert!(setup.paint_with_funds(&owner, 1, 0).is_err());
+    setup.paint(&owner, 1)?;
+    Ok(())
+}
+
+#[test]
+fn transfers_clear_grants() -> Result<()> {
+    let mut setup = DelegationSetup::new()?;
+    let (owner, outsider) = (setup.owner.clone(), setup.outsider.clone());
+    setup.grant(None, None)?;
+
+    setup.base.tiles.execute_transfer_nft(
+        &mut setup.base.app,
+        &owner,
+        &outsider,
+        "1".to_string(),
+    )?;
+
+    assert!(setup
+        .base
+        .tiles
+        .query_painters(&setup.base.app, 1)?
+        .painters
+        .is_empty());
+    Ok(())
+}
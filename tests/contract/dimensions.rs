@@ -0,0 +1,66 @@
+use anyhow::Result;
+use tiles::core::{
+    config::Config,
+    tile::metadata::{PixelUpdate, TileMetadata},
+};
+
+use crate::utils::TestSetup;
+
+// Same suite runs for square, wide and tall tiles
+const DIMENSIONS: [(u32, u32); 3] = [(16, 16), (32, 8), (8, 32)];
+
+fn update(id: u32) -> PixelUpdate {
+    PixelUpdate::new(id, "#FF0000", 3600)
+}
+
+#[test]
+fn tiles_follow_configured_dimensions() -> Result<()> {
+    for (tile_width, tile_height) in DIMENSIONS {
+        let TestSetup {
+            mut app,
+            users,
+            tiles,
+            ..
+        } = TestSetup::with_config(|msg| {
+            msg.tile_width = Some(tile_width);
+            msg.tile_height = Some(tile_height);
+        })?;
+        let buyer = users.get_buyer().address.clone();
+        let config = tiles.query_config(&app)?;
+        assert_eq!(
+            (config.tile_width, config.tile_height),
+            (tile_width, tile_height)
+        );
+
+        let metadata = TileMetadata::new(&config);
+        assert_eq!(tiles.query_token_hash(&app, 1)?, metadata.hash());
+
+        // The last pixel exists, the one after it does not
+        let last = tile_width * tile_height - 1;
+        let result = tiles.update_pixel(
+            &mut app,
+            &buyer,
+            1,
+            vec![update(last + 1)],
+            metadata.clone(),
+        );
+        assert!(result.is_err(), "Expected error for out of bounds pixel");
+        tiles.update_pixel(&mut app, &buyer, 1, vec![update(last)], metadata)?;
+
+        let svg = tiles.query_tile_svg(&app, 1, Some(1), None)?;
+        assert!(svg.contains(&format!(r#"viewBox="0 0 {tile_width} {tile_height}""#)));
+        assert!(svg.contains(&format!(
+            r##"<rect x="{}" y="{}" width="1" height="1" fill="#FF0000"/>"##,
+            tile_width - 1,
+            tile_height - 1
+        )));
+    }
+    Ok(())
+}
+
+#[test]
+fn default_config_keeps_ten_by_ten_tiles() {
+    let config = Config::default();
+    assert_eq!((config.tile_width, config.tile_height), (10, 10));
+    assert_eq!(TileMetadata::default().pixels.len(), 100);
+}
@@ -0,0 +1,104 @@
+use anyhow::Result;
+use cosmwasm_std::{Decimal, Uint128};
+use sg_std::NATIVE_DENOM;
+use tiles::{
+    core::{earnings::Earnings, tile::metadata::PixelUpdate},
+    defaults::constants::DEFAULT_ROYALTY_SHARE,
+};
+
+use crate::utils::TestSetup;
+
+fn updates(ids: &[u32]) -> Vec<PixelUpdate> {
+    ids.iter()
+        .map(|id| PixelUpdate::new(*id, "#FF0000", 3600))
+        .collect()
+}
+
+/// Earnings for painting `pixels` pixels for an hour, split like the contract does
+fn expected(setup: &TestSetup, pixels: u128) -> Result<Earnings> {
+    let price_scaling = setup.tiles.query_price_scaling(&setup.app)?;
+    let total = price_scaling.calculate_price(3600) * Uint128::new(pixels);
+    let royalty_amount = total * Decimal::percent(DEFAULT_ROYALTY_SHARE);
+    Ok(Earnings {
+        denom: NATIVE_DENOM.to_string(),
+        owner_amount: total - royalty_amount,
+        royalty_amount,
+        split_amount: Uint128::zero(),
+    })
+}
+
+#[test]
+fn unpainted_tiles_have_no_earnings() -> Result<()> {
+    let (setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+
+    let tile = setup.tiles.query_tile_earnings(&setup.app, token_id)?;
+    assert_eq!(tile.token_id, token_id.to_string());
+    assert!(tile.earnings.is_empty());
+    assert!(setup
+        .tiles
+        .query_owner_earnings(&setup.app, &buyer)?
+        .earnings
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+fn paints_accumulate_tile_and_owner_earnings() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+    let operator = setup.users.pixel_operator().address.clone();
+
+    // Amounts are recorded per paint, so sum the payments rather than the pixels
+    setup.update_pixel(&operator, token_id, updates(&[0, 1]))?;
+    setup.update_pixel(&operator, token_id, updates(&[2]))?;
+    let two = expected(&setup, 2)?;
+    let one = expected(&setup, 1)?;
+    let total = Earnings {
+        denom: NATIVE_DENOM.to_string(),
+        owner_amount: two.owner_amount + one.owner_amount,
+        royalty_amount: two.royalty_amount + one.royalty_amount,
+        split_amount: Uint128::zero(),
+    };
+
+    let tile = setup.tiles.query_tile_earnings(&setup.app, token_id)?;
+    assert_eq!(tile.earnings, vec![total.clone()]);
+
+    let owner = setup.tiles.query_owner_earnings(&setup.app, &buyer)?;
+    assert_eq!(owner.owner, buyer);
+    assert_eq!(owner.earnings, vec![total]);
+
+    // Painters earn nothing as painters
+    assert!(setup
+        .tiles
+        .query_owner_earnings(&setup.app, &operator)?
+        .earnings
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+fn owner_earnings_follow_the_owner_at_paint_time() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+    let operator = setup.users.pixel_operator().address.clone();
+
+    setup.update_pixel(&operator, token_id, updates(&[0]))?;
+    setup
+        .tiles
+        .execute_transfer_nft(&mut setup.app, &buyer, &operator, token_id.to_string())?;
+    setup.update_pixel(&buyer, token_id, updates(&[1, 2]))?;
+
+    let buyer_earnings = setup.tiles.query_owner_earnings(&setup.app, &buyer)?;
+    assert_eq!(buyer_earnings.earnings, vec![expected(&setup, 1)?]);
+    let operator_earnings = setup.tiles.query_owner_earnings(&setup.app, &operator)?;
+    assert_eq!(operator_earnings.earnings, vec![expected(&setup, 2)?]);
+
+    // The tile keeps its full history across owners
+    let tile = setup.tiles.query_tile_earnings(&setup.app, token_id)?;
+    assert_eq!(
+        tile.earnings[0].owner_amount,
+        buyer_earnings.earnings[0].owner_amount + operator_earnings.earnings[0].owner_amount
+    );
+    Ok(())
+}
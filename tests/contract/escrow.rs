@@ -0,0 +1,224 @@
+use anyhow::Result;
+use cosmwasm_std::{coin, Addr, Coin, Decimal, Uint128};
+use cw_multi_test::Executor;
+use cw_utils::PaymentError;
+use sg_std::NATIVE_DENOM;
+use tiles::{
+    contract::msg::{ExecuteMsg, TileExecuteMsg},
+    core::{
+        config::PayoutMode,
+        tile::metadata::{PixelUpdate, TileMetadata},
+    },
+    defaults::constants::DEFAULT_ROYALTY_SHARE,
+    events::{EventData, WithdrawEventData},
+};
+
+use crate::utils::TestSetup;
+
+struct EscrowSetup {
+    base: TestSetup,
+    owner: Addr,
+    creator: Addr,
+    painter: Addr,
+}
+
+impl EscrowSetup {
+    fn new(payout_mode: PayoutMode) -> Result<Self> {
+        let base = TestSetup::with_config(|msg| msg.payout_mode = Some(payout_mode))?;
+        Ok(Self {
+            owner: base.users.get_buyer().address.clone(),
+            creator: base.users.tile_contract_creator().address.clone(),
+            painter: base.users.pixel_operator().address.clone(),
+            base,
+        })
+    }
+
+    /// Paints one pixel for an hour, returning the (owner, royalty) split
+    fn paint(&mut self) -> Result<(u128, u128)> {
+        let update = PixelUpdate::new(0, "#FF0000", 3600);
+        // Each test paints once, so the tile is still blank
+        let metadata = TileMetadata::new(&self.base.tiles.query_config(&self.base.app)?);
+        self.base.tiles.update_pixel(
+            &mut self.base.app,
+            &self.painter,
+            1,
+            vec![update.clone()],
+            metadata,
+        )?;
+
+        let total = self
+            .base
+            .tiles
+            .query_price_scaling(&self.base.app)?
+            .calculate_price(update.expiration_duration);
+        let royalty = total * Decimal::percent(DEFAULT_ROYALTY_SHARE);
+        Ok(((total - royalty).u128(), royalty.u128()))
+    }
+
+    fn balance(&self, address: &Addr) -> u128 {
+        self.base.app.get_balance(address, NATIVE_DENOM).unwrap()
+    }
+
+    fn pending(&self, address: &Addr) -> Vec<Coin> {
+        self.base
+            .tiles
+            .query_pending_balance(&self.base.app, address)
+            .unwrap()
+            .balances
+    }
+}
+
+#[test]
+fn direct_mode_pays_immediately() -> Result<()> {
+    let mut setup = EscrowSetup::new(PayoutMode::Direct)?;
+    let owner_before = setup.balance(&setup.owner);
+    let creator_before = setup.balance(&setup.creator);
+
+    let (owner_amount, royalty_amount) = setup.paint()?;
+
+    assert_eq!(setup.balance(&setup.owner), owner_before + owner_amount);
+    assert_eq!(
+        setup.balance(&setup.creator),
+        creator_before + royalty_amount
+    );
+    assert!(setup.pending(&setup.owner).is_empty());
+    Ok(())
+}
+
+#[test]
+fn escrow_mode_credits_pending_balances() -> Result<()> {
+    let mut setup = EscrowSetup::new(PayoutMode::Escrow)?;
+    let owner_before = setup.balance(&setup.owner);
+    let creator_before = setup.balance(&setup.creator);
+
+    let (owner_amount, royalty_amount) = setup.paint()?;
+
+    // Nothing moves until withdrawn, the contract holds the payment
+    assert_eq!(setup.balance(&setup.owner), owner_before);
+    assert_eq!(setup.balance(&setup.creator), creator_before);
+    assert_eq!(
+        setup.balance(&setup.base.tiles.contract_addr),
+        owner_amount + royalty_amount
+    );
+    assert_eq!(
+        setup.pending(&setup.owner),
+        vec![coin(owner_amount, NATIVE_DENOM)]
+    );
+    assert_eq!(
+        setup.pending(&setup.creator),
+        vec![coin(royalty_amount, NATIVE_DENOM)]
+    );
+    Ok(())
+}
+
+#[test]
+fn withdraw_pays_out_pending_balance() -> Result<()> {
+    let mut setup = EscrowSetup::new(PayoutMode::Escrow)?;
+    let (owner_amount, _) = setup.paint()?;
+    let owner = setup.owner.clone();
+    let before = setup.balance(&owner);
+
+    // Partial withdrawal leaves the rest pending
+    let response = setup.base.tiles.execute_withdraw(
+        &mut setup.base.app,
+        &owner,
+        NATIVE_DENOM,
+        Some(1_000),
+    )?;
+    let event = response
+        .events
+        .iter()
+        .find_map(WithdrawEventData::try_from_event)
+        .expect("withdraw event");
+    assert_eq!(event.address, owner);
+    assert_eq!(event.amount, 1_000);
+    assert_eq!(setup.balance(&owner), before + 1_000);
+    assert_eq!(
+        setup.pending(&owner),
+        vec![coin(owner_amount - 1_000, NATIVE_DENOM)]
+    );
+
+    // Omitting the amount withdraws everything that is left
+    setup
+        .base
+        .tiles
+        .execute_withdraw(&mut setup.base.app, &owner, NATIVE_DENOM, None)?;
+    assert_eq!(setup.balance(&owner), before + owner_amount);
+    assert!(setup.pending(&owner).is_empty());
+    Ok(())
+}
+
+#[test]
+fn withdraw_rejects_more_than_pending() -> Result<()> {
+    let mut setup = EscrowSetup::new(PayoutMode::Escrow)?;
+    let (owner_amount, _) = setup.paint()?;
+    let owner = setup.owner.clone();
+    let painter = setup.painter.clone();
+    let app = &mut setup.base.app;
+
+    assert!(setup
+        .base
+        .tiles
+        .execute_withdraw(app, &owner, NATIVE_DENOM, Some(owner_amount + 1))
+        .is_err());
+    assert!(setup
+        .base
+        .tiles
+        .execute_withdraw(app, &owner, "uatom", None)
+        .is_err());
+    assert!(setup
+        .base
+        .tiles
+        .execute_withdraw(app, &painter, NATIVE_DENOM, None)
+        .is_err());
+    assert!(setup
+        .base
+        .tiles
+        .execute_withdraw(app, &owner, NATIVE_DENOM, Some(0))
+        .is_err());
+    assert_eq!(
+        setup
+            .base
+            .tiles
+            .query_pending_balance(app, &owner)?
+            .balances[0]
+            .amount,
+        Uint128::new(owner_amount)
+    );
+    Ok(())
+}
+
+#[test]
+fn withdraw_rejects_attached_funds() -> Result<()> {
+    let mut setup = EscrowSetup::new(PayoutMode::Escrow)?;
+    let (owner_amount, _) = setup.paint()?;
+    let owner = setup.owner.clone();
+    let before = setup.balance(&owner);
+
+    let err = setup
+        .base
+        .app
+        .inner_mut()
+        .execute_contract(
+            owner.clone(),
+            setup.base.tiles.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::Withdraw {
+                    denom: NATIVE_DENOM.to_string(),
+                    amount: None,
+                },
+            },
+            &[coin(1, NATIVE_DENOM)],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        PaymentError::NonPayable {}.to_string()
+    );
+    assert_eq!(setup.balance(&owner), before);
+    assert_eq!(
+        setup.pending(&owner),
+        vec![coin(owner_amount, NATIVE_DENOM)]
+    );
+    Ok(())
+}
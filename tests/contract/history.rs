@@ -0,0 +1,272 @@
+use anyhow::Result;
+use cosmwasm_std::Addr;
+use tiles::core::tile::{
+    history::PixelHistoryEntry,
+    metadata::{PixelData, PixelUpdate, TileMetadata},
+};
+
+use crate::utils::TestSetup;
+
+struct HistorySetup {
+    base: TestSetup,
+    buyer: Addr,
+    metadata: TileMetadata,
+}
+
+impl HistorySetup {
+    fn new(depth: Option<u32>) -> Result<Self> {
+        let base = TestSetup::with_config(|msg| msg.pixel_history_depth = depth)?;
+        Ok(Self {
+            buyer: base.users.get_buyer().address.clone(),
+            metadata: base.state.get_token_metadata(1)?,
+            base,
+        })
+    }
+
+    /// Paints the given pixels in a new block, returning the block time
+    fn paint(&mut self, pixels: &[(u32, &str)]) -> Result<u64> {
+        self.base.app.advance_time(10);
+        self.paint_in_same_block(pixels)
+    }
+
+    fn paint_in_same_block(&mut self, pixels: &[(u32, &str)]) -> Result<u64> {
+        let now = self.base.app.inner().block_info().time.seconds();
+        let updates: Vec<PixelUpdate> = pixels
+            .iter()
+            .map(|(id, color)| PixelUpdate::new(*id, color.to_string(), 3600))
+            .collect();
+        self.base.tiles.update_pixel(
+            &mut self.base.app,
+            &self.buyer,
+            1,
+            updates.clone(),
+            self.metadata.clone(),
+        )?;
+        self.metadata.apply_updates(updates, &self.buyer, now);
+        Ok(now)
+    }
+
+    fn pixel_history(
+        &self,
+        pixel_id: u32,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Vec<PixelHistoryEntry> {
+        self.base
+            .tiles
+            .query_pixel_history(&self.base.app, 1, pixel_id, start_after, limit)
+            .unwrap()
+            .entries
+    }
+
+    fn tile_history(&self, since: Option<u64>, limit: Option<u32>) -> Vec<PixelHistoryEntry> {
+        self.base
+            .tiles
+            .query_tile_history(&self.base.app, 1, since, limit)
+            .unwrap()
+            .entries
+    }
+}
+
+fn seqs_and_colors(entries: &[PixelHistoryEntry]) -> Vec<(u64, &str)> {
+    entries
+        .iter()
+        .map(|entry| (entry.seq, entry.pixel.color.as_str()))
+        .collect()
+}
+
+const COLORS: [&str; 5] = ["#000001", "#000002", "#000003", "#000004", "#000005"];
+
+#[test]
+fn history_is_disabled_by_default() -> Result<()> {
+    let mut setup = HistorySetup::new(None)?;
+    setup.paint(&[(0, "#FF0000")])?;
+
+    assert!(setup.pixel_history(0, None, None).is_empty());
+    assert!(setup.tile_history(None, None).is_empty());
+    Ok(())
+}
+
+#[test]
+fn pixel_history_keeps_the_latest_paints() -> Result<()> {
+    let mut setup = HistorySetup::new(Some(3))?;
+    for color in COLORS {
+        setup.paint(&[(7, color)])?;
+    }
+
+    // Only the last three paints survive, newest first
+    let entries = setup.pixel_history(7, None, None);
+    assert_eq!(
+        seqs_and_colors(&entries),
+        vec![(4, "#000005"), (3, "#000004"), (2, "#000003")]
+    );
+    assert_eq!(
+        entries[0].pixel,
+        PixelData {
+            id: 7,
+            ..setup.metadata.pixels[7].clone()
+        }
+    );
+    assert!(setup.pixel_history(8, None, None).is_empty());
+    Ok(())
+}
+
+#[test]
+fn pixel_history_pages_backwards() -> Result<()> {
+    let mut setup = HistorySetup::new(Some(4))?;
+    for color in COLORS {
+        setup.paint(&[(0, color)])?;
+    }
+
+    let first = setup.pixel_history(0, None, Some(2));
+    assert_eq!(
+        seqs_and_colors(&first),
+        vec![(4, "#000005"), (3, "#000004")]
+    );
+    let second = setup.pixel_history(0, Some(first[1].seq), Some(2));
+    assert_eq!(
+        seqs_and_colors(&second),
+        vec![(2, "#000003"), (1, "#000002")]
+    );
+    assert!(setup
+        .pixel_history(0, Some(second[1].seq), Some(2))
+        .is_empty());
+    Ok(())
+}
+
+#[test]
+fn pixel_history_rejects_unknown_pixels_and_tokens() -> Result<()> {
+    let setup = HistorySetup::new(Some(2))?;
+    let app = &setup.base.app;
+
+    assert!(setup
+        .base
+        .tiles
+        .query_pixel_history(app, 1, 100, None, None)
+        .is_err());
+    assert!(setup
+        .base
+        .tiles
+        .query_pixel_history(app, 2, 0, None, None)
+        .is_err());
+    assert!(setup
+        .base
+        .tiles
+        .query_tile_history(app, 2, None, None)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn tile_history_lists_paints_since_a_time() -> Result<()> {
+    let mut setup = HistorySetup::new(Some(2))?;
+    let first = setup.paint(&[(0, "#000001"), (5, "#000002")])?;
+    let second = setup.paint(&[(3, "#000003")])?;
+    setup.paint(&[(0, "#000004")])?;
+
+    let all = setup.tile_history(None, None);
+    let pixels: Vec<(u64, u32)> = all
+        .iter()
+        .map(|entry| (entry.pixel.last_updated_at, entry.pixel.id))
+        .collect();
+    assert_eq!(
+        pixels,
+        vec![(first, 0), (first, 5), (second, 3), (second + 10, 0)]
+    );
+
+    let since_first = setup.tile_history(Some(first), None);
+    assert_eq!(
+        seqs_and_colors(&since_first),
+        vec![(0, "#000003"), (1, "#000004")]
+    );
+    Ok(())
+}
+
+#[test]
+fn tile_history_drops_evicted_paints() -> Result<()> {
+    let mut setup = HistorySetup::new(Some(2))?;
+    for color in COLORS {
+        setup.paint(&[(1, color)])?;
+    }
+
+    let entries = setup.tile_history(None, None);
+    assert_eq!(
+        seqs_and_colors(&entries),
+        vec![(3, "#000004"), (4, "#000005")]
+    );
+    Ok(())
+}
+
+#[test]
+fn tile_history_pages_end_on_a_paint_boundary() -> Result<()> {
+    let mut setup = HistorySetup::new(Some(4))?;
+    let first = setup.paint(&[(0, "#000001"), (1, "#000001")])?;
+    let second = setup.paint(&[(2, "#000002"), (3, "#000002")])?;
+    setup.paint(&[(4, "#000003")])?;
+
+    // The second paint does not fit, so the page stops after the first one
+    let page = setup.tile_history(None, Some(3));
+    assert_eq!(page.len(), 2);
+    assert!(page
+        .iter()
+        .all(|entry| entry.pixel.last_updated_at == first));
+
+    let page = setup.tile_history(Some(first), Some(3));
+    assert_eq!(page.len(), 3);
+
+    // A single paint larger than the limit is returned whole
+    let page = setup.tile_history(Some(first), Some(1));
+    assert_eq!(page.len(), 2);
+    assert!(page
+        .iter()
+        .all(|entry| entry.pixel.last_updated_at == second));
+    Ok(())
+}
+
+#[test]
+fn tile_history_keeps_repaints_within_a_block() -> Result<()> {
+    let mut setup = HistorySetup::new(Some(4))?;
+    let painted_at = setup.paint(&[(2, "#000001")])?;
+    setup.paint_in_same_block(&[(2, "#000002")])?;
+
+    let entries = setup.tile_history(None, None);
+    assert_eq!(
+        seqs_and_colors(&entries),
+        vec![(0, "#000001"), (1, "#000002")]
+    );
+    assert!(entries
+        .iter()
+        .all(|entry| entry.pixel.last_updated_at == painted_at));
+    Ok(())
+}
+
+#[test]
+fn burning_a_tile_clears_its_history() -> Result<()> {
+    let mut setup = HistorySetup::new(Some(4))?;
+    setup.paint(&[(0, "#000001"), (1, "#000002")])?;
+    setup.paint(&[(0, "#000003")])?;
+
+    // Pixels must expire before the tile can be burned
+    setup.base.app.advance_time(3600);
+    setup
+        .base
+        .tiles
+        .execute_burn(&mut setup.base.app, &setup.buyer, "1".to_string())?;
+
+    // A token minted again under the same ID starts without history
+    let minter = setup.base.minter.contract_addr.clone();
+    setup
+        .base
+        .tiles
+        .execute_mint(&mut setup.base.app, &minter, &setup.buyer, 1)?;
+    assert!(setup.pixel_history(0, None, None).is_empty());
+    assert!(setup.tile_history(None, None).is_empty());
+
+    setup.metadata = TileMetadata::new(&setup.base.tiles.query_config(&setup.base.app)?);
+    setup.paint(&[(0, "#000004")])?;
+    assert_eq!(
+        seqs_and_colors(&setup.pixel_history(0, None, None)),
+        vec![(0, "#000004")]
+    );
+    Ok(())
+}
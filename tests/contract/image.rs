@@ -0,0 +1,153 @@
+use anyhow::Result;
+use cosmwasm_std::Addr;
+use tiles::{
+    core::tile::{image::ImageFormat, metadata::TileMetadata},
+    defaults::constants::{DEFAULT_COLOR, ONE_HOUR},
+};
+
+use crate::utils::TestSetup;
+
+const WHITE: [u8; 3] = [0xFF, 0xFF, 0xFF];
+const RED: [u8; 3] = [0xFF, 0x00, 0x00];
+const BLUE: [u8; 3] = [0x00, 0x00, 0xFF];
+
+struct ImageSetup {
+    base: TestSetup,
+    painter: Addr,
+    metadata: TileMetadata,
+    pixel_price: u128,
+}
+
+impl ImageSetup {
+    fn new() -> Result<Self> {
+        let base = TestSetup::with_config(|_| {})?;
+        Ok(Self {
+            painter: base.users.pixel_operator().address.clone(),
+            metadata: base.state.get_token_metadata(1)?,
+            pixel_price: base
+                .tiles
+                .query_price_scaling(&base.app)?
+                .calculate_price(ONE_HOUR)
+                .u128(),
+            base,
+        })
+    }
+
+    /// Paints the image and tracks the pixels it changed
+    fn paint(&mut self, format: ImageFormat, data: Vec<u8>, pixels: u128) -> Result<()> {
+        let now = self.base.app.inner().block_info().time.seconds();
+        let colors = format.decode(&data, 100)?;
+        self.base.tiles.execute_paint_image(
+            &mut self.base.app,
+            &self.painter,
+            1,
+            format,
+            data,
+            pixels * self.pixel_price,
+        )?;
+        let updates = self
+            .metadata
+            .image_updates(&colors, DEFAULT_COLOR, now, ONE_HOUR);
+        self.metadata.apply_updates(updates, &self.painter, now);
+        self.base
+            .tiles
+            .assert_token_hash(&self.base.app, 1, &self.metadata.hash());
+        Ok(())
+    }
+}
+
+/// White tile with the given pixels colored in
+fn bitmap(colored: &[(usize, [u8; 3])]) -> Vec<u8> {
+    let mut pixels = vec![WHITE; 100];
+    for (id, color) in colored {
+        pixels[*id] = *color;
+    }
+    pixels.concat()
+}
+
+#[test]
+fn only_changed_pixels_are_painted_and_paid() -> Result<()> {
+    let mut setup = ImageSetup::new()?;
+    let image = bitmap(&[(0, RED), (99, BLUE)]);
+
+    // Paying for the whole tile is rejected, only the two colored pixels change
+    assert!(setup.paint(ImageFormat::Rgb, image.clone(), 100).is_err());
+    setup.paint(ImageFormat::Rgb, image, 2)?;
+    assert_eq!(setup.metadata.pixels[0].color, "#FF0000");
+    assert_eq!(setup.metadata.pixels[99].color, "#0000FF");
+    assert_eq!(setup.metadata.live_pixels(0), 2);
+
+    // Repainting the same image changes nothing
+    let unchanged = setup.base.tiles.execute_paint_image(
+        &mut setup.base.app,
+        &setup.painter,
+        1,
+        ImageFormat::Rgb,
+        bitmap(&[(0, RED), (99, BLUE)]),
+        setup.pixel_price,
+    );
+    assert!(unchanged
+        .unwrap_err()
+        .root_cause()
+        .to_string()
+        .contains("does not change any pixel"));
+
+    setup.paint(
+        ImageFormat::Rgb,
+        bitmap(&[(0, RED), (50, RED), (99, BLUE)]),
+        1,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn run_length_encoded_images_paint_like_raw_ones() -> Result<()> {
+    let mut setup = ImageSetup::new()?;
+    let runs = [[1, 0xFF, 0x00, 0x00], [255, 0xFF, 0xFF, 0xFF]];
+    // A run longer than the tile is rejected before anything is painted
+    assert!(setup
+        .base
+        .tiles
+        .execute_paint_image(
+            &mut setup.base.app,
+            &setup.painter,
+            1,
+            ImageFormat::RgbRle,
+            runs.concat(),
+            setup.pixel_price,
+        )
+        .is_err());
+
+    let runs = [
+        [1, 0xFF, 0x00, 0x00],
+        [98, 0xFF, 0xFF, 0xFF],
+        [1, 0x00, 0x00, 0xFF],
+    ];
+    setup.paint(ImageFormat::RgbRle, runs.concat(), 2)?;
+    assert_eq!(setup.metadata.pixels[0].color, "#FF0000");
+    assert_eq!(setup.metadata.pixels[99].color, "#0000FF");
+
+    Ok(())
+}
+
+#[test]
+fn images_must_cover_the_tile() -> Result<()> {
+    let mut setup = ImageSetup::new()?;
+    let mut image = bitmap(&[(0, RED)]);
+    image.truncate(297);
+    assert!(setup
+        .base
+        .tiles
+        .execute_paint_image(
+            &mut setup.base.app,
+            &setup.painter,
+            1,
+            ImageFormat::Rgb,
+            image,
+            setup.pixel_price,
+        )
+        .is_err());
+
+    Ok(())
+}
@@ -1,7 +1,15 @@
 use anyhow::Result;
-use tiles::core::pricing::PriceScaling;
+use cosmwasm_std::{Decimal, Uint128};
+use tiles::core::{
+    config::{Config, PayoutMode, UnrevealedCommits},
+    outbid::OutbidConfig,
+    pricing::PriceScaling,
+    revenue::RevenueSplit,
+};
 
-use crate::utils::{ContractAssertions, EventAssertions, Launchpad};
+use crate::utils::{
+    contracts::tiles::TilesContract, ContractAssertions, EventAssertions, EventParser, Launchpad,
+};
 
 #[test]
 fn can_instantiate_contracts() -> Result<()> {
@@ -20,3 +28,122 @@ fn can_instantiate_contracts() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn factory_instantiate_uses_default_config() -> Result<()> {
+    let (launchpad, response) = Launchpad::setup()?;
+
+    let event = EventParser::parse_instantiate_event(&response)?;
+    let config: Config = serde_json::from_str(&event.config)?;
+    assert_eq!(config, Config::default());
+    assert_eq!(
+        launchpad.tiles.query_config(&launchpad.app)?,
+        Config::default()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_instantiate_with_custom_config() -> Result<()> {
+    let (mut launchpad, _) = Launchpad::setup()?;
+    let code_id = launchpad.factory.collection_code_id.unwrap();
+    let minter = launchpad.minter.contract_addr.clone();
+    let creator = launchpad.users.tile_contract_creator().address.clone();
+
+    let price_scaling = PriceScaling {
+        hour_1_price: Uint128::new(1_000),
+        hour_12_price: Uint128::new(2_000),
+        hour_24_price: Uint128::new(3_000),
+        quadratic_base: Uint128::new(4_000),
+        annotation_byte_price: Uint128::zero(),
+    };
+    let expected = Config {
+        denom: "uatom".to_string(),
+        pixel_min_expiration: 60,
+        pixel_max_expiration: 7200,
+        tile_width: 32,
+        tile_height: 8,
+        default_color: "#000000".to_string(),
+        pixel_history_depth: 4,
+        payout_mode: PayoutMode::Escrow,
+        revenue_splits: vec![RevenueSplit {
+            recipient: launchpad.users.creator().address.clone(),
+            basis_points: 500,
+        }],
+        min_price_multiplier: Decimal::percent(50),
+        max_price_multiplier: Decimal::percent(300),
+        outbid: Some(OutbidConfig {
+            premium_bps: 2_000,
+            displaced_share_bps: 5_000,
+        }),
+        reveal_window_blocks: 5,
+        unrevealed_commits: UnrevealedCommits::Forfeit,
+        keyframe_surcharge_bps: 2_500,
+        moderators: vec![launchpad.users.creator().address.clone()],
+        free_owner_painting: true,
+    };
+    let mut msg = TilesContract::default_instantiate_msg(&minter, &creator);
+    msg.price_scaling = Some(price_scaling.clone());
+    msg.denom = Some(expected.denom.clone());
+    msg.pixel_min_expiration = Some(expected.pixel_min_expiration);
+    msg.pixel_max_expiration = Some(expected.pixel_max_expiration);
+    msg.tile_width = Some(expected.tile_width);
+    msg.tile_height = Some(expected.tile_height);
+    msg.default_color = Some(expected.default_color.clone());
+    msg.pixel_history_depth = Some(expected.pixel_history_depth);
+    msg.payout_mode = Some(expected.payout_mode.clone());
+    msg.revenue_splits = Some(expected.revenue_splits.clone());
+    msg.min_price_multiplier = Some(expected.min_price_multiplier);
+    msg.max_price_multiplier = Some(expected.max_price_multiplier);
+    msg.outbid = expected.outbid.clone();
+    msg.reveal_window_blocks = Some(expected.reveal_window_blocks);
+    msg.unrevealed_commits = Some(expected.unrevealed_commits.clone());
+    msg.keyframe_surcharge_bps = Some(expected.keyframe_surcharge_bps);
+    msg.moderators = Some(expected.moderators.clone());
+    msg.free_owner_painting = Some(expected.free_owner_painting);
+
+    let (tiles, response) = TilesContract::instantiate(&mut launchpad.app, code_id, &minter, &msg)?;
+
+    // The event carries the full effective config
+    EventAssertions::assert_instantiate_price_scaling(&response, &price_scaling)?;
+    let event = EventParser::parse_instantiate_event(&response)?;
+    assert_eq!(serde_json::from_str::<Config>(&event.config)?, expected);
+
+    assert_eq!(tiles.query_config(&launchpad.app)?, expected);
+    ContractAssertions::assert_price_scaling(&launchpad.app, &tiles, &price_scaling);
+
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_config() -> Result<()> {
+    let (mut launchpad, _) = Launchpad::setup()?;
+    let code_id = launchpad.factory.collection_code_id.unwrap();
+    let minter = launchpad.minter.contract_addr.clone();
+    let creator = launchpad.users.tile_contract_creator().address.clone();
+
+    let mut msg = TilesContract::default_instantiate_msg(&minter, &creator);
+    msg.pixel_min_expiration = Some(7200);
+    msg.pixel_max_expiration = Some(3600);
+    let result = TilesContract::instantiate(&mut launchpad.app, code_id, &minter, &msg);
+    assert!(
+        result.is_err(),
+        "Expected error for inverted expiration bounds"
+    );
+
+    let mut msg = TilesContract::default_instantiate_msg(&minter, &creator);
+    msg.default_color = Some("white".to_string());
+    let result = TilesContract::instantiate(&mut launchpad.app, code_id, &minter, &msg);
+    assert!(result.is_err(), "Expected error for invalid default color");
+
+    let mut msg = TilesContract::default_instantiate_msg(&minter, &creator);
+    msg.price_scaling = Some(PriceScaling {
+        hour_1_price: Uint128::zero(),
+        ..PriceScaling::default()
+    });
+    let result = TilesContract::instantiate(&mut launchpad.app, code_id, &minter, &msg);
+    assert!(result.is_err(), "Expected error for zero price");
+
+    Ok(())
+}
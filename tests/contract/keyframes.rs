@@ -0,0 +1,166 @@
+use anyhow::Result;
+use cosmwasm_std::Addr;
+use tiles::core::tile::metadata::{Keyframe, PixelUpdate, TileMetadata};
+
+use crate::utils::TestSetup;
+
+const DURATION: u64 = 3600;
+
+struct KeyframeSetup {
+    base: TestSetup,
+    painter: Addr,
+    metadata: TileMetadata,
+}
+
+impl KeyframeSetup {
+    fn new() -> Result<Self> {
+        let base = TestSetup::with_config(|msg| msg.keyframe_surcharge_bps = Some(2_500))?;
+        Ok(Self {
+            painter: base.users.pixel_operator().address.clone(),
+            metadata: base.state.get_token_metadata(1)?,
+            base,
+        })
+    }
+
+    /// Price of `update` including its keyframe surcharge
+    fn price(&self, update: &PixelUpdate) -> Result<u128> {
+        let config = self.base.tiles.query_config(&self.base.app)?;
+        let price = self
+            .base
+            .tiles
+            .query_price_scaling(&self.base.app)?
+            .calculate_price(update.expiration_duration);
+        Ok((price + config.keyframe_surcharge(price, update.keyframes.len())).u128())
+    }
+
+    fn paint(&mut self, update: PixelUpdate, amount: u128) -> Result<()> {
+        self.base.tiles.update_pixel_with_funds(
+            &mut self.base.app,
+            &self.painter,
+            1,
+            vec![update.clone()],
+            amount,
+            self.metadata.clone(),
+        )?;
+        let now = self.base.app.inner().block_info().time.seconds();
+        self.metadata
+            .apply_updates(vec![update], &self.painter, now);
+        Ok(())
+    }
+
+    fn first_pixel_fill(&self) -> String {
+        let svg = self
+            .base
+            .tiles
+            .query_tile_svg(&self.base.app, 1, Some(1), None)
+            .unwrap();
+        let rect = r#"<rect x="0" y="0" width="1" height="1" fill=""#;
+        let start = svg.find(rect).expect("first pixel") + rect.len();
+        svg[start..start + 7].to_string()
+    }
+}
+
+fn day_night(keyframes: &[(u64, &str)]) -> PixelUpdate {
+    PixelUpdate {
+        id: 0,
+        color: "#FFFF00".to_string(),
+        expiration_duration: DURATION,
+        keyframes: keyframes
+            .iter()
+            .map(|(start_offset, color)| Keyframe {
+                start_offset: *start_offset,
+                color: color.to_string(),
+            })
+            .collect(),
+        annotation: None,
+    }
+}
+
+#[test]
+fn rendering_follows_the_schedule() -> Result<()> {
+    let mut setup = KeyframeSetup::new()?;
+    let update = day_night(&[(1200, "#000080"), (2400, "#000000")]);
+    let price = setup.price(&update)?;
+    setup.paint(update, price)?;
+
+    assert_eq!(setup.first_pixel_fill(), "#FFFF00");
+    setup.base.app.advance_time(1200);
+    assert_eq!(setup.first_pixel_fill(), "#000080");
+    setup.base.app.advance_time(1200);
+    assert_eq!(setup.first_pixel_fill(), "#000000");
+    setup.base.app.advance_time(1200);
+    assert_eq!(setup.first_pixel_fill(), "#FFFFFF");
+
+    Ok(())
+}
+
+#[test]
+fn keyframes_are_surcharged() -> Result<()> {
+    let mut setup = KeyframeSetup::new()?;
+    let update = day_night(&[(1200, "#000080"), (2400, "#000000")]);
+    let base_price = setup.price(&day_night(&[]))?;
+    let price = setup.price(&update)?;
+    assert_eq!(price, base_price + base_price / 2);
+
+    assert!(setup.paint(update.clone(), base_price).is_err());
+    setup.paint(update, price)?;
+
+    Ok(())
+}
+
+#[test]
+fn tile_hash_covers_the_schedule() -> Result<()> {
+    let mut setup = KeyframeSetup::new()?;
+    let update = day_night(&[(1200, "#000080")]);
+    let price = setup.price(&update)?;
+    setup.paint(update, price)?;
+
+    let hash = setup.base.tiles.query_token_hash(&setup.base.app, 1)?;
+    assert_eq!(hash, setup.metadata.hash());
+
+    // Metadata that drops the schedule no longer matches
+    let mut without_schedule = setup.metadata.clone();
+    without_schedule.pixels[0].keyframes.clear();
+    assert_ne!(hash, without_schedule.hash());
+    let next = day_night(&[]);
+    let price = setup.price(&next)?;
+    assert!(setup
+        .base
+        .tiles
+        .update_pixel_with_funds(
+            &mut setup.base.app,
+            &setup.painter,
+            1,
+            vec![next],
+            price,
+            without_schedule,
+        )
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_schedules_are_rejected() -> Result<()> {
+    let mut setup = KeyframeSetup::new()?;
+    let too_many: Vec<(u64, &str)> = (1..=17).map(|i| (i * 60, "#000000")).collect();
+
+    for keyframes in [
+        vec![(0, "#000000")],
+        vec![(1200, "#000000"), (1200, "#FFFFFF")],
+        vec![(2400, "#000000"), (1200, "#FFFFFF")],
+        vec![(DURATION, "#000000")],
+        vec![(1200, "black")],
+        too_many,
+    ] {
+        let update = day_night(&keyframes);
+        let price = setup.price(&update)?;
+        assert!(
+            setup.paint(update, price).is_err(),
+            "Expected {:?} to be rejected",
+            keyframes
+        );
+    }
+
+    Ok(())
+}
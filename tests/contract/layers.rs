@@ -0,0 +1,166 @@
+use anyhow::Result;
+use cosmwasm_std::Addr;
+use tiles::{
+    core::tile::{
+        layer::BasePixel,
+        metadata::{PixelUpdate, TileMetadata},
+    },
+    events::{BaseLayerUpdateEventData, EventData},
+};
+
+use crate::utils::TestSetup;
+
+const BASE: &str = "#0000FF";
+const RENTAL: &str = "#FF0000";
+
+struct LayerSetup {
+    base: TestSetup,
+    owner: Addr,
+    painter: Addr,
+    metadata: TileMetadata,
+}
+
+impl LayerSetup {
+    fn new() -> Result<Self> {
+        let base = TestSetup::with_config(|_| {})?;
+        Ok(Self {
+            owner: base.users.get_buyer().address.clone(),
+            painter: base.users.pixel_operator().address.clone(),
+            metadata: base.state.get_token_metadata(1)?,
+            base,
+        })
+    }
+
+    fn set_base(&mut self, sender: &Addr, pixels: Vec<BasePixel>) -> Result<()> {
+        self.base
+            .tiles
+            .execute_set_base_layer(&mut self.base.app, sender, 1, pixels.clone())?;
+        self.metadata.apply_base_pixels(&pixels);
+        Ok(())
+    }
+
+    fn paint(&mut self, id: u32) -> Result<()> {
+        let updates = vec![PixelUpdate::new(id, RENTAL.to_string(), 3600)];
+        self.base.tiles.update_pixel(
+            &mut self.base.app,
+            &self.painter,
+            1,
+            updates.clone(),
+            self.metadata.clone(),
+        )?;
+        let now = self.base.app.inner().block_info().time.seconds();
+        self.metadata.apply_updates(updates, &self.painter, now);
+        Ok(())
+    }
+
+    fn fill(&self, x: u32, y: u32) -> String {
+        let svg = self
+            .base
+            .tiles
+            .query_tile_svg(&self.base.app, 1, Some(1), None)
+            .unwrap();
+        let rect = format!(r#"<rect x="{x}" y="{y}" width="1" height="1" fill=""#);
+        let start = svg.find(&rect).expect("pixel rect") + rect.len();
+        svg[start..start + 7].to_string()
+    }
+
+    fn pixel_color(&self, id: u32) -> String {
+        self.base
+            .tiles
+            .query_pixel(&self.base.app, 1, id)
+            .unwrap()
+            .color
+    }
+}
+
+fn base(id: u32, color: Option<&str>) -> BasePixel {
+    BasePixel {
+        id,
+        color: color.map(str::to_string),
+    }
+}
+
+#[test]
+fn expired_rental_pixels_show_the_base_layer() -> Result<()> {
+    let mut setup = LayerSetup::new()?;
+    let owner = setup.owner.clone();
+    setup.set_base(&owner, vec![base(0, Some(BASE)), base(1, Some(BASE))])?;
+    assert_eq!(setup.fill(0, 0), BASE);
+
+    setup.paint(0)?;
+    assert_eq!(setup.fill(0, 0), RENTAL);
+    assert_eq!(setup.fill(1, 0), BASE);
+    // The pixel query reports what the tile shows
+    assert_eq!(setup.pixel_color(0), RENTAL);
+    assert_eq!(setup.pixel_color(1), BASE);
+
+    setup.base.app.advance_time(3600);
+    assert_eq!(setup.fill(0, 0), BASE);
+    assert_eq!(setup.pixel_color(0), BASE);
+
+    Ok(())
+}
+
+#[test]
+fn only_the_owner_writes_the_base_layer() -> Result<()> {
+    let mut setup = LayerSetup::new()?;
+    let painter = setup.painter.clone();
+    assert!(setup.set_base(&painter, vec![base(0, Some(BASE))]).is_err());
+    assert_eq!(setup.fill(0, 0), "#FFFFFF");
+
+    Ok(())
+}
+
+#[test]
+fn base_layer_writes_are_validated() -> Result<()> {
+    let mut setup = LayerSetup::new()?;
+    let owner = setup.owner.clone();
+    for pixels in [
+        vec![base(100, Some(BASE))],
+        vec![base(0, Some("blue"))],
+        vec![base(0, Some(BASE)), base(0, None)],
+    ] {
+        assert!(setup.set_base(&owner, pixels).is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn tile_hash_covers_the_base_layer() -> Result<()> {
+    let mut setup = LayerSetup::new()?;
+    let owner = setup.owner.clone();
+    let blank_hash = setup.metadata.hash();
+
+    let response = setup.base.tiles.execute_set_base_layer(
+        &mut setup.base.app,
+        &owner,
+        1,
+        vec![base(0, Some(BASE))],
+    )?;
+    let event = response
+        .events
+        .iter()
+        .find_map(BaseLayerUpdateEventData::try_from_event)
+        .expect("base layer event");
+    assert_ne!(event.tile_hash, blank_hash);
+    setup
+        .base
+        .tiles
+        .assert_token_hash(&setup.base.app, 1, &event.tile_hash);
+
+    // Painters have to know about the base layer to paint
+    assert!(setup.paint(1).is_err());
+    setup.metadata.apply_base_pixels(&event.pixels);
+    setup.paint(1)?;
+
+    // Clearing the layer restores the plain hash of the rental layer
+    setup.set_base(&owner, vec![base(0, None)])?;
+    setup
+        .base
+        .tiles
+        .assert_token_hash(&setup.base.app, 1, &setup.metadata.hash());
+    assert!(setup.metadata.base_layer.is_empty());
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use anyhow::Result;
-use tiles::defaults::constants::MINT_PRICE;
 
-use crate::utils::{ContractAssertions, EventAssertions, EventParser, TestSetup};
+use crate::utils::{
+    contracts::MINT_PRICE, ContractAssertions, EventAssertions, EventParser, TestSetup,
+};
 
 #[test]
 fn test_successful_mint() -> Result<()> {
@@ -1,5 +1,9 @@
+pub mod dimensions;
 pub mod mint;
+pub mod nft_metadata;
+pub mod ownership;
 pub mod pixel;
 pub mod pricescaling;
+pub mod render;
 pub mod sg721_features;
 pub mod instantiate;
\ No newline at end of file
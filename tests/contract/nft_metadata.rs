@@ -0,0 +1,110 @@
+use anyhow::Result;
+use cosmwasm_std::Binary;
+use tiles::core::tile::{metadata::PixelUpdate, nft_metadata::TokenMetadata};
+
+use crate::utils::TestSetup;
+
+fn decode_data_uri(uri: &str, prefix: &str) -> Result<Vec<u8>> {
+    let encoded = uri
+        .strip_prefix(prefix)
+        .ok_or_else(|| anyhow::anyhow!("Unexpected data URI: {}", uri))?;
+    Ok(Binary::from_base64(encoded)?.to_vec())
+}
+
+fn query_token_metadata(setup: &TestSetup, token_id: u32) -> Result<TokenMetadata> {
+    let response = setup.tiles.query_nft_info(&setup.app, token_id)?;
+    let token_uri = response
+        .token_uri
+        .ok_or_else(|| anyhow::anyhow!("Missing token_uri"))?;
+    let json = decode_data_uri(&token_uri, "data:application/json;base64,")?;
+    Ok(serde_json::from_slice(&json)?)
+}
+
+fn attribute(metadata: &TokenMetadata, trait_type: &str) -> String {
+    metadata
+        .attributes
+        .iter()
+        .find(|a| a.trait_type == trait_type)
+        .map(|a| a.value.clone())
+        .unwrap_or_else(|| panic!("Missing attribute {}", trait_type))
+}
+
+#[test]
+fn nft_info_returns_generated_metadata() -> Result<()> {
+    let (setup, token_id) = TestSetup::with_minted_token()?;
+
+    let metadata = query_token_metadata(&setup, token_id)?;
+
+    assert!(metadata.name.ends_with(&format!("#{}", token_id)));
+    assert!(metadata
+        .original_token_uri
+        .as_deref()
+        .is_some_and(|uri| uri.starts_with("ipfs://")));
+    assert_eq!(attribute(&metadata, "Painted Pixels"), "0");
+    assert_eq!(attribute(&metadata, "Live Painters"), "0");
+
+    let svg = decode_data_uri(&metadata.image, "data:image/svg+xml;base64,")?;
+    assert!(String::from_utf8(svg)?.starts_with("<svg"));
+
+    Ok(())
+}
+
+#[test]
+fn nft_info_attributes_follow_painting() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+    let operator = setup.users.pixel_operator().clone();
+
+    let red = |id| PixelUpdate::new(id, "#FF0000", 3600);
+    setup.update_pixel(&buyer.address, token_id, vec![red(0), red(1)])?;
+    setup.update_pixel(&operator.address, token_id, vec![red(2)])?;
+
+    let metadata = query_token_metadata(&setup, token_id)?;
+    assert_eq!(attribute(&metadata, "Painted Pixels"), "3");
+    assert_eq!(attribute(&metadata, "Live Painters"), "2");
+    assert_eq!(
+        attribute(&metadata, "Last Updated"),
+        setup.app.inner().block_info().time.seconds().to_string()
+    );
+
+    let svg = decode_data_uri(&metadata.image, "data:image/svg+xml;base64,")?;
+    assert!(String::from_utf8(svg)?.contains("#FF0000"));
+
+    Ok(())
+}
+
+#[test]
+fn dominant_color_is_most_frequent_displayed_color() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+
+    let updates = (0..60)
+        .map(|id| PixelUpdate::new(id, "#00FF00", 3600))
+        .collect();
+    setup.update_pixel(&buyer.address, token_id, updates)?;
+
+    let metadata = query_token_metadata(&setup, token_id)?;
+    assert_eq!(attribute(&metadata, "Dominant Color"), "#00FF00");
+
+    Ok(())
+}
+
+#[test]
+fn dominant_color_ignores_case() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+
+    // Split by case neither half outnumbers the 40 blank pixels
+    let updates = (0..60)
+        .map(|id| {
+            let color = if id % 2 == 0 { "#00ff00" } else { "#00FF00" };
+            PixelUpdate::new(id, color, 3600)
+        })
+        .collect();
+    setup.update_pixel(&buyer.address, token_id, updates)?;
+
+    let metadata = query_token_metadata(&setup, token_id)?;
+    assert_eq!(attribute(&metadata, "Dominant Color"), "#00FF00");
+
+    Ok(())
+}
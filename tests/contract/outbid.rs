@@ -0,0 +1,200 @@
+use anyhow::Result;
+use cosmwasm_std::{Addr, Uint128};
+use sg_std::NATIVE_DENOM;
+use tiles::{
+    core::{
+        outbid::{OutbidConfig, Takeover},
+        tile::metadata::{PixelUpdate, TileMetadata},
+    },
+    events::{EventData, PixelOutbidEventData},
+};
+
+use crate::utils::TestSetup;
+
+const DURATION: u64 = 3600;
+
+struct OutbidSetup {
+    base: TestSetup,
+    painter: Addr,
+    challenger: Addr,
+    metadata: TileMetadata,
+    price: u128,
+}
+
+impl OutbidSetup {
+    fn new(outbid: Option<OutbidConfig>) -> Result<Self> {
+        let base = TestSetup::with_config(|msg| msg.outbid = outbid)?;
+        Ok(Self {
+            painter: base.users.pixel_operator().address.clone(),
+            challenger: base.users.get_buyer().address.clone(),
+            metadata: base.state.get_token_metadata(1)?,
+            price: base
+                .tiles
+                .query_price_scaling(&base.app)?
+                .calculate_price(DURATION)
+                .u128(),
+            base,
+        })
+    }
+
+    /// Paints the first pixel for an hour, paying `amount`
+    fn paint(&mut self, sender: &Addr, amount: u128) -> Result<Vec<PixelOutbidEventData>> {
+        let updates = vec![PixelUpdate::new(0, "#FF0000", DURATION)];
+        let response = self.base.tiles.update_pixel_with_funds(
+            &mut self.base.app,
+            sender,
+            1,
+            updates.clone(),
+            amount,
+            self.metadata.clone(),
+        )?;
+        let now = self.base.app.inner().block_info().time.seconds();
+        self.metadata.apply_updates(updates, sender, now);
+        Ok(response
+            .events
+            .iter()
+            .filter_map(PixelOutbidEventData::try_from_event)
+            .collect())
+    }
+
+    fn takeover(&self) -> Result<Takeover> {
+        Ok(self
+            .base
+            .tiles
+            .query_takeover_price(&self.base.app, 1, 0)?
+            .takeover)
+    }
+
+    fn balance(&self, address: &Addr) -> u128 {
+        self.base.app.get_balance(address, NATIVE_DENOM).unwrap()
+    }
+}
+
+fn outbid_config() -> Option<OutbidConfig> {
+    Some(OutbidConfig {
+        premium_bps: 2_000,
+        displaced_share_bps: 5_000,
+    })
+}
+
+#[test]
+fn takeover_price_is_prorated_with_premium() -> Result<()> {
+    let mut setup = OutbidSetup::new(outbid_config())?;
+    let painter = setup.painter.clone();
+    setup.paint(&painter, setup.price)?;
+
+    setup.base.app.advance_time(DURATION / 2);
+    let response = setup
+        .base
+        .tiles
+        .query_takeover_price(&setup.base.app, 1, 0)?;
+    let remaining_value = Uint128::new(setup.price / 2);
+    let premium = remaining_value.multiply_ratio(2_000u128, 10_000u128);
+    assert_eq!(response.painter, Some(painter));
+    assert_eq!(response.denom, Some(NATIVE_DENOM.to_string()));
+    assert_eq!(
+        response.takeover,
+        Takeover {
+            remaining_value,
+            premium,
+            displaced_refund: remaining_value + premium.multiply_ratio(1u128, 2u128),
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn challenger_refunds_the_displaced_painter() -> Result<()> {
+    let mut setup = OutbidSetup::new(outbid_config())?;
+    let (painter, challenger) = (setup.painter.clone(), setup.challenger.clone());
+    setup.paint(&painter, setup.price)?;
+
+    setup.base.app.advance_time(DURATION / 2);
+    let takeover = setup.takeover()?;
+    let before = setup.balance(&painter);
+    let events = setup.paint(&challenger, setup.price + takeover.price().u128())?;
+
+    assert_eq!(
+        setup.balance(&painter),
+        before + takeover.displaced_refund.u128()
+    );
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].displaced, painter);
+    assert_eq!(events[0].challenger, challenger);
+    assert_eq!(events[0].refund, takeover.displaced_refund.u128());
+
+    // The challenger now holds the pixel at the full price they paid for it
+    let response = setup
+        .base
+        .tiles
+        .query_takeover_price(&setup.base.app, 1, 0)?;
+    assert_eq!(response.painter, Some(challenger));
+    assert_eq!(response.takeover.remaining_value.u128(), setup.price);
+
+    Ok(())
+}
+
+#[test]
+fn taking_a_live_pixel_requires_the_takeover_price() -> Result<()> {
+    let mut setup = OutbidSetup::new(outbid_config())?;
+    let (painter, challenger) = (setup.painter.clone(), setup.challenger.clone());
+    setup.paint(&painter, setup.price)?;
+
+    setup.base.app.advance_time(DURATION / 2);
+    assert!(setup.paint(&challenger, setup.price).is_err());
+    assert_eq!(setup.metadata.pixels[0].last_updated_by, painter);
+
+    Ok(())
+}
+
+#[test]
+fn expired_pixels_cost_the_regular_price() -> Result<()> {
+    let mut setup = OutbidSetup::new(outbid_config())?;
+    let (painter, challenger) = (setup.painter.clone(), setup.challenger.clone());
+    setup.paint(&painter, setup.price)?;
+
+    setup.base.app.advance_time(DURATION + 1);
+    let response = setup
+        .base
+        .tiles
+        .query_takeover_price(&setup.base.app, 1, 0)?;
+    assert_eq!(response.painter, None);
+    assert_eq!(response.takeover, Takeover::default());
+
+    let events = setup.paint(&challenger, setup.price)?;
+    assert!(events.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn painters_extend_their_own_pixels_at_the_regular_price() -> Result<()> {
+    let mut setup = OutbidSetup::new(outbid_config())?;
+    let painter = setup.painter.clone();
+    setup.paint(&painter, setup.price)?;
+
+    setup.base.app.advance_time(DURATION / 2);
+    let events = setup.paint(&painter, setup.price)?;
+    assert!(events.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn live_pixels_are_overwritten_freely_without_outbids() -> Result<()> {
+    let mut setup = OutbidSetup::new(None)?;
+    let (painter, challenger) = (setup.painter.clone(), setup.challenger.clone());
+    setup.paint(&painter, setup.price)?;
+
+    setup.base.app.advance_time(DURATION / 2);
+    assert!(setup
+        .base
+        .tiles
+        .query_takeover_price(&setup.base.app, 1, 0)
+        .is_err());
+    let events = setup.paint(&challenger, setup.price)?;
+    assert!(events.is_empty());
+
+    Ok(())
+}
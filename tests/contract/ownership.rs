@@ -0,0 +1,103 @@
+use anyhow::Result;
+use tiles::core::tile::metadata::PixelUpdate;
+
+use crate::utils::{EventParser, TestSetup};
+
+fn live_update(id: u32) -> PixelUpdate {
+    PixelUpdate::new(id, "#FF0000", 3600)
+}
+
+#[test]
+fn transfer_emits_tile_transfer_event() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+    let recipient = setup.users.pixel_operator().clone();
+
+    setup.update_pixel(
+        &buyer.address,
+        token_id,
+        vec![live_update(0), live_update(1)],
+    )?;
+    let tile_hash = setup.tiles.query_token_hash(&setup.app, token_id)?;
+
+    let response = setup.tiles.execute_transfer_nft(
+        &mut setup.app,
+        &buyer.address,
+        &recipient.address,
+        token_id.to_string(),
+    )?;
+
+    let event = EventParser::parse_tile_transfer(&response)?;
+    assert_eq!(event.token_id, token_id.to_string());
+    assert_eq!(event.previous_owner, buyer.address);
+    assert_eq!(event.new_owner, recipient.address);
+    assert_eq!(event.tile_hash, tile_hash);
+    assert_eq!(event.live_pixels, 2);
+
+    Ok(())
+}
+
+#[test]
+fn transfer_does_not_count_expired_pixels() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+    let recipient = setup.users.pixel_operator().clone();
+
+    setup.update_pixel(&buyer.address, token_id, vec![live_update(0)])?;
+    setup.app.advance_time(3600);
+
+    let response = setup.tiles.execute_transfer_nft(
+        &mut setup.app,
+        &buyer.address,
+        &recipient.address,
+        token_id.to_string(),
+    )?;
+
+    let event = EventParser::parse_tile_transfer(&response)?;
+    assert_eq!(event.live_pixels, 0);
+
+    Ok(())
+}
+
+#[test]
+fn burn_is_blocked_while_pixels_are_live() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+
+    setup.update_pixel(&buyer.address, token_id, vec![live_update(0)])?;
+
+    let result = setup
+        .tiles
+        .execute_burn(&mut setup.app, &buyer.address, token_id.to_string());
+    assert!(result.is_err(), "Expected burn to fail with live pixels");
+
+    // Token still exists
+    setup
+        .tiles
+        .assert_token_owner(&setup.app, token_id, &buyer.address);
+
+    Ok(())
+}
+
+#[test]
+fn burn_after_expiration_emits_tile_burn_event() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+
+    setup.update_pixel(&buyer.address, token_id, vec![live_update(0)])?;
+    let tile_hash = setup.tiles.query_token_hash(&setup.app, token_id)?;
+    setup.app.advance_time(3600);
+
+    let response =
+        setup
+            .tiles
+            .execute_burn(&mut setup.app, &buyer.address, token_id.to_string())?;
+
+    let event = EventParser::parse_tile_burn(&response)?;
+    assert_eq!(event.token_id, token_id.to_string());
+    assert_eq!(event.owner, buyer.address);
+    assert_eq!(event.tile_hash, tile_hash);
+    assert_eq!(event.live_pixels, 0);
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use cosmwasm_std::{Addr, Uint128};
+use tiles::core::{painter::PainterRanking, tile::metadata::PixelUpdate};
+
+use crate::utils::TestSetup;
+
+fn updates(ids: &[u32], expiration_duration: u64) -> Vec<PixelUpdate> {
+    ids.iter()
+        .map(|id| PixelUpdate::new(*id, "#FF0000", expiration_duration))
+        .collect()
+}
+
+fn price(setup: &TestSetup, pixels: u128, expiration_duration: u64) -> Result<Uint128> {
+    let price_scaling = setup.tiles.query_price_scaling(&setup.app)?;
+    Ok(price_scaling.calculate_price(expiration_duration) * Uint128::new(pixels))
+}
+
+fn ranking(setup: &TestSetup, by: PainterRanking, start_after: Option<&Addr>) -> Vec<Addr> {
+    setup
+        .tiles
+        .query_top_painters(&setup.app, by, start_after, None)
+        .unwrap()
+        .painters
+        .into_iter()
+        .map(|painter| painter.address)
+        .collect()
+}
+
+#[test]
+fn unknown_painters_have_empty_stats() -> Result<()> {
+    let (setup, _) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+
+    let response = setup.tiles.query_painter_stats(&setup.app, &buyer)?;
+    assert_eq!(response.address, buyer);
+    assert_eq!(response.stats, Default::default());
+    assert_eq!(response.live_pixels, 0);
+    Ok(())
+}
+
+#[test]
+fn painting_updates_counters() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+    let second_token = setup.mint_token(&buyer)?;
+
+    setup.update_pixel(&buyer, token_id, updates(&[0, 1, 2], 3600))?;
+    setup.update_pixel(&buyer, token_id, updates(&[3], 7200))?;
+    setup.update_pixel(&buyer, second_token, updates(&[0], 3600))?;
+
+    let response = setup.tiles.query_painter_stats(&setup.app, &buyer)?;
+    assert_eq!(response.stats.pixels_painted, 5);
+    assert_eq!(response.stats.tiles_touched, 2);
+    assert_eq!(
+        response.stats.total_spent,
+        price(&setup, 4, 3600)? + price(&setup, 1, 7200)?
+    );
+    assert_eq!(response.live_pixels, 5);
+    Ok(())
+}
+
+#[test]
+fn live_pixels_expire_and_move_to_new_painters() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+    let operator = setup.users.pixel_operator().address.clone();
+
+    setup.update_pixel(&buyer, token_id, updates(&[0, 1], 3600))?;
+    setup.update_pixel(&buyer, token_id, updates(&[2], 7200))?;
+    setup.update_pixel(&operator, token_id, updates(&[1], 3600))?;
+
+    let live = |setup: &TestSetup, address: &Addr| {
+        setup
+            .tiles
+            .query_painter_stats(&setup.app, address)
+            .unwrap()
+            .live_pixels
+    };
+    assert_eq!(live(&setup, &buyer), 2);
+    assert_eq!(live(&setup, &operator), 1);
+
+    setup.app.advance_time(3600);
+    assert_eq!(live(&setup, &buyer), 1);
+    assert_eq!(live(&setup, &operator), 0);
+
+    // Painting over an expired pixel takes nothing from its last painter
+    setup.update_pixel(&operator, token_id, updates(&[0], 3600))?;
+    assert_eq!(live(&setup, &buyer), 1);
+    assert_eq!(live(&setup, &operator), 1);
+
+    // Lifetime counters are unaffected by expiry
+    let stats = setup.tiles.query_painter_stats(&setup.app, &buyer)?.stats;
+    assert_eq!(stats.pixels_painted, 3);
+    Ok(())
+}
+
+#[test]
+fn top_painters_rank_by_each_counter() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+    let operator = setup.users.pixel_operator().address.clone();
+    let second_token = setup.mint_token(&buyer)?;
+
+    // Buyer paints more pixels, the operator spends more and touches more tiles
+    setup.update_pixel(&buyer, token_id, updates(&[0, 1, 2], 3600))?;
+    setup.update_pixel(&operator, token_id, updates(&[3], 86400))?;
+    setup.update_pixel(&operator, second_token, updates(&[3], 86400))?;
+
+    assert_eq!(
+        ranking(&setup, PainterRanking::PixelsPainted, None),
+        vec![buyer.clone(), operator.clone()]
+    );
+    assert_eq!(
+        ranking(&setup, PainterRanking::TotalSpent, None),
+        vec![operator.clone(), buyer.clone()]
+    );
+    assert_eq!(
+        ranking(&setup, PainterRanking::TilesTouched, None),
+        vec![operator.clone(), buyer.clone()]
+    );
+    Ok(())
+}
+
+#[test]
+fn top_painters_paginate() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().address.clone();
+    let operator = setup.users.pixel_operator().address.clone();
+    let creator = setup.users.creator().address.clone();
+
+    setup.update_pixel(&buyer, token_id, updates(&[0, 1, 2], 3600))?;
+    setup.update_pixel(&operator, token_id, updates(&[3, 4], 3600))?;
+    setup.update_pixel(&creator, token_id, updates(&[5, 6], 3600))?;
+
+    let first =
+        setup
+            .tiles
+            .query_top_painters(&setup.app, PainterRanking::PixelsPainted, None, Some(2))?;
+    assert_eq!(first.painters.len(), 2);
+    assert_eq!(first.painters[0].address, buyer);
+
+    // Operator and creator are tied, the page boundary falls between them
+    let last = &first.painters[1].address;
+    let rest = ranking(&setup, PainterRanking::PixelsPainted, Some(last));
+    assert_eq!(rest.len(), 1);
+    assert_ne!(&rest[0], last);
+    assert!([&operator, &creator].contains(&&rest[0]));
+    Ok(())
+}
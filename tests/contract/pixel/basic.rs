@@ -8,11 +8,7 @@ fn can_set_pixel_color() -> Result<()> {
     let (mut setup, token_id) = TestSetup::with_minted_token()?;
     let buyer = setup.users.get_buyer().clone();
 
-    let update = PixelUpdate {
-        id: 0,
-        color: "#FF0000".to_string(),
-        expiration_duration: 3600,
-    };
+    let update = PixelUpdate::new(0, "#FF0000", 3600);
 
     let result = setup.update_pixel(&buyer.address, token_id, vec![update.clone()])?;
 
@@ -34,21 +30,9 @@ fn all_valid_updates_succeed() -> Result<()> {
     let buyer = setup.users.get_buyer().clone();
 
     let updates = vec![
-        PixelUpdate {
-            id: 0,
-            color: "#FF0000".to_string(),
-            expiration_duration: 3600,
-        },
-        PixelUpdate {
-            id: 1,
-            color: "#00FF00".to_string(),
-            expiration_duration: 3600,
-        },
-        PixelUpdate {
-            id: 2,
-            color: "#0000FF".to_string(),
-            expiration_duration: 3600,
-        },
+        PixelUpdate::new(0, "#FF0000", 3600),
+        PixelUpdate::new(1, "#00FF00", 3600),
+        PixelUpdate::new(2, "#0000FF", 3600),
     ];
 
     let result = setup.update_pixel(&buyer.address, token_id, updates.clone())?;
@@ -76,21 +60,9 @@ fn can_update_multiple_pixels() -> Result<()> {
     let buyer = setup.users.get_buyer().clone();
 
     let updates = vec![
-        PixelUpdate {
-            id: 0,
-            color: "#FF0000".to_string(),
-            expiration_duration: 3600,
-        },
-        PixelUpdate {
-            id: 49,
-            color: "#00FF00".to_string(),
-            expiration_duration: 3600,
-        },
-        PixelUpdate {
-            id: 99,
-            color: "#0000FF".to_string(),
-            expiration_duration: 3600,
-        },
+        PixelUpdate::new(0, "#FF0000", 3600),
+        PixelUpdate::new(49, "#00FF00", 3600),
+        PixelUpdate::new(99, "#0000FF", 3600),
     ];
 
     let result = setup.update_pixel(&buyer.address, token_id, updates.clone())?;
@@ -10,11 +10,7 @@ fn hash_changes_after_pixel_update() -> Result<()> {
 
     let initial_hash = setup.tiles.query_token_hash(&setup.app, token_id)?;
 
-    let update = PixelUpdate {
-        id: 0,
-        color: "#FF0000".to_string(),
-        expiration_duration: 3600,
-    };
+    let update = PixelUpdate::new(0, "#FF0000", 3600);
 
     let result = setup.update_pixel(&buyer.address, token_id, vec![update.clone()])?;
 
@@ -42,11 +38,7 @@ fn hash_changes_after_each_pixel_update() -> Result<()> {
     let initial_hash = setup.tiles.query_token_hash(&setup.app, token_id)?;
 
     // First update
-    let update1 = PixelUpdate {
-        id: 0,
-        color: "#FF0000".to_string(),
-        expiration_duration: 3600,
-    };
+    let update1 = PixelUpdate::new(0, "#FF0000", 3600);
     let result1 = setup.update_pixel(&buyer.address, token_id, vec![update1.clone()])?;
 
     // Assert events for first update
@@ -63,11 +55,7 @@ fn hash_changes_after_each_pixel_update() -> Result<()> {
     assert_ne!(initial_hash, first_hash);
 
     // Second update
-    let update2 = PixelUpdate {
-        id: 1,
-        color: "#00FF00".to_string(),
-        expiration_duration: 3600,
-    };
+    let update2 = PixelUpdate::new(1, "#00FF00", 3600);
     let result2 = setup.update_pixel(&buyer.address, token_id, vec![update2.clone()])?;
 
     // Assert events for second update
@@ -92,11 +80,7 @@ fn update_fails_with_hash_mismatch() -> Result<()> {
     let buyer = setup.users.get_buyer().clone();
 
     // First make a valid update to change the token's hash
-    let initial_update = PixelUpdate {
-        id: 0,
-        color: "#FF0000".to_string(),
-        expiration_duration: 3600,
-    };
+    let initial_update = PixelUpdate::new(0, "#FF0000", 3600);
     setup.update_pixel(&buyer.address, token_id, vec![initial_update])?;
 
     Ok(())
@@ -1,6 +1,12 @@
 use anyhow::Result;
+use cosmwasm_std::coin;
+use cw_multi_test::Executor;
+use cw_utils::PaymentError;
 use sg_std::NATIVE_DENOM;
-use tiles::core::tile::metadata::PixelUpdate;
+use tiles::{
+    contract::msg::{ExecuteMsg, TileExecuteMsg},
+    core::tile::metadata::PixelUpdate,
+};
 
 use crate::utils::{ContractAssertions, EventAssertions, TestSetup};
 
@@ -14,11 +20,7 @@ fn payment_is_distributed_correctly() -> Result<()> {
     let color = "#FF0000";
     let duration_hours = 1;
 
-    let update = PixelUpdate {
-        id: pixel_id,
-        color: color.to_string(),
-        expiration_duration: duration_hours * 3600,
-    };
+    let update = PixelUpdate::new(pixel_id, color.to_string(), duration_hours * 3600);
 
     let price_scaling = setup.state.get_price_scaling()?;
     let expected_payment = price_scaling.calculate_price(duration_hours * 3600);
@@ -72,3 +74,50 @@ fn payment_is_distributed_correctly() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn extra_coins_are_rejected() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let painter = setup.users.pixel_operator().address.clone();
+    let price = setup
+        .state
+        .get_price_scaling()?
+        .calculate_price(3600)
+        .u128();
+    let balance = setup.app.get_balance(&painter, NATIVE_DENOM)?;
+    setup.app.inner_mut().init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &painter,
+                vec![coin(balance, NATIVE_DENOM), coin(1_000, "uatom")],
+            )
+            .unwrap();
+    });
+
+    // The exact price, with a coin of another denom on top
+    let err = setup
+        .app
+        .inner_mut()
+        .execute_contract(
+            painter.clone(),
+            setup.tiles.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::SetPixelColor {
+                    token_id: token_id.to_string(),
+                    current_metadata: setup.state.get_token_metadata(token_id)?,
+                    updates: vec![PixelUpdate::new(0, "#FF0000", 3600)],
+                },
+            },
+            &[coin(price, NATIVE_DENOM), coin(1_000, "uatom")],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        PaymentError::MultipleDenoms {}.to_string()
+    );
+    assert_eq!(setup.app.get_balance(&painter, NATIVE_DENOM)?, balance);
+    assert_eq!(setup.app.get_balance(&painter, "uatom")?, 1_000);
+    Ok(())
+}
@@ -8,11 +8,7 @@ fn invalid_pixel_id_fails() -> Result<()> {
     let (mut setup, token_id) = TestSetup::with_minted_token()?;
     let buyer = setup.users.get_buyer().clone();
 
-    let update = PixelUpdate {
-        id: 100, // Invalid ID (too high)
-        color: "#FF0000".to_string(),
-        expiration_duration: 3600,
-    };
+    let update = PixelUpdate::new(100, "#FF0000", 3600); // Invalid ID (too high)
 
     let result = setup.update_pixel(&buyer.address, token_id, vec![update]);
     assert!(result.is_err(), "Expected error for invalid pixel id");
@@ -24,11 +20,7 @@ fn invalid_color_format_fails() -> Result<()> {
     let (mut setup, token_id) = TestSetup::with_minted_token()?;
     let buyer = setup.users.get_buyer().clone();
 
-    let update = PixelUpdate {
-        id: 0,
-        color: "invalid".to_string(), // Invalid color format
-        expiration_duration: 3600,
-    };
+    let update = PixelUpdate::new(0, "invalid", 3600); // Invalid color format
 
     let result = setup.update_pixel(&buyer.address, token_id, vec![update]);
     assert!(result.is_err(), "Expected error for invalid color format");
@@ -41,20 +33,12 @@ fn invalid_expiration_duration_fails() -> Result<()> {
     let buyer = setup.users.get_buyer().clone();
 
     // Test too short
-    let update_too_short = PixelUpdate {
-        id: 0,
-        color: "#FF0000".to_string(),
-        expiration_duration: 100,
-    };
+    let update_too_short = PixelUpdate::new(0, "#FF0000", 100);
     let result = setup.update_pixel(&buyer.address, token_id, vec![update_too_short]);
     assert!(result.is_err(), "Expected error for duration too short");
 
     // Test too long
-    let update_too_long = PixelUpdate {
-        id: 0,
-        color: "#FF0000".to_string(),
-        expiration_duration: 86401,
-    };
+    let update_too_long = PixelUpdate::new(0, "#FF0000", 86401);
     let result = setup.update_pixel(&buyer.address, token_id, vec![update_too_long]);
     assert!(result.is_err(), "Expected error for duration too long");
     Ok(())
@@ -66,16 +50,8 @@ fn duplicate_pixel_id_fails() -> Result<()> {
     let buyer = setup.users.get_buyer().clone();
 
     let updates = vec![
-        PixelUpdate {
-            id: 0,
-            color: "#FF0000".to_string(),
-            expiration_duration: 3600,
-        },
-        PixelUpdate {
-            id: 0, // Duplicate ID
-            color: "#00FF00".to_string(),
-            expiration_duration: 3600,
-        },
+        PixelUpdate::new(0, "#FF0000", 3600),
+        PixelUpdate::new(0, "#00FF00", 3600), // Duplicate ID
     ];
 
     let result = setup.update_pixel(&buyer.address, token_id, updates);
@@ -90,21 +66,9 @@ fn batch_validation_fails_fast() -> Result<()> {
 
     // Test batch with multiple invalid updates
     let updates = vec![
-        PixelUpdate {
-            id: 100, // Invalid ID
-            color: "#FF0000".to_string(),
-            expiration_duration: 3600,
-        },
-        PixelUpdate {
-            id: 0,
-            color: "invalid".to_string(), // Invalid color
-            expiration_duration: 3600,
-        },
-        PixelUpdate {
-            id: 1,
-            color: "#00FF00".to_string(),
-            expiration_duration: 100, // Invalid duration
-        },
+        PixelUpdate::new(100, "#FF0000", 3600), // Invalid ID
+        PixelUpdate::new(0, "invalid", 3600),   // Invalid color
+        PixelUpdate::new(1, "#00FF00", 100),    // Invalid duration
     ];
 
     let result = setup.update_pixel(&buyer.address, token_id, updates);
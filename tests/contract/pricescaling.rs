@@ -60,6 +60,7 @@ fn cannot_set_hour_1_price_greater_than_hour_12_price() -> Result<()> {
         hour_12_price: Uint128::from(DEFAULT_PRICE_1_HOUR),
         hour_24_price: Uint128::from(DEFAULT_PRICE_24_HOURS),
         quadratic_base: Uint128::from(1u128),
+        annotation_byte_price: Uint128::zero(),
     };
 
     let result =
@@ -80,6 +81,7 @@ fn cannot_set_hour_12_price_greater_than_hour_24_price() -> Result<()> {
         hour_12_price: Uint128::from(DEFAULT_PRICE_24_HOURS),
         hour_24_price: Uint128::from(DEFAULT_PRICE_12_HOURS),
         quadratic_base: Uint128::from(1u128),
+        annotation_byte_price: Uint128::zero(),
         ..PriceScaling::default()
     };
 
@@ -100,6 +102,7 @@ fn cannot_set_zero_hour_1_price() -> Result<()> {
     let invalid_scaling = PriceScaling {
         hour_1_price: Uint128::zero(),
         quadratic_base: Uint128::from(1u128),
+        annotation_byte_price: Uint128::zero(),
         ..PriceScaling::default()
     };
 
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use tiles::core::tile::metadata::PixelUpdate;
+
+use crate::utils::TestSetup;
+
+#[test]
+fn tile_svg_reflects_painted_pixels() -> Result<()> {
+    let (mut setup, token_id) = TestSetup::with_minted_token()?;
+    let buyer = setup.users.get_buyer().clone();
+
+    let update = PixelUpdate::new(12, "#FF0000", 3600);
+    setup.update_pixel(&buyer.address, token_id, vec![update])?;
+
+    let svg = setup
+        .tiles
+        .query_tile_svg(&setup.app, token_id, None, None)?;
+    assert!(svg.contains(r##"<rect x="2" y="1" width="1" height="1" fill="#FF0000"/>"##));
+
+    // Once expired the pixel falls back to the default color
+    setup.app.advance_time(3600);
+    let svg = setup
+        .tiles
+        .query_tile_svg(&setup.app, token_id, None, None)?;
+    assert!(!svg.contains("#FF0000"));
+
+    Ok(())
+}
+
+#[test]
+fn tile_svg_supports_scale_and_grid_lines() -> Result<()> {
+    let (setup, token_id) = TestSetup::with_minted_token()?;
+
+    let svg = setup
+        .tiles
+        .query_tile_svg(&setup.app, token_id, Some(32), Some(true))?;
+    assert!(svg.contains(r#"width="320" height="320""#));
+    assert!(svg.contains("<path"));
+
+    Ok(())
+}
+
+#[test]
+fn tile_svg_rejects_invalid_scale() -> Result<()> {
+    let (setup, token_id) = TestSetup::with_minted_token()?;
+
+    let result = setup
+        .tiles
+        .query_tile_svg(&setup.app, token_id, Some(0), None);
+    assert!(result.is_err(), "Expected error for zero scale");
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use anyhow::Result;
+use cosmwasm_std::{coin, Addr, Uint128};
+use sg_std::NATIVE_DENOM;
+use tiles::{
+    contract::msg::InstantiateMsg,
+    core::{
+        config::PayoutMode,
+        pricing::PriceScaling,
+        revenue::{Payout, RevenueSplit},
+        tile::metadata::{PixelUpdate, TileMetadata},
+    },
+    events::{EventData, PaymentDistributionEventData},
+};
+
+use crate::utils::{TestSetup, TestUsers};
+
+struct SplitSetup {
+    base: TestSetup,
+    owner: Addr,
+    creator: Addr,
+    treasury: Addr,
+    pool: Addr,
+}
+
+impl SplitSetup {
+    fn new(splits: &[u16], configure: impl FnOnce(&mut InstantiateMsg)) -> Result<Self> {
+        let users = TestUsers::new();
+        let treasury = users.creator().address.clone();
+        let pool = users.factory_contract_creator().address.clone();
+        let base = TestSetup::with_config(|msg| {
+            msg.revenue_splits = Some(
+                [&treasury, &pool]
+                    .into_iter()
+                    .zip(splits)
+                    .map(|(recipient, basis_points)| RevenueSplit {
+                        recipient: recipient.clone(),
+                        basis_points: *basis_points,
+                    })
+                    .collect(),
+            );
+            configure(msg);
+        })?;
+        Ok(Self {
+            owner: base.users.get_buyer().address.clone(),
+            creator: base.users.tile_contract_creator().address.clone(),
+            treasury,
+            pool,
+            base,
+        })
+    }
+
+    /// Paints one pixel for an hour and returns the distribution event
+    fn paint(&mut self) -> Result<PaymentDistributionEventData> {
+        let painter = self.base.users.pixel_operator().address.clone();
+        let metadata = TileMetadata::new(&self.base.tiles.query_config(&self.base.app)?);
+        let response = self.base.tiles.update_pixel(
+            &mut self.base.app,
+            &painter,
+            1,
+            vec![PixelUpdate::new(0, "#FF0000", 3600)],
+            metadata,
+        )?;
+        Ok(response
+            .events
+            .iter()
+            .find_map(PaymentDistributionEventData::try_from_event)
+            .expect("payment distribution event"))
+    }
+
+    fn balances(&self) -> [u128; 4] {
+        [&self.creator, &self.treasury, &self.pool, &self.owner]
+            .map(|address| self.base.app.get_balance(address, NATIVE_DENOM).unwrap())
+    }
+
+    fn payout(&self, recipient: &Addr, amount: u128) -> Payout {
+        Payout {
+            recipient: recipient.clone(),
+            amount: Uint128::new(amount),
+        }
+    }
+}
+
+#[test]
+fn splits_are_paid_out_of_the_owner_share() -> Result<()> {
+    let mut setup = SplitSetup::new(&[1_500, 500], |_| {})?;
+    let before = setup.balances();
+
+    // 100_000 paint, 10% royalty, then 15% and 5% of the remaining 90_000
+    let event = setup.paint()?;
+
+    let received: Vec<u128> = setup
+        .balances()
+        .iter()
+        .zip(before)
+        .map(|(after, before)| after - before)
+        .collect();
+    assert_eq!(received, vec![10_000, 13_500, 4_500, 72_000]);
+    assert_eq!(event.royalty_amount, 10_000);
+    assert_eq!(event.owner_amount, 72_000);
+    assert_eq!(
+        event.payouts,
+        vec![
+            setup.payout(&setup.creator, 10_000),
+            setup.payout(&setup.treasury, 13_500),
+            setup.payout(&setup.pool, 4_500),
+            setup.payout(&setup.owner, 72_000),
+        ]
+    );
+
+    let earnings = setup.base.tiles.query_tile_earnings(&setup.base.app, 1)?;
+    assert_eq!(earnings.earnings[0].split_amount, Uint128::new(18_000));
+    assert_eq!(earnings.earnings[0].owner_amount, Uint128::new(72_000));
+    Ok(())
+}
+
+#[test]
+fn rounding_remainder_goes_to_the_owner() -> Result<()> {
+    let mut setup = SplitSetup::new(&[3_333, 3_333], |msg| {
+        msg.price_scaling = Some(PriceScaling {
+            hour_1_price: Uint128::new(1_003),
+            ..PriceScaling::default()
+        });
+    })?;
+
+    // 1_003 paint: royalty 100, each split floors 903 * 33.33% to 300
+    let event = setup.paint()?;
+
+    let amounts: Vec<u128> = event.payouts.iter().map(|p| p.amount.u128()).collect();
+    assert_eq!(amounts, vec![100, 300, 300, 303]);
+    assert_eq!(amounts.iter().sum::<u128>(), 1_003);
+    Ok(())
+}
+
+#[test]
+fn escrow_mode_credits_split_recipients() -> Result<()> {
+    let mut setup = SplitSetup::new(&[1_500, 500], |msg| {
+        msg.payout_mode = Some(PayoutMode::Escrow);
+    })?;
+    setup.paint()?;
+
+    for (recipient, amount) in [
+        (&setup.creator, 10_000),
+        (&setup.treasury, 13_500),
+        (&setup.pool, 4_500),
+        (&setup.owner, 72_000),
+    ] {
+        assert_eq!(
+            setup
+                .base
+                .tiles
+                .query_pending_balance(&setup.base.app, recipient)?
+                .balances,
+            vec![coin(amount, NATIVE_DENOM)]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn rejects_invalid_splits() -> Result<()> {
+    assert!(SplitSetup::new(&[6_000, 4_001], |_| {}).is_err());
+    assert!(SplitSetup::new(&[500], |msg| {
+        msg.revenue_splits = Some(vec![RevenueSplit {
+            recipient: Addr::unchecked("Not A Valid Address"),
+            basis_points: 500,
+        }]);
+    })
+    .is_err());
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+use tiles::schema::{schema_files, SCHEMA_DIR};
+
+fn checked_in_files(dir: &Path, prefix: &str, files: &mut BTreeSet<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let entry = entry.unwrap();
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        if entry.file_type().unwrap().is_dir() {
+            checked_in_files(&entry.path(), &format!("{}/", name), files);
+        } else {
+            files.insert(name);
+        }
+    }
+}
+
+#[test]
+fn checked_in_schema_matches_code() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(SCHEMA_DIR);
+    let generated = schema_files().unwrap();
+
+    for (name, expected) in &generated {
+        let actual = fs::read_to_string(dir.join(name)).unwrap_or_default();
+        assert!(
+            &actual == expected,
+            "schema/{} is out of date, regenerate with `cargo schema`",
+            name
+        );
+    }
+
+    let mut checked_in = BTreeSet::new();
+    checked_in_files(&dir, "", &mut checked_in);
+    let expected: BTreeSet<String> = generated.into_iter().map(|(name, _)| name).collect();
+    assert_eq!(
+        checked_in, expected,
+        "schema/ has stale files, regenerate with `cargo schema`"
+    );
+}
+
+#[test]
+fn every_query_has_a_response_schema() {
+    let api = tiles::schema::api().render();
+    let files: Vec<String> = api
+        .to_schema_files()
+        .unwrap()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+
+    for query in [
+        "price_scaling",
+        "config",
+        "tile_svg",
+        "nft_info",
+        "all_nft_info",
+        "collection_info",
+    ] {
+        let response = format!("response_to_{}.json", query);
+        assert!(files.contains(&response), "missing {}", response);
+    }
+}
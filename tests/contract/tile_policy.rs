@@ -0,0 +1,265 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use tiles::{
+    core::tile::{
+        metadata::{PixelUpdate, TileMetadata},
+        policy::{PaintAccess, TilePolicy},
+    },
+    events::{EventData, TilePolicyUpdateEventData},
+};
+
+use crate::utils::TestSetup;
+
+struct PolicySetup {
+    base: TestSetup,
+    owner: Addr,
+    painter: Addr,
+    outsider: Addr,
+}
+
+impl PolicySetup {
+    fn new() -> Result<Self> {
+        let base = TestSetup::with_config(|msg| {
+            msg.min_price_multiplier = Some(Decimal::percent(50));
+            msg.max_price_multiplier = Some(Decimal::percent(300));
+        })?;
+        Ok(Self {
+            owner: base.users.get_buyer().address.clone(),
+            painter: base.users.pixel_operator().address.clone(),
+            outsider: base.users.creator().address.clone(),
+            base,
+        })
+    }
+
+    fn set_policy(&mut self, sender: &Addr, policy: TilePolicy) -> Result<()> {
+        self.base
+            .tiles
+            .execute_set_tile_policy(&mut self.base.app, sender, 1, policy)?;
+        Ok(())
+    }
+
+    /// Paints the blank tile's first pixel for an hour, paying `multiplier` times the price
+    fn paint(&mut self, painter: &Addr, multiplier: Decimal) -> Result<()> {
+        let config = self.base.tiles.query_config(&self.base.app)?;
+        let price = self
+            .base
+            .tiles
+            .query_price_scaling(&self.base.app)?
+            .calculate_price(3600)
+            * multiplier;
+        self.base.tiles.update_pixel_with_funds(
+            &mut self.base.app,
+            painter,
+            1,
+            vec![PixelUpdate::new(0, "#FF0000", 3600)],
+            price.u128(),
+            TileMetadata::new(&config),
+        )?;
+        Ok(())
+    }
+
+    fn policy(&self) -> TilePolicy {
+        self.base
+            .tiles
+            .query_nft_info(&self.base.app, 1)
+            .unwrap()
+            .extension
+            .policy
+    }
+}
+
+fn access(access: PaintAccess) -> TilePolicy {
+    TilePolicy {
+        access,
+        ..TilePolicy::default()
+    }
+}
+
+#[test]
+fn tiles_are_minted_with_an_open_policy() -> Result<()> {
+    let setup = PolicySetup::new()?;
+    let tile = setup
+        .base
+        .tiles
+        .query_nft_info(&setup.base.app, 1)?
+        .extension;
+    assert_eq!(tile.policy, TilePolicy::default());
+    assert_eq!(tile.frozen_at, None);
+    Ok(())
+}
+
+#[test]
+fn only_the_owner_sets_the_policy() -> Result<()> {
+    let mut setup = PolicySetup::new()?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    let policy = access(PaintAccess::OwnerOnly);
+
+    assert!(setup.set_policy(&painter, policy.clone()).is_err());
+    assert_eq!(setup.policy(), TilePolicy::default());
+
+    let response =
+        setup
+            .base
+            .tiles
+            .execute_set_tile_policy(&mut setup.base.app, &owner, 1, policy.clone())?;
+    let event = response
+        .events
+        .iter()
+        .find_map(TilePolicyUpdateEventData::try_from_event)
+        .expect("tile policy event");
+    assert_eq!(event.owner, owner);
+    assert_eq!(event.policy, policy);
+    assert_eq!(setup.policy(), policy);
+    Ok(())
+}
+
+#[test]
+fn owner_only_tiles_reject_other_painters() -> Result<()> {
+    let mut setup = PolicySetup::new()?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    setup.set_policy(&owner, access(PaintAccess::OwnerOnly))?;
+
+    assert!(setup.paint(&painter, Decimal::one()).is_err());
+    setup.paint(&owner, Decimal::one())?;
+    Ok(())
+}
+
+#[test]
+fn allowlisted_painters_can_paint() -> Result<()> {
+    let mut setup = PolicySetup::new()?;
+    let (owner, painter, outsider) = (
+        setup.owner.clone(),
+        setup.painter.clone(),
+        setup.outsider.clone(),
+    );
+    setup.set_policy(
+        &owner,
+        access(PaintAccess::Allowlist(vec![painter.clone()])),
+    )?;
+
+    assert!(setup.paint(&outsider, Decimal::one()).is_err());
+    setup.paint(&painter, Decimal::one())?;
+    Ok(())
+}
+
+#[test]
+fn price_multiplier_scales_the_paint_price() -> Result<()> {
+    let mut setup = PolicySetup::new()?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+
+    // Outside the collection bounds
+    for price_multiplier in [Decimal::percent(25), Decimal::percent(400)] {
+        let policy = TilePolicy {
+            price_multiplier,
+            ..TilePolicy::default()
+        };
+        assert!(setup.set_policy(&owner, policy).is_err());
+    }
+
+    let price_multiplier = Decimal::percent(200);
+    setup.set_policy(
+        &owner,
+        TilePolicy {
+            price_multiplier,
+            ..TilePolicy::default()
+        },
+    )?;
+    assert!(setup.paint(&painter, Decimal::one()).is_err());
+    setup.paint(&painter, price_multiplier)?;
+    Ok(())
+}
+
+#[test]
+fn price_multiplier_rounds_each_pixel() -> Result<()> {
+    let mut setup = PolicySetup::new()?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    let price_multiplier = Decimal::from_str("1.234567")?;
+    setup.set_policy(
+        &owner,
+        TilePolicy {
+            price_multiplier,
+            ..TilePolicy::default()
+        },
+    )?;
+
+    let config = setup.base.tiles.query_config(&setup.base.app)?;
+    let pixel_price = setup
+        .base
+        .tiles
+        .query_price_scaling(&setup.base.app)?
+        .calculate_price(3600);
+    let updates: Vec<PixelUpdate> = (0..3)
+        .map(|id| PixelUpdate::new(id, "#FF0000", 3600))
+        .collect();
+    let mut paint = |funds: Uint128| {
+        setup.base.tiles.update_pixel_with_funds(
+            &mut setup.base.app,
+            &painter,
+            1,
+            updates.clone(),
+            funds.u128(),
+            TileMetadata::new(&config),
+        )
+    };
+
+    // The sum of the scaled pixel prices, not the scaled sum
+    let scaled_sum = pixel_price * Uint128::new(3) * price_multiplier;
+    let summed = pixel_price * price_multiplier * Uint128::new(3);
+    assert_ne!(scaled_sum, summed);
+    assert!(paint(scaled_sum).is_err());
+    paint(summed)?;
+    Ok(())
+}
+
+#[test]
+fn frozen_tiles_are_immutable() -> Result<()> {
+    let mut setup = PolicySetup::new()?;
+    let (owner, painter) = (setup.owner.clone(), setup.painter.clone());
+    setup.paint(&painter, Decimal::one())?;
+    let frozen = TilePolicy {
+        frozen: true,
+        ..TilePolicy::default()
+    };
+    let response =
+        setup
+            .base
+            .tiles
+            .execute_set_tile_policy(&mut setup.base.app, &owner, 1, frozen)?;
+    let event = response
+        .events
+        .iter()
+        .find_map(TilePolicyUpdateEventData::try_from_event)
+        .expect("tile policy update event");
+    let svg = setup
+        .base
+        .tiles
+        .query_tile_svg(&setup.base.app, 1, None, None)?;
+
+    // Painting and further policy changes are refused, even by the owner
+    let err = setup.paint(&owner, Decimal::one()).unwrap_err();
+    assert!(err.root_cause().to_string().contains("is frozen"));
+    let err = setup.set_policy(&owner, TilePolicy::default()).unwrap_err();
+    assert!(err.root_cause().to_string().contains("is frozen"));
+
+    // The painted pixel outlives its expiration in renders
+    setup.base.app.advance_time(7200);
+    assert_eq!(
+        setup
+            .base
+            .tiles
+            .query_tile_svg(&setup.base.app, 1, None, None)?,
+        svg
+    );
+    let tile = setup
+        .base
+        .tiles
+        .query_nft_info(&setup.base.app, 1)?
+        .extension;
+    assert!(tile.policy.frozen);
+    assert!(tile.frozen_at.is_some());
+    // Renderers replaying events learn the freeze time from the event
+    assert_eq!(event.frozen_at, tile.frozen_at);
+    Ok(())
+}
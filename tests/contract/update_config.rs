@@ -0,0 +1,146 @@
+use anyhow::Result;
+use tiles::{
+    contract::error::ContractError,
+    core::{
+        config::{Config, PayoutMode},
+        tile::metadata::{PixelUpdate, TileMetadata},
+    },
+    events::ConfigUpdateEventData,
+};
+
+use crate::utils::{EventParser, TestSetup};
+
+// Differs from the default in everything a deploy profile sets
+fn profile_config() -> Config {
+    Config {
+        pixel_min_expiration: 60,
+        pixel_max_expiration: 3600,
+        tile_width: 16,
+        tile_height: 8,
+        default_color: "#000000".to_string(),
+        pixel_history_depth: 8,
+        payout_mode: PayoutMode::Escrow,
+        ..Config::default()
+    }
+}
+
+#[test]
+fn config_applies_to_factory_created_collection() -> Result<()> {
+    let mut setup = TestSetup::new()?;
+    let creator = setup.users.tile_contract_creator().address.clone();
+    let buyer = setup.users.get_buyer().address.clone();
+    let config = profile_config();
+
+    let response = setup
+        .tiles
+        .execute_update_config(&mut setup.app, &creator, config.clone())?;
+    let event = EventParser::find_and_parse::<ConfigUpdateEventData>(&response)?;
+    assert_eq!(event.config, config);
+    assert_eq!(setup.tiles.query_config(&setup.app)?, config);
+
+    // Tiles minted afterwards follow the new config
+    let token_id = setup.mint_token(&buyer)?;
+    let metadata = setup.state.get_token_metadata(token_id)?;
+    assert_eq!(metadata, TileMetadata::new(&config));
+    assert_eq!(metadata.pixels.len(), 128);
+    assert_eq!(metadata.pixels[0].color, "#000000");
+
+    let err = setup
+        .update_pixel(&buyer, token_id, vec![PixelUpdate::new(0, "#FF0000", 7200)])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidPixelUpdate {
+            reason: "Expiration duration 7200 is greater than maximum 3600".to_string()
+        }
+        .to_string()
+    );
+    setup.update_pixel(
+        &buyer,
+        token_id,
+        vec![PixelUpdate::new(127, "#FF0000", 3600)],
+    )?;
+    assert_eq!(
+        setup
+            .tiles
+            .query_pixel_history(&setup.app, token_id, 127, None, None)?
+            .entries
+            .len(),
+        1
+    );
+
+    Ok(())
+}
+
+#[test]
+fn only_royalty_payment_address_can_update_config() -> Result<()> {
+    let mut setup = TestSetup::new()?;
+    let buyer = setup.users.get_buyer().address.clone();
+
+    let err = setup
+        .tiles
+        .execute_update_config(&mut setup.app, &buyer, profile_config())
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {
+            sender: buyer.to_string()
+        }
+        .to_string()
+    );
+    assert_eq!(setup.tiles.query_config(&setup.app)?, Config::default());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_config_is_rejected() -> Result<()> {
+    let mut setup = TestSetup::new()?;
+    let creator = setup.users.tile_contract_creator().address.clone();
+    let config = Config {
+        tile_width: 0,
+        ..Config::default()
+    };
+
+    let err = setup
+        .tiles
+        .execute_update_config(&mut setup.app, &creator, config.clone())
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::InvalidConfig {
+            reason: config.validate().unwrap_err().to_string()
+        }
+        .to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dimensions_are_locked_once_minted() -> Result<()> {
+    let (mut setup, _) = TestSetup::with_minted_token()?;
+    let creator = setup.users.tile_contract_creator().address.clone();
+
+    let err = setup
+        .tiles
+        .execute_update_config(&mut setup.app, &creator, profile_config())
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::TileDimensionsLocked {}.to_string()
+    );
+
+    // Everything else can still change
+    let config = Config {
+        tile_width: Config::default().tile_width,
+        tile_height: Config::default().tile_height,
+        ..profile_config()
+    };
+    setup
+        .tiles
+        .execute_update_config(&mut setup.app, &creator, config.clone())?;
+    assert_eq!(setup.tiles.query_config(&setup.app)?, config);
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use cosmwasm_std::Addr;
+use tiles::{
+    core::{config::Config, outbid::OutbidConfig, revenue::RevenueSplit},
+    defaults::constants::{
+        MAX_KEYFRAME_SURCHARGE_BPS, MAX_MODERATORS, MAX_OUTBID_PREMIUM_BPS, MAX_PIXELS_PER_TILE,
+        MAX_PIXEL_HISTORY_DEPTH, MAX_REVEAL_WINDOW_BLOCKS, MAX_REVENUE_SPLITS, MAX_TILE_DIMENSION,
+    },
+};
+
+#[test]
+fn default_config_is_valid() {
+    let config = Config::default();
+    assert!(config.validate().is_ok());
+    assert_eq!(config.pixels_per_tile(), 100);
+    assert!(config.accepts_denom("ustars"));
+    assert!(!config.accepts_denom("uatom"));
+}
+
+#[test]
+fn rejects_an_empty_denom() {
+    let config = Config {
+        denom: String::new(),
+        ..Config::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rejects_invalid_expiration_bounds() {
+    let config = Config {
+        pixel_min_expiration: 0,
+        ..Config::default()
+    };
+    assert!(config.validate().is_err());
+
+    let config = Config {
+        pixel_min_expiration: 7200,
+        pixel_max_expiration: 3600,
+        ..Config::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rejects_invalid_tile_dimensions() {
+    for (tile_width, tile_height) in [
+        (0, 10),
+        (10, 0),
+        (MAX_TILE_DIMENSION + 1, 1),
+        (1, MAX_TILE_DIMENSION + 1),
+    ] {
+        let config = Config {
+            tile_width,
+            tile_height,
+            ..Config::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    // Each dimension is in range but the tile is too large overall
+    let config = Config {
+        tile_width: MAX_TILE_DIMENSION,
+        tile_height: MAX_PIXELS_PER_TILE / MAX_TILE_DIMENSION + 1,
+        ..Config::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn rejects_invalid_default_color() {
+    for color in ["FFFFFF", "#FFF", "#GGGGGG"] {
+        let config = Config {
+            default_color: color.to_string(),
+            ..Config::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
+
+#[test]
+fn bounds_pixel_history_depth() {
+    assert!(!Config::default().history_enabled());
+
+    let config = Config {
+        pixel_history_depth: MAX_PIXEL_HISTORY_DEPTH,
+        ..Config::default()
+    };
+    assert!(config.validate().is_ok());
+    assert!(config.history_enabled());
+
+    let config = Config {
+        pixel_history_depth: MAX_PIXEL_HISTORY_DEPTH + 1,
+        ..Config::default()
+    };
+    assert!(config.validate().is_err());
+}
+
+fn split(recipient: &str, basis_points: u16) -> RevenueSplit {
+    RevenueSplit {
+        recipient: Addr::unchecked(recipient),
+        basis_points,
+    }
+}
+
+#[test]
+fn bounds_revenue_splits() {
+    let config = Config {
+        revenue_splits: vec![split("treasury", 6_000), split("pool", 4_000)],
+        ..Config::default()
+    };
+    assert!(config.validate().is_ok());
+
+    for revenue_splits in [
+        vec![split("treasury", 6_000), split("pool", 4_001)],
+        vec![split("treasury", 0)],
+        vec![split("treasury", 100), split("treasury", 100)],
+        (0..=MAX_REVENUE_SPLITS)
+            .map(|i| split(&format!("recipient{}", i), 1))
+            .collect(),
+    ] {
+        let config = Config {
+            revenue_splits,
+            ..Config::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
+
+#[test]
+fn bounds_outbid_config() {
+    let outbid = |premium_bps, displaced_share_bps| Config {
+        outbid: Some(OutbidConfig {
+            premium_bps,
+            displaced_share_bps,
+        }),
+        ..Config::default()
+    };
+    assert!(outbid(MAX_OUTBID_PREMIUM_BPS, 10_000).validate().is_ok());
+    assert!(outbid(0, 0).validate().is_ok());
+    assert!(outbid(MAX_OUTBID_PREMIUM_BPS + 1, 0).validate().is_err());
+    assert!(outbid(2_000, 10_001).validate().is_err());
+}
+
+#[test]
+fn bounds_reveal_window() {
+    let window = |reveal_window_blocks| Config {
+        reveal_window_blocks,
+        ..Config::default()
+    };
+    assert!(window(1).validate().is_ok());
+    assert!(window(MAX_REVEAL_WINDOW_BLOCKS).validate().is_ok());
+    assert!(window(0).validate().is_err());
+    assert!(window(MAX_REVEAL_WINDOW_BLOCKS + 1).validate().is_err());
+}
+
+#[test]
+fn bounds_keyframe_surcharge() {
+    let surcharge = |keyframe_surcharge_bps| Config {
+        keyframe_surcharge_bps,
+        ..Config::default()
+    };
+    assert!(surcharge(0).validate().is_ok());
+    assert!(surcharge(MAX_KEYFRAME_SURCHARGE_BPS).validate().is_ok());
+    assert!(surcharge(MAX_KEYFRAME_SURCHARGE_BPS + 1)
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn bounds_moderators() {
+    let moderators = |count: usize| Config {
+        moderators: (0..count)
+            .map(|i| Addr::unchecked(format!("moderator{}", i)))
+            .collect(),
+        ..Config::default()
+    };
+    assert!(moderators(MAX_MODERATORS).validate().is_ok());
+    assert!(moderators(MAX_MODERATORS + 1).validate().is_err());
+
+    let duplicated = Config {
+        moderators: vec![Addr::unchecked("moderator"), Addr::unchecked("moderator")],
+        ..Config::default()
+    };
+    assert!(duplicated.validate().is_err());
+}
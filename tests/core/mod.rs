@@ -1,9 +1,14 @@
+mod config;
+
 mod pricing {
     mod calculation;
     mod validation;
 }
 
+mod replay;
+
 mod tile {
+    mod dimensions;
     mod metadata;
     mod hash;
 }
@@ -8,6 +8,7 @@ fn test_valid_price_scaling() {
         hour_12_price: Uint128::new(10_000_000),
         hour_24_price: Uint128::new(20_000_000),
         quadratic_base: Uint128::new(1_000_000),
+        annotation_byte_price: Uint128::zero(),
     };
 
     assert!(price_scaling.validate().is_ok());
@@ -21,6 +22,7 @@ fn test_invalid_price_scaling() {
         hour_12_price: Uint128::new(1_000_000),
         hour_24_price: Uint128::new(20_000_000),
         quadratic_base: Uint128::new(1_000_000),
+        annotation_byte_price: Uint128::zero(),
     };
 
     assert!(price_scaling.validate().is_err());
@@ -31,6 +33,7 @@ fn test_invalid_price_scaling() {
         hour_12_price: Uint128::new(20_000_000),
         hour_24_price: Uint128::new(10_000_000),
         quadratic_base: Uint128::new(1_000_000),
+        annotation_byte_price: Uint128::zero(),
     };
 
     assert!(price_scaling.validate().is_err());
@@ -44,6 +47,7 @@ fn test_zero_prices() {
         hour_12_price: Uint128::new(10_000_000),
         hour_24_price: Uint128::new(20_000_000),
         quadratic_base: Uint128::new(1_000_000),
+        annotation_byte_price: Uint128::zero(),
     };
     assert!(price_scaling.validate().is_err());
 
@@ -53,6 +57,7 @@ fn test_zero_prices() {
         hour_12_price: Uint128::zero(),
         hour_24_price: Uint128::new(20_000_000),
         quadratic_base: Uint128::new(1_000_000),
+        annotation_byte_price: Uint128::zero(),
     };
     assert!(price_scaling.validate().is_err());
 
@@ -62,6 +67,7 @@ fn test_zero_prices() {
         hour_12_price: Uint128::new(10_000_000),
         hour_24_price: Uint128::zero(),
         quadratic_base: Uint128::new(1_000_000),
+        annotation_byte_price: Uint128::zero(),
     };
     assert!(price_scaling.validate().is_err());
 
@@ -71,6 +77,7 @@ fn test_zero_prices() {
         hour_12_price: Uint128::new(10_000_000),
         hour_24_price: Uint128::new(20_000_000),
         quadratic_base: Uint128::zero(),
+        annotation_byte_price: Uint128::zero(),
     };
     assert!(price_scaling.validate().is_err());
 }
@@ -0,0 +1,271 @@
+use cosmwasm_std::{Addr, Event};
+use tiles::{
+    core::{
+        config::Config,
+        pricing::PriceScaling,
+        replay::{ReplayError, TileStateReplayer},
+        tile::{
+            layer::BasePixel,
+            metadata::{PixelData, TileMetadata},
+        },
+    },
+    events::{
+        BaseLayerUpdateEventData, ConfigUpdateEventData, EventData, MintMetadataEventData,
+        PixelUpdateEventData, PriceScalingUpdateEventData, TileBurnEventData,
+        TileTransferEventData,
+    },
+};
+
+// Events are prefixed with "wasm-" once emitted on chain
+fn on_chain(event: Event) -> Event {
+    let mut event = event;
+    event.ty = format!("wasm-{}", event.ty);
+    event
+}
+
+fn mint_event(token_id: &str, owner: &str) -> Event {
+    let metadata = TileMetadata::default();
+    on_chain(
+        MintMetadataEventData {
+            token_id: token_id.to_string(),
+            owner: Addr::unchecked(owner),
+            tile_hash: metadata.hash(),
+            new_pixels: metadata.pixels,
+        }
+        .into_event(),
+    )
+}
+
+fn painted_pixel(id: u32, color: &str) -> PixelData {
+    PixelData {
+        id,
+        color: color.to_string(),
+        expiration_timestamp: 7200,
+        last_updated_by: Addr::unchecked("painter"),
+        last_updated_at: 3600,
+        keyframes: vec![],
+        annotation: None,
+    }
+}
+
+fn pixel_update_event(token_id: &str, pixels: Vec<PixelData>, tile_hash: String) -> Event {
+    on_chain(
+        PixelUpdateEventData {
+            token_id: token_id.to_string(),
+            new_pixels: pixels,
+            tile_hash,
+        }
+        .into_event(),
+    )
+}
+
+#[test]
+fn replays_mint_and_pixel_updates() {
+    let pixel = painted_pixel(5, "#FF0000");
+    let mut expected = TileMetadata::default();
+    // The contract keeps the id pixels were minted with
+    expected.pixels[5] = PixelData {
+        id: 0,
+        ..pixel.clone()
+    };
+
+    let events = vec![
+        mint_event("1", "owner"),
+        pixel_update_event("1", vec![pixel], expected.hash()),
+    ];
+
+    let mut replayer = TileStateReplayer::new();
+    replayer.replay(&events).unwrap();
+
+    let tile = replayer.tile("1").unwrap();
+    assert_eq!(tile.metadata, expected);
+    assert_eq!(tile.tile_hash, expected.hash());
+    assert_eq!(tile.owner, Addr::unchecked("owner"));
+}
+
+#[test]
+fn replays_base_layer_updates() {
+    let base = vec![BasePixel {
+        id: 3,
+        color: Some("#0000FF".to_string()),
+    }];
+    let mut expected = TileMetadata::default();
+    expected.apply_base_pixels(&base);
+
+    let events = vec![
+        mint_event("1", "owner"),
+        on_chain(
+            BaseLayerUpdateEventData {
+                token_id: "1".to_string(),
+                owner: Addr::unchecked("owner"),
+                pixels: base,
+                tile_hash: expected.hash(),
+            }
+            .into_event(),
+        ),
+    ];
+
+    let mut replayer = TileStateReplayer::new();
+    replayer.replay(&events).unwrap();
+    assert_eq!(replayer.tile("1").unwrap().metadata, expected);
+}
+
+#[test]
+fn reports_hash_divergence() {
+    let events = vec![
+        mint_event("1", "owner"),
+        pixel_update_event(
+            "1",
+            vec![painted_pixel(5, "#FF0000")],
+            "not-the-hash".to_string(),
+        ),
+    ];
+
+    let mut replayer = TileStateReplayer::new();
+    let result = replayer.replay(&events);
+    assert!(matches!(
+        result,
+        Err(ReplayError::HashMismatch { ref token_id, .. }) if token_id == "1"
+    ));
+    // The rejected update is not applied
+    assert_eq!(
+        replayer.tile("1").unwrap().metadata,
+        TileMetadata::default()
+    );
+}
+
+#[test]
+fn rejected_updates_leave_the_tile_untouched() {
+    let mut replayer = TileStateReplayer::new();
+    replayer.apply(&mint_event("1", "owner")).unwrap();
+
+    let result = replayer.apply(&pixel_update_event(
+        "1",
+        vec![painted_pixel(5, "#FF0000"), painted_pixel(100, "#FF0000")],
+        TileMetadata::default().hash(),
+    ));
+    assert!(matches!(
+        result,
+        Err(ReplayError::InvalidPixelId { id: 100, .. })
+    ));
+    assert_eq!(
+        replayer.tile("1").unwrap().metadata,
+        TileMetadata::default()
+    );
+}
+
+#[test]
+fn rejects_update_for_unknown_token() {
+    let events = vec![pixel_update_event(
+        "7",
+        vec![painted_pixel(0, "#FF0000")],
+        TileMetadata::default().hash(),
+    )];
+
+    let result = TileStateReplayer::new().replay(&events);
+    assert_eq!(
+        result,
+        Err(ReplayError::UnknownToken {
+            token_id: "7".to_string()
+        })
+    );
+}
+
+#[test]
+fn rejects_duplicate_mint() {
+    let events = vec![mint_event("1", "owner"), mint_event("1", "owner")];
+
+    let result = TileStateReplayer::new().replay(&events);
+    assert_eq!(
+        result,
+        Err(ReplayError::DuplicateMint {
+            token_id: "1".to_string()
+        })
+    );
+}
+
+#[test]
+fn tracks_transfers_and_burns() {
+    let tile_hash = TileMetadata::default().hash();
+    let transfer = on_chain(
+        TileTransferEventData {
+            token_id: "1".to_string(),
+            previous_owner: Addr::unchecked("owner"),
+            new_owner: Addr::unchecked("new_owner"),
+            tile_hash: tile_hash.clone(),
+            live_pixels: 0,
+        }
+        .into_event(),
+    );
+    let burn = on_chain(
+        TileBurnEventData {
+            token_id: "2".to_string(),
+            owner: Addr::unchecked("owner"),
+            tile_hash,
+            live_pixels: 0,
+        }
+        .into_event(),
+    );
+
+    let mut replayer = TileStateReplayer::new();
+    replayer
+        .replay(&[
+            mint_event("1", "owner"),
+            mint_event("2", "owner"),
+            transfer,
+            burn,
+        ])
+        .unwrap();
+
+    assert_eq!(
+        replayer.tile("1").unwrap().owner,
+        Addr::unchecked("new_owner")
+    );
+    assert!(replayer.tile("2").is_none());
+}
+
+#[test]
+fn tracks_price_scaling_updates() {
+    let scaling = PriceScaling::default();
+    let event = on_chain(
+        PriceScalingUpdateEventData {
+            hour_1_price: scaling.hour_1_price.u128(),
+            hour_12_price: scaling.hour_12_price.u128(),
+            hour_24_price: scaling.hour_24_price.u128(),
+            quadratic_base: scaling.quadratic_base.u128(),
+            annotation_byte_price: scaling.annotation_byte_price.u128(),
+        }
+        .into_event(),
+    );
+
+    let mut replayer = TileStateReplayer::new();
+    replayer.apply(&event).unwrap();
+    assert_eq!(replayer.price_scaling(), Some(&scaling));
+}
+
+#[test]
+fn tracks_config_updates() {
+    let config = Config {
+        tile_width: 16,
+        ..Config::default()
+    };
+    let event = on_chain(
+        ConfigUpdateEventData {
+            config: config.clone(),
+        }
+        .into_event(),
+    );
+
+    let mut replayer = TileStateReplayer::new();
+    replayer.apply(&event).unwrap();
+    assert_eq!(replayer.config(), Some(&config));
+}
+
+#[test]
+fn ignores_events_from_other_contracts() {
+    let event = mint_event("1", "owner").add_attribute("_contract_address", "other");
+
+    let mut replayer = TileStateReplayer::for_contract("tiles");
+    replayer.apply(&event).unwrap();
+    assert!(replayer.tile("1").is_none());
+}
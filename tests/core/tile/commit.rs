@@ -0,0 +1,26 @@
+use cosmwasm_std::Addr;
+use tiles::core::tile::{commit::paint_commitment, metadata::PixelUpdate};
+
+fn update(expiration_duration: u64) -> PixelUpdate {
+    PixelUpdate::new(0, "#FF0000", expiration_duration)
+}
+
+#[test]
+fn commitments_keep_fields_apart() {
+    let committer = Addr::unchecked("painter");
+
+    // Digits moved from the last pixel into the salt
+    assert_ne!(
+        paint_commitment(&committer, "1", &[update(3600)], "1"),
+        paint_commitment(&committer, "1", &[update(36001)], "")
+    );
+    // Pixels moved into the token id
+    assert_ne!(
+        paint_commitment(&Addr::unchecked("b"), "1", &[], "salt"),
+        paint_commitment(&Addr::unchecked(""), "1b", &[], "salt")
+    );
+    assert_eq!(
+        paint_commitment(&committer, "1", &[update(3600)], "1"),
+        paint_commitment(&committer, "1", &[update(3600)], "1")
+    );
+}
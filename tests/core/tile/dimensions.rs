@@ -0,0 +1,156 @@
+use cosmwasm_std::{Addr, Event};
+use tiles::{
+    core::{
+        config::Config,
+        replay::{ReplayError, TileStateReplayer},
+        tile::metadata::{PixelData, PixelUpdate, TileMetadata},
+    },
+    events::{EventData, MintMetadataEventData, PixelUpdateEventData},
+};
+
+// Same suite runs for square, wide, tall and degenerate tiles
+const DIMENSIONS: [(u32, u32); 5] = [(10, 10), (16, 16), (32, 8), (8, 32), (1, 1)];
+
+fn configs() -> impl Iterator<Item = Config> {
+    DIMENSIONS.iter().map(|&(tile_width, tile_height)| Config {
+        tile_width,
+        tile_height,
+        ..Config::default()
+    })
+}
+
+fn update(id: u32) -> PixelUpdate {
+    PixelUpdate::new(id, "#FF0000", 3600)
+}
+
+fn on_chain(event: Event) -> Event {
+    let mut event = event;
+    event.ty = format!("wasm-{}", event.ty);
+    event
+}
+
+#[test]
+fn configs_are_valid() {
+    for config in configs() {
+        assert!(config.validate().is_ok(), "{:?}", config);
+    }
+}
+
+#[test]
+fn new_metadata_has_one_pixel_per_cell() {
+    for config in configs() {
+        let metadata = TileMetadata::new(&config);
+        assert_eq!(
+            metadata.pixels.len() as u32,
+            config.tile_width * config.tile_height
+        );
+        assert!(metadata
+            .pixels
+            .iter()
+            .all(|pixel| pixel.color == config.default_color));
+    }
+}
+
+#[test]
+fn pixel_ids_are_bounded_by_dimensions() {
+    for config in configs() {
+        let last = config.pixels_per_tile() - 1;
+        assert!(update(last).validate_integrity(&config).is_ok());
+        assert!(update(last + 1).validate_integrity(&config).is_err());
+        assert_eq!(
+            config.pixel_position(last),
+            (config.tile_width - 1, config.tile_height - 1)
+        );
+    }
+}
+
+#[test]
+fn svg_matches_dimensions() {
+    for config in configs() {
+        let svg = TileMetadata::new(&config).to_svg(&config, 0, 2, false);
+        assert!(svg.contains(&format!(
+            r#"width="{}" height="{}" viewBox="0 0 {} {}""#,
+            config.tile_width * 2,
+            config.tile_height * 2,
+            config.tile_width,
+            config.tile_height
+        )));
+        assert_eq!(
+            svg.matches("<rect").count() as u32,
+            config.pixels_per_tile()
+        );
+    }
+}
+
+#[test]
+fn hash_covers_every_pixel() {
+    for config in configs() {
+        let metadata = TileMetadata::new(&config);
+        let mut updated = metadata.clone();
+        updated.apply_updates(
+            vec![update(config.pixels_per_tile() - 1)],
+            &Addr::unchecked("painter"),
+            1000,
+        );
+        assert_ne!(metadata.hash(), updated.hash());
+    }
+}
+
+#[test]
+fn replay_respects_dimensions() {
+    for config in configs() {
+        let metadata = TileMetadata::new(&config);
+        let mint = on_chain(
+            MintMetadataEventData {
+                token_id: "1".to_string(),
+                owner: Addr::unchecked("owner"),
+                tile_hash: metadata.hash(),
+                new_pixels: metadata.pixels.clone(),
+            }
+            .into_event(),
+        );
+
+        let last = config.pixels_per_tile() - 1;
+        let pixel = PixelData {
+            id: last,
+            color: "#FF0000".to_string(),
+            ..PixelData::default()
+        };
+        let mut expected = metadata.clone();
+        expected.pixels[last as usize] = PixelData {
+            id: expected.pixels[last as usize].id,
+            ..pixel.clone()
+        };
+        let paint_last = on_chain(
+            PixelUpdateEventData {
+                token_id: "1".to_string(),
+                new_pixels: vec![pixel],
+                tile_hash: expected.hash(),
+            }
+            .into_event(),
+        );
+
+        let mut replayer = TileStateReplayer::new();
+        replayer.replay(&[mint, paint_last]).unwrap();
+        assert_eq!(replayer.tile("1").unwrap().metadata, expected);
+
+        let out_of_bounds = on_chain(
+            PixelUpdateEventData {
+                token_id: "1".to_string(),
+                new_pixels: vec![PixelData {
+                    id: last + 1,
+                    ..PixelData::default()
+                }],
+                tile_hash: expected.hash(),
+            }
+            .into_event(),
+        );
+        assert_eq!(
+            replayer.apply(&out_of_bounds),
+            Err(ReplayError::InvalidPixelId {
+                token_id: "1".to_string(),
+                id: last + 1
+            })
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use cosmwasm_std::Addr;
+use tiles::{
+    core::tile::{
+        image::ImageFormat,
+        metadata::{Keyframe, TileMetadata},
+    },
+    defaults::constants::DEFAULT_COLOR,
+};
+
+#[test]
+fn decodes_raw_rgb() {
+    let data = [0xFF, 0x00, 0x00, 0x00, 0x80, 0xFF];
+    assert_eq!(
+        ImageFormat::Rgb.decode(&data, 2).unwrap(),
+        vec!["#FF0000".to_string(), "#0080FF".to_string()]
+    );
+    assert!(ImageFormat::Rgb.decode(&data, 3).is_err());
+    assert!(ImageFormat::Rgb.decode(&data[..5], 2).is_err());
+}
+
+#[test]
+fn decodes_run_length_encoded_rgb() {
+    let data = [3, 0xFF, 0xFF, 0xFF, 1, 0x00, 0x00, 0x00];
+    let colors = ImageFormat::RgbRle.decode(&data, 4).unwrap();
+    assert_eq!(colors, vec!["#FFFFFF", "#FFFFFF", "#FFFFFF", "#000000"]);
+
+    // Runs have to cover the tile exactly
+    assert!(ImageFormat::RgbRle.decode(&data, 5).is_err());
+    assert!(ImageFormat::RgbRle.decode(&data, 3).is_err());
+    // Empty and truncated runs
+    assert!(ImageFormat::RgbRle
+        .decode(&[0, 0, 0, 0, 4, 0, 0, 0], 4)
+        .is_err());
+    assert!(ImageFormat::RgbRle.decode(&data[..7], 4).is_err());
+}
+
+#[test]
+fn image_updates_skip_unchanged_pixels() {
+    let mut metadata = TileMetadata::default();
+    metadata.pixels[1].color = "#ff0000".to_string();
+    metadata.pixels[1].expiration_timestamp = 5000;
+    metadata.pixels[1].last_updated_by = Addr::unchecked("painter");
+    // Expired paint no longer shows, so repainting its color is a change
+    metadata.pixels[2].color = "#FF0000".to_string();
+    metadata.pixels[2].expiration_timestamp = 500;
+    // Live now, but gone before the image would be
+    metadata.pixels[4].color = "#FF0000".to_string();
+    metadata.pixels[4].expiration_timestamp = 2000;
+    // Switches color halfway through
+    metadata.pixels[5].color = "#FF0000".to_string();
+    metadata.pixels[5].expiration_timestamp = 5000;
+    metadata.pixels[5].last_updated_at = 900;
+    metadata.pixels[5].keyframes = vec![Keyframe {
+        start_offset: 1000,
+        color: "#0000FF".to_string(),
+    }];
+
+    let mut colors = vec![DEFAULT_COLOR.to_string(); 100];
+    for id in [1, 2, 4, 5] {
+        colors[id] = "#FF0000".to_string();
+    }
+    colors[3] = "#00FF00".to_string();
+
+    let updates = metadata.image_updates(&colors, DEFAULT_COLOR, 1000, 3600);
+    let ids: Vec<u32> = updates.iter().map(|update| update.id).collect();
+    assert_eq!(ids, vec![2, 3, 4, 5]);
+    // Paint lasting exactly as long as the image is kept
+    let updates = metadata.image_updates(&colors, DEFAULT_COLOR, 1000, 4000);
+    assert!(!updates.iter().any(|update| update.id == 1));
+    assert!(updates
+        .iter()
+        .all(|update| update.expiration_duration == 4000));
+}
@@ -33,6 +33,8 @@ fn test_pixel_update() {
         expiration_timestamp,
         last_updated_by: last_updated_by.clone(),
         last_updated_at,
+        keyframes: vec![],
+        annotation: None,
     };
 
     // Verify the update
@@ -62,3 +64,16 @@ fn test_pixel_bounds() {
     let metadata = TileMetadata::default();
     assert_eq!(metadata.pixels.len(), 100);
 }
+
+#[test]
+fn annotations_are_hashed_until_cleared() {
+    let mut metadata = TileMetadata::default();
+    let blank_hash = metadata.hash();
+
+    metadata.pixels[3].annotation = Some("gm".to_string());
+    assert_ne!(metadata.hash(), blank_hash);
+
+    metadata.clear_annotations(&[3]);
+    assert_eq!(metadata.pixels[3].annotation, None);
+    assert_eq!(metadata.hash(), blank_hash);
+}
@@ -1,2 +1,3 @@
 mod metadata;
-mod hash; 
\ No newline at end of file
+mod hash;
+mod render; 
\ No newline at end of file
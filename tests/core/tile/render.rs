@@ -0,0 +1,123 @@
+use cosmwasm_std::Addr;
+use tiles::{
+    core::{
+        config::Config,
+        tile::{
+            layer::BasePixel,
+            metadata::{Keyframe, PixelData, TileMetadata},
+        },
+    },
+    defaults::constants::DEFAULT_COLOR,
+};
+
+fn painted(color: &str, expiration_timestamp: u64) -> PixelData {
+    PixelData {
+        id: 0,
+        color: color.to_string(),
+        expiration_timestamp,
+        last_updated_by: Addr::unchecked("painter"),
+        last_updated_at: 0,
+        keyframes: vec![],
+        annotation: None,
+    }
+}
+
+#[test]
+fn renders_one_rect_per_pixel() {
+    let svg = TileMetadata::default().to_svg(&Config::default(), 0, 10, false);
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.ends_with("</svg>"));
+    assert!(svg.contains(r#"width="100" height="100""#));
+    assert_eq!(svg.matches("<rect").count(), 100);
+    assert!(!svg.contains("<path"));
+}
+
+#[test]
+fn live_pixels_use_their_color_and_expired_pixels_use_default() {
+    let mut metadata = TileMetadata::default();
+    metadata.pixels[0] = painted("#FF0000", 2000);
+    metadata.pixels[11] = painted("#00FF00", 500);
+
+    let svg = metadata.to_svg(&Config::default(), 1000, 1, false);
+
+    assert!(svg.contains(r##"<rect x="0" y="0" width="1" height="1" fill="#FF0000"/>"##));
+    assert!(svg.contains(&format!(
+        r#"<rect x="1" y="1" width="1" height="1" fill="{}"/>"#,
+        DEFAULT_COLOR
+    )));
+    assert!(!svg.contains("#00FF00"));
+}
+
+#[test]
+fn keyframes_take_over_once_they_start() {
+    let pixel = PixelData {
+        last_updated_at: 1000,
+        annotation: None,
+        keyframes: vec![
+            Keyframe {
+                start_offset: 100,
+                color: "#00FF00".to_string(),
+            },
+            Keyframe {
+                start_offset: 200,
+                color: "#0000FF".to_string(),
+            },
+        ],
+        ..painted("#FF0000", 2000)
+    };
+
+    assert_eq!(pixel.effective_color(DEFAULT_COLOR, 1099), "#FF0000");
+    assert_eq!(pixel.effective_color(DEFAULT_COLOR, 1100), "#00FF00");
+    assert_eq!(pixel.effective_color(DEFAULT_COLOR, 1999), "#0000FF");
+    assert_eq!(pixel.effective_color(DEFAULT_COLOR, 2000), DEFAULT_COLOR);
+}
+
+#[test]
+fn rental_pixels_are_composited_over_the_base_layer() {
+    let mut metadata = TileMetadata::default();
+    metadata.apply_base_pixels(&[
+        BasePixel {
+            id: 0,
+            color: Some("#0000FF".to_string()),
+        },
+        BasePixel {
+            id: 1,
+            color: Some("#0000FF".to_string()),
+        },
+    ]);
+    metadata.pixels[0] = painted("#FF0000", 2000);
+
+    assert_eq!(metadata.pixel_color(0, DEFAULT_COLOR, 1000), "#FF0000");
+    assert_eq!(metadata.pixel_color(0, DEFAULT_COLOR, 2000), "#0000FF");
+    assert_eq!(metadata.pixel_color(1, DEFAULT_COLOR, 1000), "#0000FF");
+    assert_eq!(metadata.pixel_color(2, DEFAULT_COLOR, 1000), DEFAULT_COLOR);
+
+    // Clearing every base pixel drops the layer
+    metadata.apply_base_pixels(&[
+        BasePixel { id: 0, color: None },
+        BasePixel { id: 1, color: None },
+    ]);
+    assert!(metadata.base_layer.is_empty());
+}
+
+#[test]
+fn grid_lines_are_optional() {
+    let svg = TileMetadata::default().to_svg(&Config::default(), 0, 10, true);
+    assert!(svg.contains("<path"));
+}
+
+#[test]
+fn uses_configured_dimensions_and_default_color() {
+    let config = Config {
+        tile_width: 4,
+        tile_height: 2,
+        default_color: "#000000".to_string(),
+        ..Config::default()
+    };
+    let svg = TileMetadata::new(&config).to_svg(&config, 0, 10, false);
+
+    assert!(svg.contains(r#"width="40" height="20" viewBox="0 0 4 2""#));
+    assert_eq!(svg.matches("<rect").count(), 8);
+    assert_eq!(svg.matches(r##"fill="#000000""##).count(), 8);
+}
@@ -1,7 +1,22 @@
 pub mod utils;
 pub mod contract {
+    pub mod annotations;
+    pub mod commit_reveal;
+    pub mod cw20_payment;
+    pub mod delegation;
+    pub mod dimensions;
+    pub mod earnings;
+    pub mod escrow;
+    pub mod history;
+    pub mod image;
     pub mod instantiate;
+    pub mod keyframes;
+    pub mod layers;
     pub mod mint;
+    pub mod nft_metadata;
+    pub mod outbid;
+    pub mod ownership;
+    pub mod painter;
     pub mod pixel {
         pub mod basic;
         pub mod hash;
@@ -9,16 +24,27 @@ pub mod contract {
         pub mod validation;
     }
     pub mod pricescaling;
+    pub mod render;
+    pub mod revenue_splits;
+    pub mod schema;
     pub mod sg721_execute;
+    pub mod tile_policy;
+    pub mod update_config;
 }
 
 mod core {
+    pub mod config;
     pub mod pricing {
         pub mod calculation;
         pub mod validation;
     }
+    pub mod replay;
     pub mod tile {
+        pub mod commit;
+        pub mod dimensions;
         pub mod hash;
+        pub mod image;
         pub mod metadata;
+        pub mod render;
     }
 }
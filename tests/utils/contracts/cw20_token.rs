@@ -0,0 +1,163 @@
+//! Minimal stand-in for cw20-base: balances, `Transfer`, `Send` and the
+//! `Balance` query, enough to exercise the tiles receive hook
+
+use anyhow::Result;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    Uint128,
+};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use cw_multi_test::{AppResponse, ContractWrapper, Executor};
+use cw_storage_plus::Map;
+
+use crate::utils::core::app::TestApp;
+
+const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+
+#[cw_serde]
+pub struct Cw20InstantiateMsg {
+    pub initial_balances: Vec<Cw20Coin>,
+}
+
+fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: Cw20InstantiateMsg,
+) -> StdResult<Response> {
+    for coin in msg.initial_balances {
+        let address = deps.api.addr_validate(&coin.address)?;
+        BALANCES.save(deps.storage, &address, &coin.amount)?;
+    }
+    Ok(Response::new())
+}
+
+fn move_tokens(deps: DepsMut, from: &Addr, to: &Addr, amount: Uint128) -> StdResult<()> {
+    if amount.is_zero() {
+        return Err(StdError::generic_err("Invalid zero amount"));
+    }
+    BALANCES.update(deps.storage, from, |balance| {
+        Ok::<_, StdError>(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    BALANCES.update(deps.storage, to, |balance| {
+        Ok::<_, StdError>(balance.unwrap_or_default() + amount)
+    })?;
+    Ok(())
+}
+
+fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: Cw20ExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        Cw20ExecuteMsg::Transfer { recipient, amount } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            move_tokens(deps, &info.sender, &recipient, amount)?;
+            Ok(Response::new())
+        }
+        Cw20ExecuteMsg::Send {
+            contract,
+            amount,
+            msg,
+        } => {
+            let contract = deps.api.addr_validate(&contract)?;
+            move_tokens(deps, &info.sender, &contract, amount)?;
+            let hook = Cw20ReceiveMsg {
+                sender: info.sender.to_string(),
+                amount,
+                msg,
+            }
+            .into_cosmos_msg(contract)?;
+            Ok(Response::new().add_message(hook))
+        }
+        _ => Err(StdError::generic_err("not supported by the test token")),
+    }
+}
+
+fn query(deps: Deps, _env: Env, msg: Cw20QueryMsg) -> StdResult<Binary> {
+    match msg {
+        Cw20QueryMsg::Balance { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_json_binary(&BalanceResponse {
+                balance: BALANCES
+                    .may_load(deps.storage, &address)?
+                    .unwrap_or_default(),
+            })
+        }
+        _ => Err(StdError::generic_err("not supported by the test token")),
+    }
+}
+
+pub struct Cw20Contract {
+    pub contract_addr: Addr,
+}
+
+impl Cw20Contract {
+    pub fn store_code(app: &mut TestApp) -> u64 {
+        app.store_code(Box::new(ContractWrapper::new_with_empty(
+            execute,
+            instantiate,
+            query,
+        )))
+    }
+
+    pub fn instantiate(
+        app: &mut TestApp,
+        sender: &Addr,
+        balances: &[(&Addr, u128)],
+    ) -> Result<Self> {
+        let code_id = Self::store_code(app);
+        let msg = Cw20InstantiateMsg {
+            initial_balances: balances
+                .iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(*amount),
+                })
+                .collect(),
+        };
+        let contract_addr = app.inner_mut().instantiate_contract(
+            code_id,
+            sender.clone(),
+            &msg,
+            &[],
+            "test-cw20",
+            None,
+        )?;
+        Ok(Self { contract_addr })
+    }
+
+    /// Sends tokens to a contract along with a hook message
+    pub fn send(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        contract: &Addr,
+        amount: u128,
+        msg: Binary,
+    ) -> Result<AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: contract.to_string(),
+                amount: Uint128::new(amount),
+                msg,
+            },
+            &[],
+        )
+    }
+
+    pub fn balance(&self, app: &TestApp, address: &Addr) -> Result<u128> {
+        let response: BalanceResponse = app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )?;
+        Ok(response.balance.u128())
+    }
+}
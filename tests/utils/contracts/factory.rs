@@ -4,11 +4,7 @@ use cw_multi_test::{AppResponse, ContractWrapper, Executor};
 use sg2::msg::{CollectionParams, CreateMinterMsg};
 use sg721::{CollectionInfo, RoyaltyInfoResponse};
 use sg_std::NATIVE_DENOM;
-use tiles::defaults::constants::{
-    AIRDROP_MINT_FEE_BPS, AIRDROP_MINT_PRICE, BASE_TOKEN_URI, COLLECTION_DESCRIPTION,
-    COLLECTION_NAME, COLLECTION_SYMBOL, COLLECTION_URI, CREATION_FEE, DEFAULT_ROYALTY_SHARE,
-    MAX_PER_ADDRESS_LIMIT, MAX_TOKEN_LIMIT, MINT_FEE_BPS, MINT_PRICE, MIN_MINT_PRICE, SHUFFLE_FEE,
-};
+use tiles::defaults::constants::DEFAULT_ROYALTY_SHARE;
 use vending_factory::{
     msg::{
         ExecuteMsg as FactoryExecuteMsg, InstantiateMsg as FactoryInstantiateMsg,
@@ -17,6 +13,11 @@ use vending_factory::{
     state::{ParamsExtension, VendingMinterParams},
 };
 
+use super::{
+    AIRDROP_MINT_FEE_BPS, AIRDROP_MINT_PRICE, BASE_TOKEN_URI, COLLECTION_DESCRIPTION,
+    COLLECTION_NAME, COLLECTION_SYMBOL, COLLECTION_URI, CREATION_FEE, MAX_PER_ADDRESS_LIMIT,
+    MAX_TOKEN_LIMIT, MINT_FEE_BPS, MINT_PRICE, MIN_MINT_PRICE, SHUFFLE_FEE,
+};
 use crate::utils::core::app::TestApp;
 
 #[derive(Clone)]
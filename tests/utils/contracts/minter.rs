@@ -2,9 +2,9 @@ use anyhow::Result;
 use cosmwasm_std::{coins, Addr};
 use cw_multi_test::{AppResponse, ContractWrapper, Executor};
 use sg_std::NATIVE_DENOM;
-use tiles::defaults::constants::MINT_PRICE;
 use vending_minter::msg::ExecuteMsg;
 
+use super::MINT_PRICE;
 use crate::utils::core::app::TestApp;
 
 pub struct MinterContract {
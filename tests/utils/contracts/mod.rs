@@ -1,7 +1,28 @@
+pub mod cw20_token;
 pub mod factory;
 pub mod minter;
 pub mod tiles;
 
+pub use cw20_token::Cw20Contract;
 pub use factory::FactoryContract;
 pub use minter::MinterContract;
 pub use tiles::TilesContract;
+
+// Collection details for tests, real deployments take these from a profile
+pub const COLLECTION_NAME: &str = "Tiles";
+pub const COLLECTION_SYMBOL: &str = "TILE";
+pub const COLLECTION_DESCRIPTION: &str = "A collaborative pixel art canvas on Stargaze";
+pub const COLLECTION_URI: &str = "ipfs://QmXzzVdLPNZCG1RnCSxDCSu9TfFd7fpnnwt8GuXdjNkjZw";
+pub const BASE_TOKEN_URI: &str =
+    "ipfs://bafybeidrmkt5uzfpz66esvhk3qflp47reztskaijxlsfu4fysujxicw7mu";
+
+// Mint and vending factory parameters for tests, real deployments take these from a profile
+pub const MINT_PRICE: u128 = 100_000_000; // 100 STARS
+pub const CREATION_FEE: u128 = 1_000_000; // 1 STARS
+pub const MINT_FEE_BPS: u64 = 1000; // 10%
+pub const MAX_TOKEN_LIMIT: u32 = 10000;
+pub const MAX_PER_ADDRESS_LIMIT: u32 = 3;
+pub const MIN_MINT_PRICE: u128 = 0;
+pub const AIRDROP_MINT_PRICE: u128 = 0;
+pub const AIRDROP_MINT_FEE_BPS: u64 = 0;
+pub const SHUFFLE_FEE: u128 = 0;
@@ -1,21 +1,33 @@
 use anyhow::Result;
-use cosmwasm_std::{coins, Addr, Binary, Coin};
-use cw721::{NftInfoResponse, OwnerOfResponse};
+use cosmwasm_std::{coins, to_json_binary, Addr, Binary, Coin, Decimal, Uint128, WasmMsg};
+use cw721::{Expiration, NftInfoResponse, OwnerOfResponse};
 use cw721_base::Action;
-use cw_multi_test::{ContractWrapper, Executor};
+use cw_multi_test::{AppResponse, ContractWrapper, Executor};
 use sg721::{CollectionInfo, RoyaltyInfoResponse, UpdateCollectionInfoMsg};
 use sg_std::NATIVE_DENOM;
 use tiles::{
-    contract::msg::{ExecuteMsg, QueryMsg, TileExecuteMsg},
+    contract::msg::{
+        Cw20TokensResponse, ExecuteMsg, InstantiateMsg, OwnerEarningsResponse, PaintCommitResponse,
+        PainterStatsResponse, PaintersResponse, PendingBalanceResponse, PixelHistoryResponse,
+        PixelResponse, QueryMsg, TakeoverPriceResponse, TileEarningsResponse, TileExecuteMsg,
+        TileHistoryResponse, TileSvgResponse, TopPaintersResponse,
+    },
     core::{
+        config::Config,
+        painter::PainterRanking,
         pricing::PriceScaling,
         tile::{
+            image::ImageFormat,
+            layer::BasePixel,
             metadata::{PixelUpdate, TileMetadata},
+            policy::TilePolicy,
             Tile,
         },
     },
+    defaults::constants::{DEFAULT_ROYALTY_SHARE, ONE_HOUR},
 };
 
+use super::{COLLECTION_DESCRIPTION, COLLECTION_NAME, COLLECTION_SYMBOL, COLLECTION_URI};
 use crate::utils::core::app::TestApp;
 
 pub struct TilesContract {
@@ -36,6 +48,101 @@ impl TilesContract {
         Ok(app.store_code(Box::new(contract)))
     }
 
+    /// Instantiate message matching what the vending factory sends, with no tiles overrides
+    pub fn default_instantiate_msg(minter: &Addr, creator: &Addr) -> InstantiateMsg {
+        InstantiateMsg {
+            name: COLLECTION_NAME.to_string(),
+            symbol: COLLECTION_SYMBOL.to_string(),
+            minter: minter.to_string(),
+            collection_info: CollectionInfo {
+                creator: creator.to_string(),
+                description: COLLECTION_DESCRIPTION.to_string(),
+                image: COLLECTION_URI.to_string(),
+                external_link: None,
+                explicit_content: Some(false),
+                start_trading_time: None,
+                royalty_info: Some(RoyaltyInfoResponse {
+                    payment_address: creator.to_string(),
+                    share: Decimal::percent(DEFAULT_ROYALTY_SHARE),
+                }),
+            },
+            price_scaling: None,
+            denom: None,
+            pixel_min_expiration: None,
+            pixel_max_expiration: None,
+            tile_width: None,
+            tile_height: None,
+            default_color: None,
+            pixel_history_depth: None,
+            payout_mode: None,
+            revenue_splits: None,
+            min_price_multiplier: None,
+            max_price_multiplier: None,
+            outbid: None,
+            reveal_window_blocks: None,
+            unrevealed_commits: None,
+            keyframe_surcharge_bps: None,
+            moderators: None,
+            free_owner_painting: None,
+        }
+    }
+
+    /// Instantiates the tiles contract directly, bypassing the factory.
+    /// sg721 requires the sender to be a contract, usually the minter.
+    pub fn instantiate(
+        app: &mut TestApp,
+        code_id: u64,
+        sender: &Addr,
+        msg: &InstantiateMsg,
+    ) -> Result<(Self, AppResponse)> {
+        // Go through a wasm message so the instantiate events are kept in the response
+        let response = app.inner_mut().execute(
+            sender.clone(),
+            WasmMsg::Instantiate {
+                admin: None,
+                code_id,
+                msg: to_json_binary(msg)?,
+                funds: vec![],
+                label: "tiles".to_string(),
+            }
+            .into(),
+        )?;
+        let addr = response
+            .events
+            .iter()
+            .find(|event| event.ty == "instantiate")
+            .and_then(|event| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attr| attr.key == "_contract_addr")
+            })
+            .map(|attr| Addr::unchecked(&attr.value))
+            .ok_or_else(|| anyhow::anyhow!("Missing instantiated contract address"))?;
+        Ok((Self::new(addr), response))
+    }
+
+    /// Mints directly as the minter, for contracts instantiated outside the factory
+    pub fn execute_mint(
+        &self,
+        app: &mut TestApp,
+        minter: &Addr,
+        owner: &Addr,
+        token_id: u32,
+    ) -> Result<AppResponse> {
+        app.inner_mut().execute_contract(
+            minter.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Mint {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+            &[],
+        )
+    }
+
     pub fn update_price_scaling(
         &self,
         app: &mut TestApp,
@@ -61,6 +168,22 @@ impl TilesContract {
         )
     }
 
+    pub fn execute_update_config(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        config: Config,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::UpdateConfig(config),
+            },
+            &[],
+        )
+    }
+
     pub fn update_pixel(
         &self,
         app: &mut TestApp,
@@ -90,6 +213,286 @@ impl TilesContract {
         )
     }
 
+    pub fn execute_set_cw20_price_scaling(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token: &Addr,
+        price_scaling: Option<PriceScaling>,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::SetCw20PriceScaling {
+                    token: token.to_string(),
+                    price_scaling,
+                },
+            },
+            &[],
+        )
+    }
+
+    pub fn execute_set_tile_policy(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        policy: TilePolicy,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::SetTilePolicy {
+                    token_id: token_id.to_string(),
+                    policy,
+                },
+            },
+            &[],
+        )
+    }
+
+    pub fn execute_grant_painter(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        painter: &Addr,
+        expires: Option<Expiration>,
+        max_pixels: Option<u32>,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::GrantPainter {
+                    token_id: token_id.to_string(),
+                    painter: painter.to_string(),
+                    expires,
+                    max_pixels,
+                },
+            },
+            &[],
+        )
+    }
+
+    pub fn execute_revoke_painter(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        painter: &Addr,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::RevokePainter {
+                    token_id: token_id.to_string(),
+                    painter: painter.to_string(),
+                },
+            },
+            &[],
+        )
+    }
+
+    pub fn execute_set_base_layer(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        pixels: Vec<BasePixel>,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::SetBaseLayer {
+                    token_id: token_id.to_string(),
+                    pixels,
+                },
+            },
+            &[],
+        )
+    }
+
+    pub fn execute_clear_annotations(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        pixel_ids: Vec<u32>,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::ClearAnnotations {
+                    token_id: token_id.to_string(),
+                    pixel_ids,
+                },
+            },
+            &[],
+        )
+    }
+
+    /// Paints `data` over the tile for an hour
+    pub fn execute_paint_image(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        format: ImageFormat,
+        data: Vec<u8>,
+        funds_amount: u128,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::PaintImage {
+                    token_id: token_id.to_string(),
+                    format,
+                    data: Binary::from(data),
+                    duration: ONE_HOUR,
+                },
+            },
+            &[Coin::new(funds_amount, NATIVE_DENOM)],
+        )
+    }
+
+    pub fn execute_commit_paint(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        pixel_ids: Vec<u32>,
+        commitment: String,
+        funds: &[Coin],
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::CommitPaint {
+                    token_id: token_id.to_string(),
+                    pixel_ids,
+                    commitment,
+                },
+            },
+            funds,
+        )
+    }
+
+    pub fn execute_reveal_paint(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        updates: Vec<PixelUpdate>,
+        salt: &str,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::RevealPaint {
+                    token_id: token_id.to_string(),
+                    updates,
+                    salt: salt.to_string(),
+                },
+            },
+            &[],
+        )
+    }
+
+    pub fn execute_expire_commit(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        token_id: u32,
+        committer: &Addr,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::ExpireCommit {
+                    token_id: token_id.to_string(),
+                    committer: committer.to_string(),
+                },
+            },
+            &[],
+        )
+    }
+
+    pub fn query_paint_commit(
+        &self,
+        app: &TestApp,
+        token_id: u32,
+        committer: &Addr,
+    ) -> Result<PaintCommitResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::PaintCommit {
+                token_id: token_id.to_string(),
+                committer: committer.to_string(),
+            },
+        )?)
+    }
+
+    pub fn query_painters(&self, app: &TestApp, token_id: u32) -> Result<PaintersResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::Painters {
+                token_id: token_id.to_string(),
+            },
+        )?)
+    }
+
+    pub fn query_takeover_price(
+        &self,
+        app: &TestApp,
+        token_id: u32,
+        pixel_id: u32,
+    ) -> Result<TakeoverPriceResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::TakeoverPrice {
+                token_id: token_id.to_string(),
+                pixel_id,
+            },
+        )?)
+    }
+
+    pub fn query_cw20_tokens(&self, app: &TestApp) -> Result<Cw20TokensResponse> {
+        Ok(app
+            .inner()
+            .wrap()
+            .query_wasm_smart(self.contract_addr.clone(), &QueryMsg::Cw20Tokens {})?)
+    }
+
+    pub fn execute_withdraw(
+        &self,
+        app: &mut TestApp,
+        sender: &Addr,
+        denom: &str,
+        amount: Option<u128>,
+    ) -> Result<cw_multi_test::AppResponse> {
+        app.inner_mut().execute_contract(
+            sender.clone(),
+            self.contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: TileExecuteMsg::Withdraw {
+                    denom: denom.to_string(),
+                    amount: amount.map(Uint128::new),
+                },
+            },
+            &[],
+        )
+    }
+
     pub fn update_pixel_with_funds(
         &self,
         app: &mut TestApp,
@@ -99,6 +502,11 @@ impl TilesContract {
         funds_amount: u128,
         current_metadata: TileMetadata,
     ) -> Result<cw_multi_test::AppResponse> {
+        // Zero sends no funds at all, as free paints require
+        let funds = match funds_amount {
+            0 => vec![],
+            amount => vec![Coin::new(amount, NATIVE_DENOM)],
+        };
         app.inner_mut().execute_contract(
             sender.clone(),
             self.contract_addr.clone(),
@@ -109,7 +517,7 @@ impl TilesContract {
                     updates,
                 },
             },
-            &[Coin::new(funds_amount, NATIVE_DENOM)],
+            &funds,
         )
     }
 
@@ -123,6 +531,22 @@ impl TilesContract {
         Ok(response.extension.tile_hash)
     }
 
+    pub fn query_nft_info(&self, app: &TestApp, token_id: u32) -> Result<NftInfoResponse<Tile>> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            },
+        )?)
+    }
+
+    pub fn query_config(&self, app: &TestApp) -> Result<Config> {
+        Ok(app
+            .inner()
+            .wrap()
+            .query_wasm_smart(self.contract_addr.clone(), &QueryMsg::Config {})?)
+    }
+
     pub fn query_price_scaling(&self, app: &TestApp) -> Result<PriceScaling> {
         Ok(app
             .inner()
@@ -130,6 +554,144 @@ impl TilesContract {
             .query_wasm_smart(self.contract_addr.clone(), &QueryMsg::PriceScaling {})?)
     }
 
+    pub fn query_tile_svg(
+        &self,
+        app: &TestApp,
+        token_id: u32,
+        scale: Option<u32>,
+        grid_lines: Option<bool>,
+    ) -> Result<String> {
+        let response: TileSvgResponse = app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::TileSvg {
+                token_id: token_id.to_string(),
+                scale,
+                grid_lines,
+            },
+        )?;
+        Ok(response.svg)
+    }
+
+    pub fn query_pixel(
+        &self,
+        app: &TestApp,
+        token_id: u32,
+        pixel_id: u32,
+    ) -> Result<PixelResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::Pixel {
+                token_id: token_id.to_string(),
+                pixel_id,
+            },
+        )?)
+    }
+
+    pub fn query_pixel_history(
+        &self,
+        app: &TestApp,
+        token_id: u32,
+        pixel_id: u32,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<PixelHistoryResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::PixelHistory {
+                token_id: token_id.to_string(),
+                pixel_id,
+                start_after,
+                limit,
+            },
+        )?)
+    }
+
+    pub fn query_tile_history(
+        &self,
+        app: &TestApp,
+        token_id: u32,
+        since: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<TileHistoryResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::TileHistory {
+                token_id: token_id.to_string(),
+                since,
+                limit,
+            },
+        )?)
+    }
+
+    pub fn query_painter_stats(
+        &self,
+        app: &TestApp,
+        address: &Addr,
+    ) -> Result<PainterStatsResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::PainterStats {
+                address: address.to_string(),
+            },
+        )?)
+    }
+
+    pub fn query_top_painters(
+        &self,
+        app: &TestApp,
+        by: PainterRanking,
+        start_after: Option<&Addr>,
+        limit: Option<u32>,
+    ) -> Result<TopPaintersResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::TopPainters {
+                by,
+                start_after: start_after.map(|address| address.to_string()),
+                limit,
+            },
+        )?)
+    }
+
+    pub fn query_tile_earnings(
+        &self,
+        app: &TestApp,
+        token_id: u32,
+    ) -> Result<TileEarningsResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::TileEarnings {
+                token_id: token_id.to_string(),
+            },
+        )?)
+    }
+
+    pub fn query_owner_earnings(
+        &self,
+        app: &TestApp,
+        owner: &Addr,
+    ) -> Result<OwnerEarningsResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::OwnerEarnings {
+                owner: owner.to_string(),
+            },
+        )?)
+    }
+
+    pub fn query_pending_balance(
+        &self,
+        app: &TestApp,
+        address: &Addr,
+    ) -> Result<PendingBalanceResponse> {
+        Ok(app.inner().wrap().query_wasm_smart(
+            self.contract_addr.clone(),
+            &QueryMsg::PendingBalance {
+                address: address.to_string(),
+            },
+        )?)
+    }
+
     pub fn execute_transfer_nft(
         &self,
         app: &mut TestApp,
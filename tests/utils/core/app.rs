@@ -45,6 +45,17 @@ impl TestApp {
         });
     }
 
+    /// Advances the chain by the specified number of blocks, five seconds each.
+    ///
+    /// # Arguments
+    /// * `blocks` - Number of blocks to advance by
+    pub fn advance_blocks(&mut self, blocks: u64) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(5 * blocks);
+            block.height += blocks;
+        });
+    }
+
     /// Stores contract code in the test environment
     ///
     /// # Arguments
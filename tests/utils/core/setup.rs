@@ -3,7 +3,7 @@
 use anyhow::Result;
 use cosmwasm_std::Addr;
 use cw_multi_test::AppResponse;
-use tiles::core::tile::metadata::PixelUpdate;
+use tiles::{contract::msg::InstantiateMsg, core::tile::metadata::PixelUpdate};
 
 use crate::utils::{
     contracts::{factory::FactoryContract, minter::MinterContract, tiles::TilesContract},
@@ -57,6 +57,27 @@ impl TestSetup {
         })
     }
 
+    /// Creates a test setup whose tiles contract is instantiated directly, with
+    /// `configure` applied to the message the factory would send, and token 1
+    /// minted to the buyer.
+    pub fn with_config(configure: impl FnOnce(&mut InstantiateMsg)) -> Result<Self> {
+        let mut setup = Self::new()?;
+        let code_id = setup.factory.collection_code_id.unwrap();
+        let minter = setup.minter.contract_addr.clone();
+        let creator = setup.users.tile_contract_creator().address.clone();
+        let owner = setup.users.get_buyer().address.clone();
+
+        let mut msg = TilesContract::default_instantiate_msg(&minter, &creator);
+        configure(&mut msg);
+        let (tiles, response) = TilesContract::instantiate(&mut setup.app, code_id, &minter, &msg)?;
+        setup.state = StateTracker::new();
+        setup.state.track_instantiate(&response)?;
+        let response = tiles.execute_mint(&mut setup.app, &minter, &owner, 1)?;
+        setup.state.track_mint(&response)?;
+        setup.tiles = tiles;
+        Ok(setup)
+    }
+
     /// Mints a new token for the specified buyer.
     ///
     /// # Arguments
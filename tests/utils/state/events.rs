@@ -4,6 +4,7 @@ use cw_multi_test::AppResponse;
 use tiles::events::{
     EventData, InstantiatePriceScalingEventData, MintMetadataEventData,
     PaymentDistributionEventData, PixelUpdateEventData, PriceScalingUpdateEventData,
+    TileBurnEventData, TileTransferEventData,
 };
 
 pub struct EventParser {}
@@ -72,6 +73,14 @@ impl EventParser {
         Self::find_and_parse::<PriceScalingUpdateEventData>(response)
     }
 
+    pub fn parse_tile_transfer(response: &AppResponse) -> Result<TileTransferEventData> {
+        Self::find_and_parse::<TileTransferEventData>(response)
+    }
+
+    pub fn parse_tile_burn(response: &AppResponse) -> Result<TileBurnEventData> {
+        Self::find_and_parse::<TileBurnEventData>(response)
+    }
+
     pub fn extract_token_id(response: &AppResponse) -> Result<u32> {
         // Find all wasm events
         let events = Self::find_events(response, "wasm");
@@ -2,14 +2,12 @@ use anyhow::Result;
 use cw_multi_test::AppResponse;
 use tiles::core::{
     pricing::PriceScaling,
+    replay::TileStateReplayer,
     tile::metadata::{PixelUpdate, TileMetadata},
 };
 
-use super::events::EventParser;
-
 pub struct StateTracker {
-    price_scaling: Option<PriceScaling>,
-    token_metadata: std::collections::HashMap<u32, TileMetadata>,
+    replayer: TileStateReplayer,
 }
 
 impl Default for StateTracker {
@@ -21,53 +19,43 @@ impl Default for StateTracker {
 impl StateTracker {
     pub fn new() -> Self {
         Self {
-            price_scaling: None,
-            token_metadata: std::collections::HashMap::new(),
+            replayer: TileStateReplayer::new(),
         }
     }
 
     pub fn get_price_scaling(&self) -> Result<PriceScaling> {
-        self.price_scaling
-            .clone()
+        self.replayer
+            .price_scaling()
+            .cloned()
             .ok_or_else(|| anyhow::anyhow!("No price scaling found"))
     }
 
     pub fn get_token_metadata(&self, token_id: u32) -> Result<TileMetadata> {
-        self.token_metadata
-            .get(&token_id)
-            .cloned()
+        self.replayer
+            .tile(&token_id.to_string())
+            .map(|tile| tile.metadata.clone())
             .ok_or_else(|| anyhow::anyhow!("No metadata found for token {}", token_id))
     }
 
-    pub fn track_instantiate(&mut self, response: &AppResponse) -> Result<()> {
-        let event = EventParser::parse_instantiate_event(response)?;
-        let price_scaling: PriceScaling = serde_json::from_str(&event.price_scaling)?;
-        self.price_scaling = Some(price_scaling);
+    pub fn track_response(&mut self, response: &AppResponse) -> Result<()> {
+        self.replayer.replay(&response.events)?;
         Ok(())
     }
 
+    pub fn track_instantiate(&mut self, response: &AppResponse) -> Result<()> {
+        self.track_response(response)
+    }
+
     pub fn track_mint(&mut self, response: &AppResponse) -> Result<()> {
-        let event = EventParser::parse_mint_metadata(response)?;
-        let token_id = event.token_id.parse::<u32>()?;
-        self.token_metadata
-            .insert(token_id, TileMetadata::default());
-        Ok(())
+        self.track_response(response)
     }
 
     pub fn track_pixel_update(
         &mut self,
-        token_id: u32,
+        _token_id: u32,
         _updates: &[PixelUpdate],
         response: &AppResponse,
     ) -> Result<()> {
-        let event = EventParser::parse_pixel_update(response)?;
-        let metadata = self.token_metadata.entry(token_id).or_default();
-        println!("event: {:?}", event);
-        // Update pixels from event data
-        for pixel_data in event.new_pixels {
-            println!("pixel_data: {:?}", pixel_data);
-            metadata.pixels[pixel_data.id as usize] = pixel_data.clone();
-        }
-        Ok(())
+        self.track_response(response)
     }
 }
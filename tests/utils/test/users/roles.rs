@@ -1,11 +1,3 @@
-use cosmwasm_std::Addr;
-
-#[derive(Clone)]
-pub struct User {
-    pub address: Addr,
-    pub role: UserRole,
-}
-
 #[derive(Clone, Copy, Debug)]
 pub enum UserRole {
     FactoryCreator,
@@ -15,12 +7,3 @@ pub enum UserRole {
     PixelOperator,
     Creator,
 }
-
-impl User {
-    pub fn new(address: &str, role: UserRole) -> Self {
-        Self {
-            address: Addr::unchecked(address),
-            role,
-        }
-    }
-}
@@ -1,8 +1,10 @@
 use cosmwasm_std::{Addr, Coin, Uint128};
 use sg_std::NATIVE_DENOM;
 
-use crate::utils::core::app::TestApp;
-use tiles::defaults::constants::{CREATION_FEE, MINT_PRICE};
+use crate::utils::{
+    contracts::{CREATION_FEE, MINT_PRICE},
+    core::app::TestApp,
+};
 
 #[derive(Clone)]
 pub struct User {